@@ -95,7 +95,7 @@ async fn retry_operation() -> Result<(), PrinterError> {
             }
             Err(e) => {
                 retry_count += 1;
-                if retry_count >= MAX_RETRIES {
+                if !e.is_retryable() || retry_count >= MAX_RETRIES {
                     return Err(e);
                 }
 
@@ -207,9 +207,18 @@ async fn handle_specific_errors() {
                         PrinterError::PrinterNotFound(name) => {
                             println!("      Printer '{}' not found in system", name);
                         }
+                        PrinterError::JobNotFound { printer, job_id } => {
+                            println!("      Job {} not found on '{}'", job_id, printer);
+                        }
                         PrinterError::IoError(io_err) => {
                             println!("      I/O error occurred: {}", io_err);
                         }
+                        PrinterError::Timeout(msg) => {
+                            println!("      Timed out: {}", msg);
+                        }
+                        PrinterError::InvalidConfig(msg) => {
+                            println!("      Invalid configuration: {}", msg);
+                        }
                         PrinterError::Other(msg) => {
                             println!("      General error: {}", msg);
                         }
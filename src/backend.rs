@@ -1,7 +1,9 @@
-#[cfg(windows)]
-use crate::PrinterError;
-use crate::{Printer, Result};
+use crate::{MediaInfo, Printer, PrinterClass, PrinterDriver, PrinterError, Result};
 use async_trait::async_trait;
+#[cfg(feature = "stream")]
+use futures_util::stream::BoxStream;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 
 /// Trait for platform-specific printer backend implementations
 #[async_trait]
@@ -14,260 +16,3392 @@ pub trait PrinterBackend: Send + Sync {
     /// List all printers on the system
     async fn list_printers(&self) -> Result<Vec<Printer>>;
 
+    /// List all printers, collecting per-entry conversion failures instead of failing.
+    ///
+    /// Returns the successfully parsed printers together with one error per entry
+    /// that could not be converted. Backends that cannot fail per entry fall back
+    /// to `list_printers` with no entry errors.
+    async fn list_printers_lenient(&self) -> Result<(Vec<Printer>, Vec<PrinterError>)> {
+        Ok((self.list_printers().await?, Vec::new()))
+    }
+
     /// Find a printer by name (case-insensitive)
     async fn find_printer(&self, name: &str) -> Result<Option<Printer>>;
-}
 
-/// Windows backend using WMI
-#[cfg(windows)]
-pub struct WindowsBackend;
+    /// List at most `limit` printers, in the order `list_printers` reports them.
+    ///
+    /// The default implementation lists all printers and truncates the result;
+    /// platform backends override it to stop enumerating once `limit` entries
+    /// have been read.
+    async fn list_printers_up_to(&self, limit: usize) -> Result<Vec<Printer>> {
+        let mut printers = self.list_printers().await?;
+        printers.truncate(limit);
+        Ok(printers)
+    }
 
-#[cfg(windows)]
-#[async_trait]
-impl PrinterBackend for WindowsBackend {
-    async fn new() -> Result<Self> {
-        use log::info;
+    /// Report which optional features this backend supports.
+    ///
+    /// The default implementation reports none of them.
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::default()
+    }
 
-        info!("Initializing Windows WMI backend...");
-        Ok(Self)
+    /// Re-initialize the backend after a fatal query error (e.g. the WMI service restarted).
+    ///
+    /// The default implementation does nothing, which suits stateless backends.
+    async fn reconnect(&self) -> Result<()> {
+        Ok(())
     }
 
-    async fn list_printers(&self) -> Result<Vec<Printer>> {
-        use crate::printer::Win32Printer;
-        use log::info;
-        use wmi::COMLibrary;
+    /// List only the printer names.
+    ///
+    /// The default implementation takes the names from `list_printers`; platform
+    /// backends override it with a cheaper query.
+    async fn list_printer_names(&self) -> Result<Vec<String>> {
+        let printers = self.list_printers().await?;
+        Ok(printers
+            .into_iter()
+            .map(|printer| printer.name().to_string())
+            .collect())
+    }
 
-        info!("Querying printer information via WMI...");
+    /// Check whether the Print Spooler service is running (Windows only).
+    ///
+    /// The default implementation reports that the check is not supported.
+    #[cfg(windows)]
+    async fn spooler_running(&self) -> Result<bool> {
+        Err(PrinterError::PlatformNotSupported)
+    }
 
-        // Run WMI operations in a blocking task to avoid Send/Sync issues
-        let wmi_printers = tokio::task::spawn_blocking(|| -> Result<Vec<Win32Printer>> {
-            let com_con = COMLibrary::new().map_err(PrinterError::from)?;
-            let wmi_connection = wmi::WMIConnection::new(com_con).map_err(PrinterError::from)?;
-            let printers: Vec<Win32Printer> = wmi_connection.raw_query("SELECT Name, PrinterStatus, DetectedErrorState, WorkOffline, PrinterState, Default, ExtendedPrinterStatus, ExtendedDetectedErrorState, Status FROM Win32_Printer").map_err(PrinterError::from)?;
-            Ok(printers)
-        })
-        .await
-        .map_err(|e| PrinterError::Other(format!("Failed to execute WMI query: {}", e)))??;
+    /// Make the named printer the default printer.
+    ///
+    /// Unlike every other method this changes system configuration. The default
+    /// implementation reports that the operation is not supported.
+    async fn set_default_printer(&self, name: &str) -> Result<()> {
+        let _ = name;
+        Err(PrinterError::PlatformNotSupported)
+    }
 
-        let printers = wmi_printers.into_iter().map(Printer::from).collect();
-        Ok(printers)
+    /// Pause (`paused = true`) or resume a printer's queue.
+    ///
+    /// Like `set_default_printer` this changes system state. The default
+    /// implementation reports that the operation is not supported.
+    async fn set_paused(&self, name: &str, paused: bool) -> Result<()> {
+        let _ = (name, paused);
+        Err(PrinterError::PlatformNotSupported)
     }
 
-    async fn find_printer(&self, name: &str) -> Result<Option<Printer>> {
-        let printers = self.list_printers().await?;
+    /// Cancel one job (`Some(job_id)`) or every job (`None`) queued on a printer.
+    ///
+    /// Like `set_default_printer` this changes system state. The default
+    /// implementation reports that the operation is not supported.
+    async fn cancel_jobs(&self, printer_name: &str, job_id: Option<u32>) -> Result<()> {
+        let _ = (printer_name, job_id);
+        Err(PrinterError::PlatformNotSupported)
+    }
 
-        for printer in printers {
-            if printer.name().eq_ignore_ascii_case(name) {
-                return Ok(Some(printer));
+    /// List the paper sizes a printer supports and its default paper size.
+    ///
+    /// The default implementation reports no media information.
+    async fn media_sizes(&self, name: &str) -> Result<MediaInfo> {
+        let _ = name;
+        Ok(MediaInfo::default())
+    }
+
+    /// List the printer drivers installed on the system.
+    ///
+    /// The default implementation reports that the operation is not supported.
+    async fn list_drivers(&self) -> Result<Vec<PrinterDriver>> {
+        Err(PrinterError::PlatformNotSupported)
+    }
+
+    /// List the CUPS printer classes and their members (Linux only).
+    ///
+    /// The default implementation reports that the operation is not supported.
+    async fn list_classes(&self) -> Result<Vec<PrinterClass>> {
+        Err(PrinterError::PlatformNotSupported)
+    }
+
+    /// Read how many pages a printer has printed, or `None` if no counter is available.
+    ///
+    /// The default implementation reports no counter.
+    async fn page_count(&self, name: &str) -> Result<Option<u64>> {
+        let _ = name;
+        Ok(None)
+    }
+
+    /// Gather the printers together with their page counters in one pass.
+    ///
+    /// The default implementation lists the printers and then reads each page
+    /// counter separately; platform backends override it to collect everything
+    /// with as few queries as the platform allows.
+    async fn snapshot_all(&self) -> Result<FleetSnapshot> {
+        let printers = self.list_printers().await?;
+        let mut page_counts = HashMap::new();
+        for printer in &printers {
+            if let Some(pages) = self.page_count(printer.name()).await? {
+                page_counts.insert(printer.name().to_string(), pages);
             }
         }
+        Ok(FleetSnapshot {
+            printers,
+            page_counts,
+        })
+    }
 
-        Ok(None)
+    /// Stream printers one at a time as the backend produces them.
+    ///
+    /// The default implementation lists all printers and yields them in order;
+    /// the Linux backend overrides it to yield entries while `lpstat` output is
+    /// still being read. The Windows backend collects the rows on its WMI worker
+    /// first, so a slow consumer never holds up other queries.
+    #[cfg(feature = "stream")]
+    fn printers_stream(&self) -> BoxStream<'_, Result<Printer>> {
+        use futures_util::stream::{self, StreamExt};
+
+        stream::once(self.list_printers())
+            .flat_map(|result| match result {
+                Ok(printers) => stream::iter(printers.into_iter().map(Ok)).left_stream(),
+                Err(e) => stream::iter(vec![Err(e)]).right_stream(),
+            })
+            .boxed()
     }
 }
 
-/// Linux backend using CUPS commands
-#[cfg(unix)]
-pub struct LinuxBackend;
+/// Everything a fleet dashboard shows, gathered in one coordinated pass by
+/// [`crate::PrinterMonitor::snapshot_all`]
+#[derive(Debug, Clone, Default)]
+pub struct FleetSnapshot {
+    /// All printers on the system
+    pub printers: Vec<Printer>,
+    /// Pages printed per printer name, for printers with an available counter
+    /// (see [`crate::PrinterMonitor::page_count`])
+    pub page_counts: HashMap<String, u64>,
+}
 
-#[cfg(unix)]
-#[async_trait]
-impl PrinterBackend for LinuxBackend {
-    async fn new() -> Result<Self> {
-        use log::info;
-        use tokio::process::Command;
+impl FleetSnapshot {
+    /// Returns the page counter of the named printer, if one was available
+    pub fn page_count(&self, name: &str) -> Option<u64> {
+        self.page_counts.get(name).copied()
+    }
+}
 
-        info!("Initializing Linux CUPS backend...");
+/// Optional features a backend supports, so callers can adapt at runtime
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct BackendCapabilities {
+    /// Print jobs can be managed (e.g. cancelled)
+    pub supports_jobs: bool,
+    /// Changes are picked up from system notifications instead of polling alone
+    pub supports_events: bool,
+    /// Printers on other machines can be queried
+    pub supports_remote: bool,
+    /// Toner/ink and paper supply levels are reported
+    pub supports_supply_levels: bool,
+    /// Extended status codes beyond the basic state are reported
+    pub supports_extended_status: bool,
+}
 
-        // Check if lpstat is available
-        let output = Command::new("which").arg("lpstat").output().await;
+/// Settings applied when creating a platform backend
+#[derive(Debug, Clone)]
+pub(crate) struct BackendOptions {
+    /// WMI namespace to connect to instead of `root\cimv2` (Windows only)
+    pub(crate) wmi_namespace: Option<String>,
+    /// Whether `DetectedErrorState = 0` maps to `NoError` rather than `UnknownError` (Windows only)
+    pub(crate) treat_zero_error_as_no_error: bool,
+    /// What to do with printer rows that have no name (Windows only)
+    pub(crate) nameless_printers: NamelessPrinterPolicy,
+    /// WMI class printers are enumerated from (Windows only)
+    pub(crate) wmi_printer_class: WmiPrinterClass,
+}
 
-        match output {
-            Ok(result) if result.status.success() => {
-                info!("CUPS tools found, backend ready");
-                Ok(Self)
-            }
-            _ => {
-                // Check if we can find any printers using /proc or /sys
-                info!("CUPS not found, checking for alternative printer detection methods");
-                Ok(Self)
-            }
+#[cfg_attr(not(windows), allow(dead_code))]
+impl BackendOptions {
+    /// Returns the namespace to connect to: the configured one, or the default
+    /// namespace of the printer class. `None` means the default `root\cimv2`.
+    pub(crate) fn namespace(&self) -> Option<String> {
+        self.wmi_namespace.clone().or_else(|| {
+            (self.wmi_printer_class != WmiPrinterClass::default())
+                .then(|| self.wmi_printer_class.default_namespace().to_string())
+        })
+    }
+}
+
+impl Default for BackendOptions {
+    fn default() -> Self {
+        Self {
+            wmi_namespace: None,
+            treat_zero_error_as_no_error: true,
+            nameless_printers: NamelessPrinterPolicy::default(),
+            wmi_printer_class: WmiPrinterClass::default(),
         }
     }
+}
 
-    async fn list_printers(&self) -> Result<Vec<Printer>> {
-        use log::{info, warn};
-        use tokio::process::Command;
+/// WMI class printers are enumerated from (Windows only)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WmiPrinterClass {
+    /// `Win32_Printer` in `root\cimv2`, available on every Windows version (the default)
+    #[default]
+    Win32Printer,
+    /// `MSFT_Printer` in `root\StandardCimv2`, the class behind the PrintManagement
+    /// PowerShell module.
+    ///
+    /// It reports a single enumerated `PrinterStatus` instead of the legacy
+    /// status codes, and no default printer flag. Settings, jobs, page counters,
+    /// drivers and the spooler state are still read from the `Win32_*` classes,
+    /// over a second connection to `root\cimv2`.
+    MsftPrinter,
+}
 
-        info!("Querying printer information via system commands...");
+impl WmiPrinterClass {
+    /// Returns the WMI class name
+    pub fn class_name(&self) -> &'static str {
+        match self {
+            WmiPrinterClass::Win32Printer => "Win32_Printer",
+            WmiPrinterClass::MsftPrinter => "MSFT_Printer",
+        }
+    }
 
-        let mut printers = Vec::new();
+    /// Returns the namespace the class lives in
+    pub fn default_namespace(&self) -> &'static str {
+        match self {
+            WmiPrinterClass::Win32Printer => "root\\cimv2",
+            WmiPrinterClass::MsftPrinter => "root\\StandardCimv2",
+        }
+    }
+}
 
-        // Try lpstat first
-        if let Ok(output) = Command::new("lpstat").arg("-p").arg("-d").output().await {
-            if output.status.success() {
-                let stdout = String::from_utf8_lossy(&output.stdout);
-
-                for line in stdout.lines() {
-                    if line.starts_with("printer ") {
-                        if let Some(printer_info) = parse_lpstat_line(line) {
-                            printers.push(printer_info);
-                        }
-                    }
-                }
+/// What to do with WMI printer rows that report no `Name` (Windows only)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NamelessPrinterPolicy {
+    /// Leaves nameless printers out of listings
+    Skip,
+    /// Names each nameless printer `Unknown Printer (<port>)`, falling back to
+    /// `Unknown Printer #<n>` (numbered in listing order) when the port is
+    /// missing or already used, so every placeholder is unique (the default)
+    #[default]
+    Placeholder,
+}
 
-                // Get default printer
-                let default_printer = get_default_printer().await;
-
-                // Mark default printer
-                if let Some(ref default_name) = default_printer {
-                    for printer in &mut printers {
-                        if printer.name() == default_name {
-                            *printer = Printer::new(
-                                printer.name().to_string(),
-                                printer.status().clone(),
-                                printer.error_state().clone(),
-                                printer.is_offline(),
-                                true, // is_default
-                            );
-                        }
-                    }
+/// Names or drops nameless rows while a listing is enumerated, keeping every
+/// placeholder unique within that listing
+#[cfg_attr(not(windows), allow(dead_code))]
+struct NamelessRows {
+    policy: NamelessPrinterPolicy,
+    seen: HashSet<String>,
+    unnamed: usize,
+}
+
+#[cfg_attr(not(windows), allow(dead_code))]
+impl NamelessRows {
+    fn new(policy: NamelessPrinterPolicy) -> Self {
+        Self {
+            policy,
+            seen: HashSet::new(),
+            unnamed: 0,
+        }
+    }
+
+    /// Returns the name to use for a row, or `None` if the row is skipped.
+    /// Blank names count as missing.
+    fn resolve(&mut self, name: Option<String>, port: Option<&str>) -> Option<String> {
+        let name = match name.filter(|name| !name.trim().is_empty()) {
+            Some(name) => name,
+            None if self.policy == NamelessPrinterPolicy::Skip => {
+                log::debug!("Skipping printer without a name (port {:?})", port);
+                return None;
+            }
+            None => {
+                self.unnamed += 1;
+                let by_port = port
+                    .map(str::trim)
+                    .filter(|port| !port.is_empty())
+                    .map(|port| format!("Unknown Printer ({})", port))
+                    .filter(|candidate| !self.seen.contains(candidate));
+                let mut number = self.unnamed;
+                let mut candidate =
+                    by_port.unwrap_or_else(|| format!("Unknown Printer #{}", number));
+                while self.seen.contains(&candidate) {
+                    number += 1;
+                    candidate = format!("Unknown Printer #{}", number);
                 }
+                candidate
             }
+        };
+        self.seen.insert(name.clone());
+        Some(name)
+    }
+}
+
+/// Applies the nameless printer policy to a full WMI listing
+#[cfg(windows)]
+fn name_rows(
+    rows: Vec<crate::printer::Win32Printer>,
+    policy: NamelessPrinterPolicy,
+) -> Vec<crate::printer::Win32Printer> {
+    let mut names = NamelessRows::new(policy);
+    rows.into_iter()
+        .filter_map(|mut row| {
+            row.name = Some(names.resolve(row.name.take(), row.port_name.as_deref())?);
+            Some(row)
+        })
+        .collect()
+}
+
+/// Win32_Printer columns every supported Windows version provides
+const WIN32_PRINTER_CORE_COLUMNS: &[&str] = &[
+    "Name",
+    "PrinterStatus",
+    "DetectedErrorState",
+    "WorkOffline",
+    "PrinterState",
+    "Default",
+];
+
+/// Win32_Printer columns that older or restricted systems may lack; they read as `None` when dropped
+const WIN32_PRINTER_OPTIONAL_COLUMNS: &[&str] = &[
+    "ExtendedPrinterStatus",
+    "ExtendedDetectedErrorState",
+    "Status",
+    "Attributes",
+    "Location",
+    "Comment",
+    "Priority",
+    "PortName",
+    "JobCountSinceLastReset",
+    "DriverName",
+    "Published",
+    "ServerName",
+];
+
+/// WQL query used to enumerate printers from `MSFT_Printer`
+#[cfg_attr(not(windows), allow(dead_code))]
+const MSFT_PRINTER_QUERY: &str = "SELECT Name, PrinterStatus, Location, Comment, Priority, \
+     PortName, DriverName, Published, ComputerName FROM MSFT_Printer";
+
+/// Builds the WQL query used to enumerate printers, leaving out the `dropped` optional columns
+#[cfg_attr(not(windows), allow(dead_code))]
+fn win32_printer_query(dropped: &[&str]) -> String {
+    let columns: Vec<&str> = WIN32_PRINTER_CORE_COLUMNS
+        .iter()
+        .chain(
+            WIN32_PRINTER_OPTIONAL_COLUMNS
+                .iter()
+                .filter(|column| !dropped.contains(column)),
+        )
+        .copied()
+        .collect();
+    format!("SELECT {} FROM Win32_Printer", columns.join(", "))
+}
+
+/// Quotes a value as a WQL string literal, escaping backslashes and quotes
+#[cfg_attr(not(windows), allow(dead_code))]
+fn wql_string_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('\'');
+    for c in value.chars() {
+        if matches!(c, '\\' | '\'') {
+            literal.push('\\');
         }
+        literal.push(c);
+    }
+    literal.push('\'');
+    literal
+}
 
-        // If no printers found via lpstat, try alternative methods
-        if printers.is_empty() {
-            warn!("No printers found via lpstat, trying alternative detection methods");
-            printers.extend(detect_printers_alternative().await?);
+/// Restricts a `SELECT` query to the row whose `Name` matches (WQL compares case-insensitively)
+#[cfg_attr(not(windows), allow(dead_code))]
+fn where_name_equals(query: &str, name: &str) -> String {
+    format!("{} WHERE Name = {}", query, wql_string_literal(name))
+}
+
+/// Checks whether a WMI error means the query referenced a property the class lacks
+#[cfg(windows)]
+fn is_missing_column(error: &wmi::WMIError) -> bool {
+    use windows::Win32::System::Wmi::{
+        WBEM_E_INVALID_PROPERTY, WBEM_E_INVALID_QUERY, WBEM_E_NOT_FOUND,
+    };
+
+    matches!(
+        error,
+        wmi::WMIError::HResultError { hres }
+            if [WBEM_E_INVALID_QUERY.0, WBEM_E_INVALID_PROPERTY.0, WBEM_E_NOT_FOUND.0]
+                .contains(hres)
+    )
+}
+
+/// The printer enumeration query of a backend, for its configured class
+#[cfg(windows)]
+struct PrinterQuery {
+    class: WmiPrinterClass,
+    /// Query adapted to the columns this system provides, resolved on first use
+    resolved: std::sync::OnceLock<String>,
+}
+
+#[cfg(windows)]
+impl PrinterQuery {
+    fn new(class: WmiPrinterClass) -> Self {
+        Self {
+            class,
+            resolved: std::sync::OnceLock::new(),
         }
+    }
 
-        Ok(printers)
+    /// Deserializes a printer row of the configured class
+    fn deserialize(
+        &self,
+        object: wmi::IWbemClassWrapper,
+    ) -> std::result::Result<crate::printer::Win32Printer, wmi::WMIError> {
+        match self.class {
+            WmiPrinterClass::Win32Printer => object.into_desr(),
+            WmiPrinterClass::MsftPrinter => object
+                .into_desr::<crate::printer::MsftPrinter>()
+                .map(Into::into),
+        }
     }
 
-    async fn find_printer(&self, name: &str) -> Result<Option<Printer>> {
-        let printers = self.list_printers().await?;
+    /// Runs `query` and deserializes every row
+    fn rows(
+        &self,
+        wmi_connection: &wmi::WMIConnection,
+        query: &str,
+    ) -> Result<Vec<crate::printer::Win32Printer>> {
+        wmi_connection
+            .exec_query(query)?
+            .map(|row| row.and_then(|object| self.deserialize(object)))
+            .collect::<std::result::Result<_, _>>()
+            .map_err(PrinterError::from)
+    }
+}
 
-        for printer in printers {
-            if printer.name().eq_ignore_ascii_case(name) {
-                return Ok(Some(printer));
-            }
+/// Returns the printer query for this connection, resolving it on first use.
+///
+/// Queries are evaluated lazily, so a missing column only surfaces when the first
+/// row is read. If the full `Win32_Printer` projection fails that way, each
+/// optional column is probed on its own and the ones the system rejects are
+/// dropped. `MSFT_Printer` has a fixed set of properties and is queried as is.
+#[cfg(windows)]
+fn printer_query<'a>(query: &'a PrinterQuery, wmi_connection: &wmi::WMIConnection) -> &'a str {
+    use log::warn;
+
+    if query.class == WmiPrinterClass::MsftPrinter {
+        return MSFT_PRINTER_QUERY;
+    }
+
+    let rejects = |query: &str| {
+        wmi_connection
+            .exec_query(query)
+            .map(|mut rows| matches!(rows.next(), Some(Err(e)) if is_missing_column(&e)))
+            .unwrap_or_else(|e| is_missing_column(&e))
+    };
+
+    query.resolved.get_or_init(|| {
+        let full = win32_printer_query(&[]);
+        if !rejects(&full) {
+            return full;
         }
 
-        Ok(None)
+        let dropped: Vec<&str> = WIN32_PRINTER_OPTIONAL_COLUMNS
+            .iter()
+            .copied()
+            .filter(|column| rejects(&format!("SELECT Name, {} FROM Win32_Printer", column)))
+            .collect();
+        warn!(
+            "Win32_Printer does not provide {}; querying without them",
+            dropped.join(", ")
+        );
+        win32_printer_query(&dropped)
+    })
+}
+
+/// WQL query used to read each printer's current color and duplex settings
+#[cfg(windows)]
+const WIN32_PRINTER_CONFIGURATION_QUERY: &str =
+    "SELECT Name, Color, Duplex FROM Win32_PrinterConfiguration";
+
+/// Current printer settings read from `Win32_PrinterConfiguration`, plus the
+/// queue length from the spooler counters
+#[cfg(windows)]
+#[derive(Debug, Clone, Copy, Default)]
+struct PrinterSettings {
+    color_mode: Option<crate::ColorMode>,
+    duplex_mode: Option<crate::DuplexMode>,
+    jobs_queued: Option<u32>,
+}
+
+/// Reads the current settings of every printer, keyed by printer name.
+///
+/// Failures are logged and yield an empty map, since settings are optional detail.
+#[cfg(windows)]
+fn query_settings(wmi_connection: &wmi::WMIConnection) -> HashMap<String, PrinterSettings> {
+    query_settings_with(wmi_connection, WIN32_PRINTER_CONFIGURATION_QUERY)
+}
+
+/// Reads printer settings using the given `Win32_PrinterConfiguration` query
+#[cfg(windows)]
+fn query_settings_with(
+    wmi_connection: &wmi::WMIConnection,
+    query: &str,
+) -> HashMap<String, PrinterSettings> {
+    use crate::printer::Win32PrinterConfiguration;
+    use log::debug;
+
+    let mut settings: HashMap<String, PrinterSettings> =
+        match wmi_connection.raw_query::<Win32PrinterConfiguration>(query) {
+            Ok(configurations) => configurations
+                .into_iter()
+                .filter_map(|configuration| {
+                    let settings = PrinterSettings {
+                        color_mode: configuration.color.and_then(crate::ColorMode::from_wmi),
+                        duplex_mode: configuration.duplex.map(crate::DuplexMode::from_wmi),
+                        jobs_queued: None,
+                    };
+                    Some((configuration.name?, settings))
+                })
+                .collect(),
+            Err(e) => {
+                debug!("Could not query printer settings: {}", e);
+                HashMap::new()
+            }
+        };
+
+    for (name, jobs) in query_queued_jobs(wmi_connection) {
+        settings.entry(name).or_default().jobs_queued = Some(jobs);
     }
+    settings
 }
 
-#[cfg(unix)]
-fn parse_lpstat_line(line: &str) -> Option<Printer> {
-    use crate::{ErrorState, PrinterStatus};
+/// Reads the spooler's `Jobs` counter (jobs currently queued) of every print queue,
+/// keyed by queue name.
+///
+/// Spooler performance counters may be disabled, which just means no counts.
+#[cfg(windows)]
+fn query_queued_jobs(wmi_connection: &wmi::WMIConnection) -> HashMap<String, u32> {
+    use serde::Deserialize;
 
-    // Example line: "printer HP_LaserJet_1020 is idle.  enabled since Mon 01 Jan 2024 12:00:00 PM UTC"
-    if let Some(rest) = line.strip_prefix("printer ") {
-        if let Some(space_pos) = rest.find(' ') {
-            let name = &rest[..space_pos];
-            let status_part = &rest[space_pos + 1..];
-
-            let (status, error_state, is_offline) = if status_part.contains("idle") {
-                (PrinterStatus::Idle, ErrorState::NoError, false)
-            } else if status_part.contains("printing") {
-                (PrinterStatus::Printing, ErrorState::NoError, false)
-            } else if status_part.contains("stopped") || status_part.contains("disabled") {
-                (PrinterStatus::Offline, ErrorState::Other, true)
-            } else {
-                (
-                    PrinterStatus::StatusUnknown,
-                    ErrorState::UnknownError,
-                    false,
-                )
-            };
+    #[derive(Deserialize)]
+    struct PrintQueueJobs {
+        #[serde(rename = "Name")]
+        name: Option<String>,
+        #[serde(rename = "Jobs")]
+        jobs: Option<u32>,
+    }
 
-            return Some(Printer::new(
-                name.to_string(),
-                status,
-                error_state,
-                is_offline,
-                false, // is_default - will be set later
-            ));
-        }
+    wmi_connection
+        .raw_query::<PrintQueueJobs>("SELECT Name, Jobs FROM Win32_PerfRawData_Spooler_PrintQueue")
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|row| Some((row.name?, row.jobs?)))
+        .collect()
+}
+
+/// Reads the spooler's `TotalPagesPrinted` counter of every print queue, keyed by queue name.
+///
+/// Spooler performance counters may be disabled, which just means no counters.
+#[cfg(windows)]
+fn query_page_counts(wmi_connection: &wmi::WMIConnection) -> HashMap<String, u64> {
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct PrintQueueCounters {
+        #[serde(rename = "Name")]
+        name: Option<String>,
+        #[serde(rename = "TotalPagesPrinted")]
+        total_pages_printed: Option<u32>,
     }
 
-    None
+    wmi_connection
+        .raw_query::<PrintQueueCounters>(
+            "SELECT Name, TotalPagesPrinted FROM Win32_PerfRawData_Spooler_PrintQueue",
+        )
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|row| Some((row.name?, u64::from(row.total_pages_printed?))))
+        .collect()
 }
 
-#[cfg(unix)]
-async fn get_default_printer() -> Option<String> {
-    use tokio::process::Command;
+/// Converts a WMI printer row, attaching its current settings when known
+#[cfg(windows)]
+fn printer_with_settings(
+    wmi_printer: crate::printer::Win32Printer,
+    settings: &HashMap<String, PrinterSettings>,
+    treat_zero_error_as_no_error: bool,
+) -> Printer {
+    let printer = Printer::from_wmi(wmi_printer, treat_zero_error_as_no_error);
+    let current = settings.get(printer.name()).copied().unwrap_or_default();
+    printer
+        .with_color_mode(current.color_mode)
+        .with_duplex_mode(current.duplex_mode)
+        .with_jobs_queued(current.jobs_queued)
+}
 
-    if let Ok(output) = Command::new("lpstat").arg("-d").output().await {
-        if output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            for line in stdout.lines() {
-                if line.starts_with("system default destination: ") {
-                    return Some(line.replace("system default destination: ", ""));
-                }
-                if line.starts_with("no system default destination") {
-                    return None;
-                }
-            }
-        }
+/// Windows backend using WMI
+#[cfg(windows)]
+pub struct WindowsBackend {
+    worker: std::sync::RwLock<WmiWorker>,
+    options: BackendOptions,
+    /// Printer query adapted to the columns this system provides
+    printer_query: std::sync::Arc<PrinterQuery>,
+}
+
+#[cfg(windows)]
+impl WindowsBackend {
+    /// Creates the backend with the given settings
+    pub(crate) fn with_options(options: &BackendOptions) -> Result<Self> {
+        Ok(Self {
+            worker: std::sync::RwLock::new(WmiWorker::spawn(options)?),
+            options: options.clone(),
+            printer_query: std::sync::Arc::new(PrinterQuery::new(options.wmi_printer_class)),
+        })
     }
 
-    None
+    /// Returns a handle to the current WMI worker
+    fn worker(&self) -> WmiWorker {
+        self.worker.read().unwrap().clone()
+    }
 }
 
-#[cfg(unix)]
-async fn detect_printers_alternative() -> Result<Vec<Printer>> {
-    use crate::{ErrorState, PrinterStatus};
-    use log::info;
-    use tokio::fs;
+/// A query executed on the WMI worker thread against its cached connections.
+///
+/// Returns `true` when the query failed with a WMI error, so that the worker
+/// drops the connections and opens fresh ones for the next query.
+#[cfg(windows)]
+type WmiJob = Box<dyn FnOnce(Result<&WmiConnections>) -> bool + Send>;
 
-    let mut printers = Vec::new();
+/// WMI connections owned by the worker thread.
+///
+/// Printers come from the namespace of the configured [`WmiPrinterClass`], while
+/// settings, jobs, drivers, counters and the spooler service are only available
+/// as `Win32_*` classes in `root\cimv2`. When the printer class lives elsewhere
+/// a second connection to `root\cimv2` serves those queries.
+#[cfg(windows)]
+struct WmiConnections {
+    printers: wmi::WMIConnection,
+    cimv2: Option<wmi::WMIConnection>,
+}
 
-    // Check for USB printers in /sys/class/usb
-    info!("Checking for USB printers in /sys/class/usb...");
-    if let Ok(_entries) = fs::read_dir("/sys/class/usb").await {
-        // This is a basic implementation - in practice you'd need to parse USB device info
-        // to identify printers by their device class
-        info!("Found USB entries, but printer detection requires more complex parsing");
+#[cfg(windows)]
+impl WmiConnections {
+    /// Opens the connections needed for the given settings
+    fn open(options: &BackendOptions) -> Result<Self> {
+        use wmi::{COMLibrary, WMIConnection};
+
+        let com = COMLibrary::new()?;
+        let printers = match options.namespace() {
+            Some(namespace) => WMIConnection::with_namespace_path(&namespace, com)?,
+            None => WMIConnection::new(com)?,
+        };
+        let cimv2 = match options.wmi_printer_class {
+            WmiPrinterClass::Win32Printer => None,
+            WmiPrinterClass::MsftPrinter => Some(WMIConnection::new(com)?),
+        };
+        Ok(Self { printers, cimv2 })
     }
 
-    // Check for parallel port printers
-    if let Ok(_) = fs::metadata("/dev/lp0").await {
-        info!("Found parallel port printer device");
-        printers.push(Printer::new(
-            "Parallel Port Printer".to_string(),
-            PrinterStatus::StatusUnknown,
-            ErrorState::UnknownError,
-            false,
-            false,
-        ));
+    /// Connection to the namespace of the printer class
+    fn printers(&self) -> &wmi::WMIConnection {
+        &self.printers
     }
 
-    // For WSL or systems without direct hardware access, we might not find any printers
-    if printers.is_empty() {
-        info!("No printers detected via alternative methods");
+    /// Connection for the `Win32_*` classes other than the printer class
+    fn cimv2(&self) -> &wmi::WMIConnection {
+        self.cimv2.as_ref().unwrap_or(&self.printers)
     }
+}
 
-    Ok(printers)
+/// Dedicated thread owning the reused WMI connections.
+///
+/// COM objects are apartment-bound and not `Send`, so the connection cannot be
+/// shared across Tokio worker threads. Instead one OS thread initializes COM
+/// once, keeps the `WMIConnection` alive and runs every query sent to it. This
+/// avoids repeating COM initialization and the `ConnectServer` handshake on each
+/// poll; `test_wmi_connection_reused_across_queries` prints the cost of a first
+/// query against that of later ones. A connection that reported a WMI error is
+/// dropped and reopened by the next query, so a broken connection does not
+/// outlive a single failure. The thread exits when the backend is dropped.
+#[cfg(windows)]
+#[derive(Clone)]
+struct WmiWorker {
+    jobs: std::sync::mpsc::Sender<WmiJob>,
 }
 
-/// Create the appropriate backend for the current platform
-pub async fn create_backend() -> Result<Box<dyn PrinterBackend>> {
-    #[cfg(windows)]
-    {
-        let backend = WindowsBackend::new().await?;
-        Ok(Box::new(backend))
+#[cfg(windows)]
+impl WmiWorker {
+    /// Starts the worker thread. The connections themselves are opened lazily on
+    /// the first query and re-attempted on later queries if opening them failed.
+    fn spawn(options: &BackendOptions) -> Result<Self> {
+        let (jobs, receiver) = std::sync::mpsc::channel::<WmiJob>();
+        let options = options.clone();
+
+        std::thread::Builder::new()
+            .name("wmi-worker".to_string())
+            .spawn(move || {
+                let mut connection: Option<WmiConnections> = None;
+
+                for job in receiver {
+                    if connection.is_none() {
+                        match WmiConnections::open(&options) {
+                            Ok(con) => connection = Some(con),
+                            Err(e) => {
+                                job(Err(e));
+                                continue;
+                            }
+                        }
+                    }
+
+                    if let Some(con) = connection.as_ref()
+                        && job(Ok(con))
+                    {
+                        connection = None;
+                    }
+                }
+            })?;
+
+        Ok(Self { jobs })
     }
 
-    #[cfg(unix)]
-    {
-        let backend = LinuxBackend::new().await?;
-        Ok(Box::new(backend))
+    /// Queues a job on the worker thread without waiting for it
+    fn submit(&self, job: WmiJob) -> Result<()> {
+        self.jobs
+            .send(job)
+            .map_err(|_| PrinterError::Other("WMI worker thread has stopped".to_string()))
     }
 
-    #[cfg(not(any(windows, unix)))]
+    /// Runs a query on the worker thread and awaits its result
+    async fn run<T, F>(&self, query: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&WmiConnections) -> Result<T> + Send + 'static,
     {
-        Err(PrinterError::PlatformNotSupported)
+        let (reply, response) = tokio::sync::oneshot::channel();
+        self.submit(Box::new(move |connection| {
+            let result = connection.and_then(query);
+            let failed = matches!(result, Err(PrinterError::WmiError(_)));
+            let _ = reply.send(result);
+            failed
+        }))?;
+
+        response
+            .await
+            .map_err(|e| PrinterError::Other(format!("Failed to execute WMI query: {}", e)))?
+    }
+}
+
+#[cfg(windows)]
+#[async_trait]
+impl PrinterBackend for WindowsBackend {
+    async fn new() -> Result<Self> {
+        use log::info;
+
+        info!("Initializing Windows WMI backend...");
+        Self::with_options(&BackendOptions::default())
+    }
+
+    async fn list_printers(&self) -> Result<Vec<Printer>> {
+        self.list_printers_up_to(usize::MAX).await
+    }
+
+    async fn list_printers_up_to(&self, limit: usize) -> Result<Vec<Printer>> {
+        use log::info;
+
+        info!("Querying printer information via WMI...");
+
+        let query = self.printer_query.clone();
+        let (wmi_printers, settings) = self
+            .worker()
+            .run(move |wmi| {
+                // Rows past the limit are never fetched or deserialized
+                let printers = wmi
+                    .printers()
+                    .exec_query(printer_query(&query, wmi.printers()))?
+                    .take(limit)
+                    .map(|row| row.and_then(|object| query.deserialize(object)))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok((printers, query_settings(wmi.cimv2())))
+            })
+            .await?;
+
+        let printers = name_rows(wmi_printers, self.options.nameless_printers)
+            .into_iter()
+            .map(|wmi_printer| {
+                printer_with_settings(
+                    wmi_printer,
+                    &settings,
+                    self.options.treat_zero_error_as_no_error,
+                )
+            })
+            .collect();
+        Ok(printers)
+    }
+
+    async fn list_printers_lenient(&self) -> Result<(Vec<Printer>, Vec<PrinterError>)> {
+        use crate::printer::Win32Printer;
+        use log::{info, warn};
+
+        info!("Querying printer information via WMI (lenient)...");
+
+        let query = self.printer_query.clone();
+        let (rows, settings) = self
+            .worker()
+            .run(move |wmi| {
+                // Deserialize row by row so one malformed queue doesn't fail the whole query
+                let rows: Vec<Result<Win32Printer>> = wmi
+                    .printers()
+                    .exec_query(printer_query(&query, wmi.printers()))
+                    .map_err(PrinterError::from)?
+                    .map(|row| {
+                        row.and_then(|object| query.deserialize(object))
+                            .map_err(PrinterError::from)
+                    })
+                    .collect();
+                Ok((rows, query_settings(wmi.cimv2())))
+            })
+            .await?;
+
+        let mut names = NamelessRows::new(self.options.nameless_printers);
+        let mut printers = Vec::new();
+        let mut errors = Vec::new();
+        for row in rows {
+            match row {
+                Ok(mut wmi_printer) => {
+                    let port = wmi_printer.port_name.as_deref();
+                    let Some(name) = names.resolve(wmi_printer.name.take(), port) else {
+                        continue;
+                    };
+                    wmi_printer.name = Some(name);
+                    printers.push(printer_with_settings(
+                        wmi_printer,
+                        &settings,
+                        self.options.treat_zero_error_as_no_error,
+                    ));
+                }
+                Err(e) => {
+                    warn!("Skipping malformed Win32_Printer row: {}", e);
+                    errors.push(e);
+                }
+            }
+        }
+
+        Ok((printers, errors))
+    }
+
+    async fn find_printer(&self, name: &str) -> Result<Option<Printer>> {
+        use log::warn;
+
+        // Ask WMI for the one row instead of enumerating every queue
+        let query = self.printer_query.clone();
+        let target = name.to_string();
+        let targeted = self
+            .worker()
+            .run(move |wmi| {
+                let printers = query.rows(
+                    wmi.printers(),
+                    &where_name_equals(printer_query(&query, wmi.printers()), &target),
+                )?;
+                let settings = query_settings_with(
+                    wmi.cimv2(),
+                    &where_name_equals(WIN32_PRINTER_CONFIGURATION_QUERY, &target),
+                );
+                Ok((printers, settings))
+            })
+            .await;
+
+        match targeted {
+            Ok((wmi_printers, settings)) => Ok(wmi_printers
+                .into_iter()
+                .map(|wmi_printer| {
+                    printer_with_settings(
+                        wmi_printer,
+                        &settings,
+                        self.options.treat_zero_error_as_no_error,
+                    )
+                })
+                .find(|printer| printer.name().to_lowercase() == name.to_lowercase())),
+            Err(e) => {
+                warn!(
+                    "Targeted query for printer '{}' failed ({}); listing all printers",
+                    name, e
+                );
+                let printers = self.list_printers().await?;
+                Ok(printers
+                    .into_iter()
+                    .find(|printer| printer.name().to_lowercase() == name.to_lowercase()))
+            }
+        }
+    }
+
+    async fn reconnect(&self) -> Result<()> {
+        use log::info;
+
+        // Dropping the old worker's sender ends its thread and releases the dead connection
+        info!("Re-initializing Windows WMI backend...");
+        let worker = WmiWorker::spawn(&self.options)?;
+        *self.worker.write().unwrap() = worker;
+        Ok(())
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            supports_jobs: true,
+            supports_events: false,
+            supports_remote: false,
+            supports_supply_levels: false,
+            supports_extended_status: true,
+        }
+    }
+
+    async fn list_printer_names(&self) -> Result<Vec<String>> {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        struct PrinterName {
+            #[serde(rename = "Name")]
+            name: Option<String>,
+            #[serde(rename = "PortName")]
+            port_name: Option<String>,
+        }
+
+        // The port is only read to name nameless rows the way a full listing does
+        let query = format!(
+            "SELECT Name, PortName FROM {}",
+            self.options.wmi_printer_class.class_name()
+        );
+        let rows = self
+            .worker()
+            .run(move |wmi| {
+                let rows: Vec<PrinterName> = wmi
+                    .printers()
+                    .raw_query(query)
+                    .map_err(PrinterError::from)?;
+                Ok(rows)
+            })
+            .await?;
+
+        let mut names = NamelessRows::new(self.options.nameless_printers);
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| names.resolve(row.name, row.port_name.as_deref()))
+            .collect())
+    }
+
+    async fn set_default_printer(&self, name: &str) -> Result<()> {
+        use windows::Win32::Graphics::Printing::SetDefaultPrinterW;
+        use windows::core::HSTRING;
+
+        let name = HSTRING::from(name);
+        // The spooler call blocks, so it must not run on an async worker thread
+        tokio::task::spawn_blocking(move || {
+            // SAFETY: `name` is a valid NUL-terminated wide string for the duration of the call
+            let succeeded = unsafe { SetDefaultPrinterW(&name) }.as_bool();
+            if succeeded {
+                Ok(())
+            } else {
+                Err(PrinterError::IoError(std::io::Error::last_os_error()))
+            }
+        })
+        .await
+        .map_err(|e| PrinterError::Other(format!("set default printer task failed: {}", e)))?
+    }
+
+    async fn set_paused(&self, name: &str, paused: bool) -> Result<()> {
+        use windows::Win32::Graphics::Printing::{
+            ClosePrinter, OpenPrinterW, PRINTER_ACCESS_ADMINISTER, PRINTER_DEFAULTSW,
+            PRINTER_HANDLE, SetPrinterW,
+        };
+        use windows::core::{HSTRING, PWSTR};
+
+        let name = HSTRING::from(name);
+        // Opening and updating the printer blocks on the spooler, so keep it off the
+        // async worker threads
+        let result = tokio::task::spawn_blocking(move || {
+            let defaults = PRINTER_DEFAULTSW {
+                pDatatype: PWSTR::null(),
+                pDevMode: std::ptr::null_mut(),
+                DesiredAccess: PRINTER_ACCESS_ADMINISTER,
+            };
+            let mut handle = PRINTER_HANDLE::default();
+
+            // SAFETY: `name` and `defaults` outlive the calls, and the handle is closed
+            // exactly once after a successful open
+            unsafe {
+                OpenPrinterW(&name, &mut handle, Some(&defaults)).and_then(|()| {
+                    let result = SetPrinterW(handle, 0, None, printer_control(paused));
+                    let _ = ClosePrinter(handle);
+                    result
+                })
+            }
+        })
+        .await
+        .map_err(|e| PrinterError::Other(format!("set paused task failed: {}", e)))?;
+
+        result.map_err(|e| PrinterError::IoError(std::io::Error::other(e)))
+    }
+
+    async fn cancel_jobs(&self, printer_name: &str, job_id: Option<u32>) -> Result<()> {
+        use serde::Deserialize;
+        use windows::Win32::System::Wmi::{IWbemContext, WBEM_FLAG_RETURN_WBEM_COMPLETE};
+        use windows::core::BSTR;
+
+        /// WBEM_E_NOT_FOUND
+        const WBEM_E_NOT_FOUND: u32 = 0x8004_1002;
+
+        #[derive(Deserialize)]
+        struct PrintJob {
+            #[serde(rename = "Name")]
+            name: Option<String>,
+        }
+
+        let printer = printer_name.to_string();
+        self.worker()
+            .run(move |wmi| {
+                // Win32_PrintJob instances are keyed by "<printer>, <job id>"
+                let job_names = match job_id {
+                    Some(job_id) => vec![format!("{}, {}", printer, job_id)],
+                    None => {
+                        let jobs: Vec<PrintJob> = wmi
+                            .cimv2()
+                            .raw_query("SELECT Name FROM Win32_PrintJob")
+                            .map_err(PrinterError::from)?;
+                        jobs.into_iter()
+                            .filter_map(|job| job.name)
+                            .filter(|name| {
+                                name.rsplit_once(", ").is_some_and(|(queue, _)| {
+                                    queue.to_lowercase() == printer.to_lowercase()
+                                })
+                            })
+                            .collect()
+                    }
+                };
+
+                for job_name in job_names {
+                    let path = format!(
+                        "Win32_PrintJob.Name=\"{}\"",
+                        job_name.replace('\\', "\\\\").replace('"', "\\\"")
+                    );
+                    // SAFETY: `path` is a valid BSTR for the duration of the synchronous call
+                    let deleted = unsafe {
+                        wmi.cimv2().svc.DeleteInstance(
+                            &BSTR::from(path),
+                            WBEM_FLAG_RETURN_WBEM_COMPLETE,
+                            None::<&IWbemContext>,
+                            None,
+                        )
+                    };
+                    match deleted {
+                        Ok(()) => {}
+                        Err(e) if e.code().0 as u32 == WBEM_E_NOT_FOUND => {
+                            if let Some(job_id) = job_id {
+                                return Err(PrinterError::JobNotFound {
+                                    printer: printer.clone(),
+                                    job_id,
+                                });
+                            }
+                        }
+                        Err(e) => return Err(PrinterError::WmiError(e.to_string())),
+                    }
+                }
+                Ok(())
+            })
+            .await
+    }
+
+    async fn spooler_running(&self) -> Result<bool> {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        struct ServiceState {
+            #[serde(rename = "State")]
+            state: Option<String>,
+        }
+
+        let rows = self
+            .worker()
+            .run(|wmi| {
+                let rows: Vec<ServiceState> = wmi
+                    .cimv2()
+                    .raw_query("SELECT State FROM Win32_Service WHERE Name = 'Spooler'")
+                    .map_err(PrinterError::from)?;
+                Ok(rows)
+            })
+            .await?;
+
+        match rows.into_iter().next() {
+            Some(row) => Ok(row
+                .state
+                .is_some_and(|state| state.eq_ignore_ascii_case("Running"))),
+            None => Err(PrinterError::WmiError(
+                "Print Spooler service is not installed".to_string(),
+            )),
+        }
+    }
+
+    async fn media_sizes(&self, name: &str) -> Result<MediaInfo> {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        struct PrinterPapers {
+            #[serde(rename = "Name")]
+            name: Option<String>,
+            #[serde(rename = "PrinterPaperNames")]
+            paper_names: Option<Vec<String>>,
+        }
+
+        #[derive(Deserialize)]
+        struct ConfiguredPaper {
+            #[serde(rename = "Name")]
+            name: Option<String>,
+            #[serde(rename = "PaperSize")]
+            paper_size: Option<String>,
+        }
+
+        let (papers, configurations) = self
+            .worker()
+            .run(|wmi| {
+                let papers: Vec<PrinterPapers> = wmi
+                    .cimv2()
+                    .raw_query("SELECT Name, PrinterPaperNames FROM Win32_Printer")
+                    .map_err(PrinterError::from)?;
+                // The configured paper size is optional detail, like the color mode
+                let configurations: Vec<ConfiguredPaper> = wmi
+                    .cimv2()
+                    .raw_query("SELECT Name, PaperSize FROM Win32_PrinterConfiguration")
+                    .unwrap_or_default();
+                Ok((papers, configurations))
+            })
+            .await?;
+
+        let is_match = |row_name: &Option<String>| {
+            row_name
+                .as_deref()
+                .is_some_and(|row_name| row_name.to_lowercase() == name.to_lowercase())
+        };
+
+        let supported = papers
+            .into_iter()
+            .find(|row| is_match(&row.name))
+            .and_then(|row| row.paper_names)
+            .unwrap_or_default();
+        let default = configurations
+            .into_iter()
+            .find(|row| is_match(&row.name))
+            .and_then(|row| row.paper_size)
+            .filter(|size| !size.trim().is_empty());
+
+        Ok(MediaInfo { supported, default })
+    }
+
+    async fn list_drivers(&self) -> Result<Vec<PrinterDriver>> {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        struct Win32PrinterDriver {
+            #[serde(rename = "Name")]
+            name: Option<String>,
+            #[serde(rename = "Version")]
+            version: Option<u32>,
+            #[serde(rename = "SupportedPlatform")]
+            supported_platform: Option<String>,
+        }
+
+        let rows: Vec<Win32PrinterDriver> = self
+            .worker()
+            .run(|wmi| {
+                wmi.cimv2()
+                    .raw_query("SELECT Name, Version, SupportedPlatform FROM Win32_PrinterDriver")
+                    .map_err(PrinterError::from)
+            })
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                Some(driver_from_wmi(
+                    &row.name?,
+                    row.version,
+                    row.supported_platform,
+                ))
+            })
+            .collect())
+    }
+
+    async fn page_count(&self, name: &str) -> Result<Option<u64>> {
+        let counts = self
+            .worker()
+            .run(|wmi| Ok(query_page_counts(wmi.cimv2())))
+            .await?;
+
+        Ok(counts
+            .into_iter()
+            .find(|(queue, _)| queue.to_lowercase() == name.to_lowercase())
+            .map(|(_, pages)| pages))
+    }
+
+    async fn snapshot_all(&self) -> Result<FleetSnapshot> {
+        use log::info;
+
+        info!("Querying printer snapshot via WMI...");
+
+        // Printers, color modes and page counters in a single worker round-trip
+        let query = self.printer_query.clone();
+        let (wmi_printers, settings, counts) = self
+            .worker()
+            .run(move |wmi| {
+                let printers = query.rows(wmi.printers(), printer_query(&query, wmi.printers()))?;
+                Ok((
+                    printers,
+                    query_settings(wmi.cimv2()),
+                    query_page_counts(wmi.cimv2()),
+                ))
+            })
+            .await?;
+
+        let printers: Vec<Printer> = name_rows(wmi_printers, self.options.nameless_printers)
+            .into_iter()
+            .map(|wmi_printer| {
+                printer_with_settings(
+                    wmi_printer,
+                    &settings,
+                    self.options.treat_zero_error_as_no_error,
+                )
+            })
+            .collect();
+        let page_counts = printers
+            .iter()
+            .filter_map(|printer| {
+                let (_, pages) = counts
+                    .iter()
+                    .find(|(queue, _)| queue.to_lowercase() == printer.name().to_lowercase())?;
+                Some((printer.name().to_string(), *pages))
+            })
+            .collect();
+
+        Ok(FleetSnapshot {
+            printers,
+            page_counts,
+        })
+    }
+
+    #[cfg(feature = "stream")]
+    fn printers_stream(&self) -> BoxStream<'_, Result<Printer>> {
+        use futures_util::stream::{self, StreamExt};
+
+        // Rows are collected on the worker thread and yielded once the query is done.
+        // Handing them over while enumerating would hold the shared worker until the
+        // consumer drained the stream, blocking every other WMI query meanwhile.
+        let worker = self.worker();
+        let treat_zero_error_as_no_error = self.options.treat_zero_error_as_no_error;
+        let mut names = NamelessRows::new(self.options.nameless_printers);
+        let query = self.printer_query.clone();
+        let rows = async move {
+            worker
+                .run(move |wmi| {
+                    let enumerator = wmi
+                        .printers()
+                        .exec_query(printer_query(&query, wmi.printers()))
+                        .map_err(PrinterError::from)?;
+                    let settings = query_settings(wmi.cimv2());
+
+                    let printers: Vec<Result<Printer>> = enumerator
+                        .filter_map(|row| {
+                            row.and_then(|object| query.deserialize(object))
+                                .map_err(PrinterError::from)
+                                .map(|mut wmi_printer| {
+                                    let port = wmi_printer.port_name.as_deref();
+                                    let name = names.resolve(wmi_printer.name.take(), port)?;
+                                    wmi_printer.name = Some(name);
+                                    Some(wmi_printer)
+                                })
+                                .transpose()
+                        })
+                        .map(|row| {
+                            row.map(|wmi_printer| {
+                                printer_with_settings(
+                                    wmi_printer,
+                                    &settings,
+                                    treat_zero_error_as_no_error,
+                                )
+                            })
+                        })
+                        .collect();
+                    Ok(printers)
+                })
+                .await
+        };
+
+        stream::once(rows)
+            .flat_map(|rows| stream::iter(rows.unwrap_or_else(|e| vec![Err(e)])))
+            .boxed()
+    }
+}
+
+/// Linux backend using CUPS commands
+#[cfg(unix)]
+pub struct LinuxBackend;
+
+#[cfg(unix)]
+impl LinuxBackend {
+    /// Lists up to `limit` printers, collecting per-entry failures.
+    ///
+    /// `lpstat` output is only parsed up to the limit, and only the printers kept
+    /// are queried for their options.
+    async fn list_lenient_up_to(&self, limit: usize) -> Result<(Vec<Printer>, Vec<PrinterError>)> {
+        use log::{info, warn};
+        use tokio::process::Command;
+
+        info!("Querying printer information via system commands...");
+
+        let mut printers = Vec::new();
+        let mut errors = Vec::new();
+
+        // Try lpstat first
+        if let Ok(output) = Command::new("lpstat").arg("-l").arg("-p").output().await
+            && output.status.success()
+        {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            (printers, errors) = parse_lpstat_output(&stdout, limit);
+
+            // Get default printer
+            let default_printer = get_default_printer().await;
+
+            // Mark default printer
+            if let Some(ref default_name) = default_printer {
+                for printer in &mut printers {
+                    if printer.name() == default_name {
+                        printer.set_default(true);
+                    }
+                }
+            }
+
+            apply_printer_details(&mut printers).await;
+        }
+
+        // If no printers found via lpstat, try alternative methods
+        if printers.is_empty() {
+            warn!("No printers found via lpstat, trying alternative detection methods");
+            printers.extend(detect_printers_alternative().await?);
+            printers.truncate(limit);
+        }
+
+        Ok((printers, errors))
+    }
+}
+
+#[cfg(unix)]
+#[async_trait]
+impl PrinterBackend for LinuxBackend {
+    async fn new() -> Result<Self> {
+        use log::info;
+        use tokio::process::Command;
+
+        info!("Initializing Linux CUPS backend...");
+
+        // Check if lpstat is available
+        let output = Command::new("which").arg("lpstat").output().await;
+
+        match output {
+            Ok(result) if result.status.success() => {
+                info!("CUPS tools found, backend ready");
+                Ok(Self)
+            }
+            _ => {
+                // Check if we can find any printers using /proc or /sys
+                info!("CUPS not found, checking for alternative printer detection methods");
+                Ok(Self)
+            }
+        }
+    }
+
+    async fn list_printers(&self) -> Result<Vec<Printer>> {
+        use log::warn;
+
+        let (printers, errors) = self.list_printers_lenient().await?;
+        for e in &errors {
+            warn!("Skipping printer entry: {}", e);
+        }
+
+        Ok(printers)
+    }
+
+    async fn list_printers_lenient(&self) -> Result<(Vec<Printer>, Vec<PrinterError>)> {
+        self.list_lenient_up_to(usize::MAX).await
+    }
+
+    async fn list_printers_up_to(&self, limit: usize) -> Result<Vec<Printer>> {
+        use log::warn;
+
+        let (printers, errors) = self.list_lenient_up_to(limit).await?;
+        for e in &errors {
+            warn!("Skipping printer entry: {}", e);
+        }
+
+        Ok(printers)
+    }
+
+    async fn find_printer(&self, name: &str) -> Result<Option<Printer>> {
+        let printers = self.list_printers().await?;
+
+        for printer in printers {
+            if printer.name().to_lowercase() == name.to_lowercase() {
+                return Ok(Some(printer));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            supports_jobs: true,
+            // CUPS state directories are watched only with the `inotify` feature
+            supports_events: cfg!(all(target_os = "linux", feature = "inotify")),
+            supports_remote: false,
+            supports_supply_levels: false,
+            supports_extended_status: false,
+        }
+    }
+
+    async fn list_printer_names(&self) -> Result<Vec<String>> {
+        use tokio::process::Command;
+
+        if let Ok(output) = Command::new("lpstat").arg("-p").output().await
+            && output.status.success()
+        {
+            let names = parse_lpstat_names(&String::from_utf8_lossy(&output.stdout));
+            if !names.is_empty() {
+                return Ok(names);
+            }
+        }
+
+        // Same fallback detection as a full listing
+        let printers = self.list_printers().await?;
+        Ok(printers
+            .into_iter()
+            .map(|printer| printer.name().to_string())
+            .collect())
+    }
+
+    async fn set_default_printer(&self, name: &str) -> Result<()> {
+        let output = set_default_command(name).output().await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(PrinterError::CupsError(format!(
+                "lpoptions -d {} failed: {}",
+                name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )))
+        }
+    }
+
+    async fn set_paused(&self, name: &str, paused: bool) -> Result<()> {
+        let mut command = queue_control_command(name, paused);
+        let output = command.output().await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(PrinterError::CupsError(format!(
+                "{} {} failed: {}",
+                command.as_std().get_program().to_string_lossy(),
+                name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )))
+        }
+    }
+
+    async fn cancel_jobs(&self, printer_name: &str, job_id: Option<u32>) -> Result<()> {
+        let output = cancel_command(printer_name, job_id).output().await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(cancel_failure(
+                printer_name,
+                job_id,
+                &String::from_utf8_lossy(&output.stderr),
+            ))
+        }
+    }
+
+    async fn media_sizes(&self, name: &str) -> Result<MediaInfo> {
+        use tokio::process::Command;
+
+        let output = Command::new("lpoptions")
+            .arg("-p")
+            .arg(name)
+            .arg("-l")
+            .output()
+            .await;
+
+        match output {
+            Ok(output) if output.status.success() => {
+                Ok(parse_page_sizes(&String::from_utf8_lossy(&output.stdout)))
+            }
+            // No PPD options (e.g. driverless queues) or lpoptions missing
+            _ => Ok(MediaInfo::default()),
+        }
+    }
+
+    async fn list_drivers(&self) -> Result<Vec<PrinterDriver>> {
+        use tokio::process::Command;
+
+        let output = Command::new("lpinfo").arg("-m").output().await?;
+        if !output.status.success() {
+            return Err(PrinterError::CupsError(format!(
+                "lpinfo -m failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(parse_lpinfo_models(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    async fn list_classes(&self) -> Result<Vec<PrinterClass>> {
+        use tokio::process::Command;
+
+        let output = Command::new("lpstat").arg("-c").output().await?;
+        if !output.status.success() {
+            return Err(PrinterError::CupsError(format!(
+                "lpstat -c failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(parse_lpstat_classes(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    async fn page_count(&self, name: &str) -> Result<Option<u64>> {
+        let Some(contents) = read_page_log().await? else {
+            return Ok(None);
+        };
+        match parse_page_log(&contents, name) {
+            Some(pages) => Ok(Some(pages)),
+            // No pages logged yet counts as 0, but only for a printer that exists
+            None => Ok(self.find_printer(name).await?.map(|_| 0)),
+        }
+    }
+
+    async fn snapshot_all(&self) -> Result<FleetSnapshot> {
+        let printers = self.list_printers().await?;
+
+        // Read the page log once for all printers
+        let page_counts = match read_page_log().await? {
+            Some(contents) => printers
+                .iter()
+                .map(|printer| {
+                    (
+                        printer.name().to_string(),
+                        parse_page_log(&contents, printer.name()).unwrap_or(0),
+                    )
+                })
+                .collect(),
+            None => HashMap::new(),
+        };
+
+        Ok(FleetSnapshot {
+            printers,
+            page_counts,
+        })
+    }
+
+    #[cfg(feature = "stream")]
+    fn printers_stream(&self) -> BoxStream<'_, Result<Printer>> {
+        use futures_util::stream::{self, StreamExt};
+        use std::process::Stdio;
+        use tokio::io::{AsyncBufReadExt, BufReader};
+        use tokio::process::Command;
+
+        let start = async {
+            let default_printer = get_default_printer().await;
+            // One job listing up front; each printer's entry is applied as it is parsed
+            let jobs = queued_jobs().await;
+            let mut child = Command::new("lpstat")
+                .arg("-l")
+                .arg("-p")
+                .stdout(Stdio::piped())
+                .kill_on_drop(true)
+                .spawn()
+                .ok();
+            let lines = child
+                .as_mut()
+                .and_then(|child| child.stdout.take())
+                .map(|stdout| BufReader::new(stdout).lines());
+
+            LpstatStream {
+                default_printer,
+                jobs,
+                lines,
+                _child: child,
+                parser: LpstatParser::default(),
+                pending: std::collections::VecDeque::new(),
+                yielded: 0,
+                finished: false,
+            }
+        };
+
+        stream::once(start)
+            .flat_map(|state| {
+                stream::unfold(state, |mut state| async move {
+                    let item = state.next_printer().await?;
+                    Some((item, state))
+                })
+            })
+            .boxed()
+    }
+}
+
+/// State of an incremental `lpstat -l -p` read for `printers_stream`
+#[cfg(all(unix, feature = "stream"))]
+struct LpstatStream {
+    default_printer: Option<String>,
+    /// Pending jobs per printer, or `None` if `lpstat -l -o` could not be run
+    jobs: Option<HashMap<String, QueuedJobs>>,
+    lines: Option<tokio::io::Lines<tokio::io::BufReader<tokio::process::ChildStdout>>>,
+    _child: Option<tokio::process::Child>,
+    parser: LpstatParser,
+    pending: std::collections::VecDeque<Printer>,
+    yielded: usize,
+    finished: bool,
+}
+
+#[cfg(all(unix, feature = "stream"))]
+impl LpstatStream {
+    /// Reads lines until a printer entry is complete, then yields it
+    async fn next_printer(&mut self) -> Option<Result<Printer>> {
+        loop {
+            if let Some(mut printer) = self.pending.pop_front() {
+                apply_printer_options(&mut printer).await;
+                self.yielded += 1;
+                return Some(Ok(printer));
+            }
+            if self.finished {
+                return None;
+            }
+
+            let line = match self.lines.as_mut() {
+                Some(lines) => lines.next_line().await,
+                None => Ok(None),
+            };
+
+            match line {
+                Ok(Some(line)) => {
+                    let results = self.parser.push_line(&line);
+                    self.enqueue(results);
+                }
+                Ok(None) => {
+                    self.finished = true;
+                    let results = std::mem::take(&mut self.parser).finish();
+                    self.enqueue(results);
+
+                    // Same fallback as list_printers when lpstat reports nothing
+                    if self.yielded == 0 && self.pending.is_empty() {
+                        match detect_printers_alternative().await {
+                            Ok(printers) => self.pending.extend(printers),
+                            Err(e) => return Some(Err(e)),
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(PrinterError::from(e)));
+                }
+            }
+        }
+    }
+
+    fn enqueue(&mut self, results: impl IntoIterator<Item = Result<Printer>>) {
+        use log::warn;
+
+        for result in results {
+            match result {
+                Ok(mut printer) => {
+                    if self.default_printer.as_deref() == Some(printer.name()) {
+                        printer.set_default(true);
+                    }
+                    if let Some(jobs) = &self.jobs {
+                        let queued = jobs.get(printer.name()).copied().unwrap_or_default();
+                        apply_job_states(&mut printer, queued);
+                    }
+                    self.pending.push_back(printer);
+                }
+                Err(e) => warn!("Skipping printer entry: {}", e),
+            }
+        }
+    }
+}
+
+/// Parses `lpstat -l -p` output, separating printer lines that could not be understood.
+///
+/// The indented detail lines following each printer are used to derive its
+/// `PrinterState` from the CUPS state reasons listed under `Alerts:`. Parsing
+/// stops once `limit` printers have been read.
+#[cfg(unix)]
+fn parse_lpstat_output(stdout: &str, limit: usize) -> (Vec<Printer>, Vec<PrinterError>) {
+    let mut parser = LpstatParser::default();
+    let mut printers = Vec::new();
+    let mut errors = Vec::new();
+
+    let mut collect = |results: Vec<Result<Printer>>, printers: &mut Vec<Printer>| {
+        for result in results {
+            match result {
+                Ok(printer) => printers.push(printer),
+                Err(e) => errors.push(e),
+            }
+        }
+    };
+
+    // A line completes at most one entry, so the limit is never overshot
+    for line in stdout.lines() {
+        if printers.len() >= limit {
+            break;
+        }
+        collect(parser.push_line(line), &mut printers);
+    }
+    if printers.len() < limit {
+        collect(parser.finish().into_iter().collect(), &mut printers);
+    }
+
+    (printers, errors)
+}
+
+/// Incremental parser for `lpstat -l -p` output.
+///
+/// A printer entry spans its `printer ...` line and the indented detail lines
+/// below it, so an entry is only complete once the next entry (or the end of
+/// the output) is reached.
+#[cfg(unix)]
+#[derive(Default)]
+struct LpstatParser {
+    current: Option<(Printer, Vec<String>)>,
+    in_printer: bool,
+}
+
+#[cfg(unix)]
+impl LpstatParser {
+    /// Feeds one line, returning the entries this line completed or rejected
+    fn push_line(&mut self, line: &str) -> Vec<Result<Printer>> {
+        let mut results = Vec::new();
+
+        if line.starts_with("printer ") {
+            results.extend(self.take_current().map(Ok));
+            match parse_lpstat_line(line) {
+                Some(printer) => {
+                    self.current = Some((printer, Vec::new()));
+                    self.in_printer = true;
+                }
+                None => {
+                    results.push(Err(PrinterError::CupsError(format!(
+                        "Unrecognized lpstat line: '{}'",
+                        line
+                    ))));
+                    self.in_printer = false;
+                }
+            }
+        } else if line.starts_with(char::is_whitespace) && self.in_printer {
+            // Example detail lines: "\tAlerts: media-empty-error toner-low-report",
+            // "\tDescription: HP LaserJet", "\tLocation: Floor 3"
+            let detail = line.trim();
+            if let Some((printer, reasons)) = self.current.as_mut() {
+                if let Some(alerts) = detail.strip_prefix("Alerts:") {
+                    reasons.extend(
+                        alerts
+                            .split([' ', ','])
+                            .filter(|reason| !reason.is_empty() && *reason != "none")
+                            .map(str::to_string),
+                    );
+                } else if let Some(description) = detail.strip_prefix("Description:") {
+                    printer.set_comment(Some(description.trim().to_string()));
+                } else if let Some(location) = detail.strip_prefix("Location:") {
+                    printer.set_location(Some(location.trim().to_string()));
+                }
+            }
+        } else {
+            self.in_printer = false;
+        }
+
+        results
+    }
+
+    /// Completes the last entry once the output has ended
+    fn finish(mut self) -> Option<Result<Printer>> {
+        self.take_current().map(Ok)
+    }
+
+    fn take_current(&mut self) -> Option<Printer> {
+        use crate::{PrinterState, PrinterStatus};
+
+        let (mut printer, reasons) = self.current.take()?;
+        let cups_state = match printer.status() {
+            PrinterStatus::Idle => 3,
+            PrinterStatus::Printing => 4,
+            PrinterStatus::Offline => 5,
+            _ => 0,
+        };
+        printer.set_state(Some(PrinterState::from_cups(cups_state, &reasons)));
+        printer.set_queue_stopped(cups_state == 5);
+        Some(printer)
+    }
+}
+
+#[cfg(unix)]
+fn parse_lpstat_line(line: &str) -> Option<Printer> {
+    use crate::{ErrorState, PrinterStatus};
+
+    // Example line: "printer HP_LaserJet_1020 is idle.  enabled since Mon 01 Jan 2024 12:00:00 PM UTC"
+    if let Some(rest) = line.strip_prefix("printer ")
+        && let Some(space_pos) = rest.find(' ')
+    {
+        let name = &rest[..space_pos];
+        let status_part = &rest[space_pos + 1..];
+
+        let (status, error_state, is_offline) = if status_part.contains("idle") {
+            (PrinterStatus::Idle, ErrorState::NoError, false)
+        } else if status_part.contains("printing") {
+            (PrinterStatus::Printing, ErrorState::NoError, false)
+        } else if status_part.contains("stopped") || status_part.contains("disabled") {
+            (PrinterStatus::Offline, ErrorState::Other, true)
+        } else {
+            (
+                PrinterStatus::StatusUnknown,
+                ErrorState::UnknownError,
+                false,
+            )
+        };
+
+        return Some(Printer::new(
+            name.to_string(),
+            status,
+            error_state,
+            is_offline,
+            false, // is_default - will be set later
+        ));
+    }
+
+    None
+}
+
+#[cfg(unix)]
+async fn get_default_printer() -> Option<String> {
+    use tokio::process::Command;
+
+    if let Ok(output) = Command::new("lpstat").arg("-d").output().await
+        && output.status.success()
+    {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for line in stdout.lines() {
+            if line.starts_with("system default destination: ") {
+                return Some(line.replace("system default destination: ", ""));
+            }
+            if line.starts_with("no system default destination") {
+                return None;
+            }
+        }
+    }
+
+    None
+}
+
+/// `SetPrinter` control code that pauses or resumes a queue
+#[cfg(windows)]
+fn printer_control(paused: bool) -> u32 {
+    use windows::Win32::Graphics::Printing::{PRINTER_CONTROL_PAUSE, PRINTER_CONTROL_RESUME};
+
+    if paused {
+        PRINTER_CONTROL_PAUSE
+    } else {
+        PRINTER_CONTROL_RESUME
+    }
+}
+
+/// Builds the `cupsdisable`/`cupsenable` command that pauses or resumes a queue
+#[cfg(unix)]
+fn queue_control_command(name: &str, paused: bool) -> tokio::process::Command {
+    let program = if paused { "cupsdisable" } else { "cupsenable" };
+    let mut command = tokio::process::Command::new(program);
+    command.arg(name);
+    command
+}
+
+/// Builds the `cancel <printer>-<id>` or `cancel -a <printer>` command
+#[cfg(unix)]
+fn cancel_command(printer_name: &str, job_id: Option<u32>) -> tokio::process::Command {
+    let mut command = tokio::process::Command::new("cancel");
+    match job_id {
+        Some(job_id) => command.arg(format!("{}-{}", printer_name, job_id)),
+        None => command.arg("-a").arg(printer_name),
+    };
+    command
+}
+
+/// Maps the stderr of a failed `cancel` to an error.
+///
+/// CUPS reports both missing jobs and missing printers as "does not exist".
+#[cfg(any(unix, test))]
+fn cancel_failure(printer_name: &str, job_id: Option<u32>, stderr: &str) -> PrinterError {
+    let message = stderr.trim();
+    let lower = message.to_lowercase();
+    if !lower.contains("does not exist") && !lower.contains("not found") {
+        return PrinterError::CupsError(format!("cancel failed: {}", message));
+    }
+
+    match job_id {
+        Some(job_id) if !lower.contains("printer") => PrinterError::JobNotFound {
+            printer: printer_name.to_string(),
+            job_id,
+        },
+        _ => PrinterError::PrinterNotFound(printer_name.to_string()),
+    }
+}
+
+/// Builds the `lpoptions -d <name>` command that sets the default destination
+#[cfg(unix)]
+fn set_default_command(name: &str) -> tokio::process::Command {
+    let mut command = tokio::process::Command::new("lpoptions");
+    command.arg("-d").arg(name);
+    command
+}
+
+/// Extracts just the printer names from `lpstat -p` output
+#[cfg(unix)]
+fn parse_lpstat_names(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("printer "))
+        .filter_map(|rest| rest.split_whitespace().next())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Pending jobs of one destination, as listed by `lpstat -l -o`
+#[cfg(unix)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct QueuedJobs {
+    /// All pending jobs
+    total: u32,
+    /// Jobs held until released (`job-hold-until-specified` and similar)
+    held: u32,
+    /// Jobs currently being processed or printed
+    processing: u32,
+}
+
+/// Collects the pending jobs per destination from `lpstat -o` or `lpstat -l -o` output.
+///
+/// Job ids have the form `<printer>-<number>`; printer names may contain dashes,
+/// so the id is split at its last one. The indented `Alerts:` line of the long
+/// format carries the job-state-reasons that tell held and processing jobs apart.
+#[cfg(unix)]
+fn parse_lpstat_jobs(stdout: &str) -> HashMap<String, QueuedJobs> {
+    let mut jobs: HashMap<String, QueuedJobs> = HashMap::new();
+    let mut current: Option<String> = None;
+    for line in stdout.lines() {
+        if line.starts_with(char::is_whitespace) {
+            let Some(alerts) = line.trim().strip_prefix("Alerts:") else {
+                continue;
+            };
+            let Some(entry) = current.as_ref().and_then(|printer| jobs.get_mut(printer)) else {
+                continue;
+            };
+            let reasons: Vec<&str> = alerts.split([' ', ',']).collect();
+            if reasons
+                .iter()
+                .any(|reason| reason.starts_with("job-hold") || reason.starts_with("job-held"))
+            {
+                entry.held += 1;
+            } else if reasons
+                .iter()
+                .any(|reason| matches!(*reason, "job-printing" | "job-transforming"))
+            {
+                entry.processing += 1;
+            }
+            continue;
+        }
+
+        current = None;
+        let Some(job_id) = line.split_whitespace().next() else {
+            continue;
+        };
+        if let Some((printer, number)) = job_id.rsplit_once('-')
+            && !printer.is_empty()
+            && number.parse::<u32>().is_ok()
+        {
+            jobs.entry(printer.to_string()).or_default().total += 1;
+            current = Some(printer.to_string());
+        }
+    }
+    jobs
+}
+
+/// Records a printer's pending jobs and lets them refine an idle state.
+///
+/// An idle queue whose jobs are all held reports [`crate::PrinterState::Waiting`],
+/// and one with a job being processed reports
+/// [`crate::PrinterState::Processing`]. Any other state is left alone, since it
+/// says more than the jobs do.
+#[cfg(unix)]
+fn apply_job_states(printer: &mut Printer, jobs: QueuedJobs) {
+    use crate::PrinterState;
+
+    printer.set_jobs_queued(Some(jobs.total));
+    if printer.state() != Some(&PrinterState::None) {
+        return;
+    }
+    if jobs.processing > 0 {
+        printer.set_state(Some(PrinterState::Processing));
+    } else if jobs.held > 0 && jobs.held == jobs.total {
+        printer.set_state(Some(PrinterState::Waiting));
+    }
+}
+
+/// Most `lpoptions` processes [`apply_printer_details`] runs at once
+#[cfg(unix)]
+const MAX_CONCURRENT_LPOPTIONS: usize = 8;
+
+/// Fills in the options of every printer from `lpoptions -p`, and their queue
+/// length and job-aware state from `lpstat -l -o`.
+///
+/// The `lpoptions` runs go up to [`MAX_CONCURRENT_LPOPTIONS`] at a time, next to
+/// the single `lpstat -l -o` run. Printers without jobs get 0; the queue is left
+/// unknown if `lpstat` cannot be run.
+#[cfg(unix)]
+async fn apply_printer_details(printers: &mut [Printer]) {
+    let names = printers
+        .iter()
+        .map(|printer| printer.name().to_string())
+        .collect();
+    let (options, jobs) = tokio::join!(
+        run_limited(names, MAX_CONCURRENT_LPOPTIONS, lpoptions),
+        queued_jobs()
+    );
+
+    for (printer, options) in printers.iter_mut().zip(options) {
+        if let Some(options) = options {
+            apply_lpoptions(printer, &options);
+        }
+    }
+    if let Some(jobs) = jobs {
+        for printer in printers {
+            let queued = jobs.get(printer.name()).copied().unwrap_or_default();
+            apply_job_states(printer, queued);
+        }
+    }
+}
+
+/// Runs `task` for every name with at most `limit` running at once, returning
+/// the results in the order of `names`. A task that panics yields `None`.
+#[cfg(unix)]
+async fn run_limited<T, F, Fut>(names: Vec<String>, limit: usize, task: F) -> Vec<Option<T>>
+where
+    F: Fn(String) -> Fut,
+    Fut: std::future::Future<Output = Option<T>> + Send + 'static,
+    T: Send + 'static,
+{
+    use log::warn;
+    use std::sync::Arc;
+    use tokio::sync::Semaphore;
+    use tokio::task::JoinSet;
+
+    let semaphore = Arc::new(Semaphore::new(limit.max(1)));
+    let mut results: Vec<Option<T>> = names.iter().map(|_| None).collect();
+    let mut tasks = JoinSet::new();
+    for (index, name) in names.into_iter().enumerate() {
+        let semaphore = Arc::clone(&semaphore);
+        let run = task(name);
+        tasks.spawn(async move {
+            // The semaphore is never closed
+            let _permit = semaphore.acquire_owned().await.ok();
+            (index, run.await)
+        });
+    }
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((index, result)) => results[index] = result,
+            Err(e) => warn!("Printer detail query failed: {}", e),
+        }
+    }
+    results
+}
+
+/// Lists the pending jobs of every printer with `lpstat -l -o`, or `None` if
+/// `lpstat` cannot be run
+#[cfg(unix)]
+async fn queued_jobs() -> Option<HashMap<String, QueuedJobs>> {
+    use tokio::process::Command;
+
+    match Command::new("lpstat").arg("-l").arg("-o").output().await {
+        Ok(output) if output.status.success() => {
+            Some(parse_lpstat_jobs(&String::from_utf8_lossy(&output.stdout)))
+        }
+        _ => None,
+    }
+}
+
+/// Accounting log CUPS writes one line per printed job (or page) to
+#[cfg(unix)]
+const CUPS_PAGE_LOG: &str = "/var/log/cups/page_log";
+
+/// Reads the CUPS page log, or `None` if page logging is disabled or the log is
+/// not readable by this user
+#[cfg(unix)]
+async fn read_page_log() -> Result<Option<String>> {
+    match tokio::fs::read_to_string(CUPS_PAGE_LOG).await {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e)
+            if matches!(
+                e.kind(),
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::PermissionDenied
+            ) =>
+        {
+            Ok(None)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Sums the pages recorded for a printer in a CUPS `page_log`.
+///
+/// Lines look like `printer user job-id [date] page-number num-copies ...`. Since
+/// CUPS 1.5 each job logs a single line with page number `total` and the job's
+/// page count in the copies column; older versions log one line per page with its
+/// number of copies. Either way the copies column is the number of pages to add.
+/// Returns `None` if the log has no line for the printer.
+#[cfg(unix)]
+fn parse_page_log(contents: &str, printer_name: &str) -> Option<u64> {
+    let mut lines = contents
+        .lines()
+        .filter(|line| {
+            line.split_whitespace()
+                .next()
+                .is_some_and(|printer| printer == printer_name)
+        })
+        .peekable();
+    lines.peek()?;
+
+    let pages = lines
+        .filter_map(|line| {
+            // The bracketed date contains a space, so read the fields after it
+            let (_, rest) = line.split_once(']')?;
+            let mut fields = rest.split_whitespace();
+            let _page = fields.next()?;
+            fields.next()?.parse::<u64>().ok()
+        })
+        .sum();
+    Some(pages)
+}
+
+/// Fills in the color mode, duplex setting and device URI of a printer from `lpoptions -p <name>`
+#[cfg(all(unix, feature = "stream"))]
+async fn apply_printer_options(printer: &mut Printer) {
+    if let Some(options) = lpoptions(printer.name().to_string()).await {
+        apply_lpoptions(printer, &options);
+    }
+}
+
+/// Runs `lpoptions -p <name>`, or returns `None` if it fails
+#[cfg(unix)]
+async fn lpoptions(name: String) -> Option<String> {
+    use tokio::process::Command;
+
+    match Command::new("lpoptions").arg("-p").arg(name).output().await {
+        Ok(output) if output.status.success() => {
+            Some(String::from_utf8_lossy(&output.stdout).into_owned())
+        }
+        _ => None,
+    }
+}
+
+/// Fills in the color mode, duplex setting and device URI of a printer from
+/// `lpoptions -p <name>` output
+#[cfg(unix)]
+fn apply_lpoptions(printer: &mut Printer, options: &str) {
+    printer.set_color_mode(parse_color_mode(options));
+    printer.set_duplex_mode(parse_duplex_mode(options));
+    printer.set_port(lpoptions_value(options, "device-uri").map(str::to_string));
+    printer.set_published(lpoptions_value(options, "printer-is-shared") == Some("true"));
+}
+
+/// Looks up a `key=value` pair in `lpoptions` output, stripping quotes
+#[cfg(unix)]
+fn lpoptions_value<'a>(output: &'a str, key: &str) -> Option<&'a str> {
+    output.split_whitespace().find_map(|pair| {
+        let value = pair.strip_prefix(key)?.strip_prefix('=')?;
+        Some(value.trim_matches(|c| c == '\'' || c == '"'))
+    })
+}
+
+/// Extracts the color mode from `lpoptions -p <name>` output.
+///
+/// Prefers the IPP `print-color-mode` option and falls back to the PPD `ColorModel`.
+#[cfg(unix)]
+fn parse_color_mode(output: &str) -> Option<crate::ColorMode> {
+    lpoptions_value(output, "print-color-mode")
+        .and_then(crate::ColorMode::from_cups)
+        .or_else(|| lpoptions_value(output, "ColorModel").and_then(crate::ColorMode::from_cups))
+}
+
+/// Extracts the duplex setting from `lpoptions -p <name>` output.
+///
+/// Prefers the IPP `sides` option and falls back to the PPD `Duplex` option.
+#[cfg(unix)]
+fn parse_duplex_mode(output: &str) -> Option<crate::DuplexMode> {
+    lpoptions_value(output, "sides")
+        .and_then(crate::DuplexMode::from_cups)
+        .or_else(|| lpoptions_value(output, "Duplex").and_then(crate::DuplexMode::from_cups))
+}
+
+/// Extracts the `PageSize` choices from `lpoptions -p <name> -l` output.
+///
+/// Each option line looks like `PageSize/Media Size: Letter *A4 Legal`, where the
+/// `*` marks the current default.
+#[cfg(unix)]
+fn parse_page_sizes(output: &str) -> MediaInfo {
+    let mut media = MediaInfo::default();
+
+    let choices = output.lines().find_map(|line| {
+        let (key, choices) = line.split_once(':')?;
+        let keyword = key.split('/').next()?.trim();
+        (keyword == "PageSize").then_some(choices)
+    });
+
+    for choice in choices.unwrap_or_default().split_whitespace() {
+        match choice.strip_prefix('*') {
+            Some(default) => {
+                media.default = Some(default.to_string());
+                media.supported.push(default.to_string());
+            }
+            None => media.supported.push(choice.to_string()),
+        }
+    }
+
+    media
+}
+
+/// Parses `lpinfo -m` output into drivers.
+///
+/// Each line holds a driver URI and the model description, e.g.
+/// `gutenprint.5.3://bjc-PIXMA-iP4000/expert Canon PIXMA iP4000 - CUPS+Gutenprint v5.3.3`.
+/// The version is taken from a trailing `v<digits>` token when present.
+#[cfg(unix)]
+fn parse_lpinfo_models(output: &str) -> Vec<PrinterDriver> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (uri, model) = line.trim().split_once(char::is_whitespace)?;
+            let model = model.trim();
+            if model.is_empty() {
+                return None;
+            }
+            let version = model
+                .rsplit(char::is_whitespace)
+                .next()
+                .and_then(|token| token.strip_prefix('v'))
+                .filter(|version| version.starts_with(|c: char| c.is_ascii_digit()))
+                .map(str::to_string);
+            Some(PrinterDriver {
+                name: model.to_string(),
+                version,
+                environment: Some(uri.to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Parses `lpstat -c` output into classes.
+///
+/// Each class starts with a `members of class <name>:` line followed by one
+/// indented line per member printer.
+#[cfg(unix)]
+fn parse_lpstat_classes(output: &str) -> Vec<PrinterClass> {
+    let mut classes: Vec<PrinterClass> = Vec::new();
+
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line.starts_with(char::is_whitespace) {
+            if let Some(class) = classes.last_mut() {
+                class.members.push(line.trim().to_string());
+            }
+        } else if let Some(name) = line
+            .trim_end()
+            .strip_suffix(':')
+            .and_then(|header| header.split_whitespace().last())
+        {
+            classes.push(PrinterClass {
+                name: name.to_string(),
+                members: Vec::new(),
+            });
+        }
+    }
+
+    classes
+}
+
+/// Builds a driver from a `Win32_PrinterDriver` row.
+///
+/// WMI names drivers `<name>,<version>,<environment>`; the separate `Version` and
+/// `SupportedPlatform` columns fill in whatever the name lacks.
+#[cfg_attr(not(windows), allow(dead_code))]
+fn driver_from_wmi(
+    name: &str,
+    version: Option<u32>,
+    supported_platform: Option<String>,
+) -> PrinterDriver {
+    let mut parts = name.rsplitn(3, ',');
+    let (environment, driver_version, driver_name) = (parts.next(), parts.next(), parts.next());
+    let non_empty = |value: &str| Some(value.trim().to_string()).filter(|value| !value.is_empty());
+
+    match driver_name {
+        Some(driver_name) => PrinterDriver {
+            name: driver_name.trim().to_string(),
+            version: driver_version
+                .and_then(non_empty)
+                .or_else(|| version.map(|v| v.to_string())),
+            environment: environment.and_then(non_empty).or(supported_platform),
+        },
+        None => PrinterDriver {
+            name: name.trim().to_string(),
+            version: version.map(|v| v.to_string()),
+            environment: supported_platform,
+        },
+    }
+}
+
+#[cfg(unix)]
+async fn detect_printers_alternative() -> Result<Vec<Printer>> {
+    use crate::{ErrorState, PrinterStatus};
+    use log::info;
+    use tokio::fs;
+
+    let mut printers = Vec::new();
+
+    // Check for USB printers in /sys/class/usb
+    info!("Checking for USB printers in /sys/class/usb...");
+    if let Ok(_entries) = fs::read_dir("/sys/class/usb").await {
+        // This is a basic implementation - in practice you'd need to parse USB device info
+        // to identify printers by their device class
+        info!("Found USB entries, but printer detection requires more complex parsing");
+    }
+
+    // Check for parallel port printers
+    if fs::metadata("/dev/lp0").await.is_ok() {
+        info!("Found parallel port printer device");
+        printers.push(Printer::new(
+            "Parallel Port Printer".to_string(),
+            PrinterStatus::StatusUnknown,
+            ErrorState::UnknownError,
+            false,
+            false,
+        ));
+    }
+
+    // For WSL or systems without direct hardware access, we might not find any printers
+    if printers.is_empty() {
+        info!("No printers detected via alternative methods");
+    }
+
+    Ok(printers)
+}
+
+/// Create the appropriate backend for the current platform
+pub async fn create_backend() -> Result<Box<dyn PrinterBackend>> {
+    create_backend_with(&BackendOptions::default()).await
+}
+
+/// Create the platform backend with the given settings
+#[cfg_attr(not(windows), allow(unused_variables))]
+pub(crate) async fn create_backend_with(
+    options: &BackendOptions,
+) -> Result<Box<dyn PrinterBackend>> {
+    #[cfg(windows)]
+    {
+        log::info!("Initializing Windows WMI backend...");
+        let backend = WindowsBackend::with_options(options)?;
+        Ok(Box::new(backend))
+    }
+
+    #[cfg(unix)]
+    {
+        let backend = LinuxBackend::new().await?;
+        Ok(Box::new(backend))
+    }
+
+    #[cfg(not(any(windows, unix)))]
+    {
+        Err(PrinterError::PlatformNotSupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_duplex_mode_from_lpoptions() {
+        use super::parse_duplex_mode;
+        use crate::DuplexMode;
+
+        let stdout = "copies=1 device-uri=ipp://10.0.0.5/ipp/print \
+                      print-color-mode=monochrome sides=two-sided-long-edge \
+                      printer-info='Office Printer'\n";
+        assert_eq!(parse_duplex_mode(stdout), Some(DuplexMode::DuplexLongEdge));
+        assert_eq!(
+            parse_duplex_mode("sides=two-sided-short-edge Duplex=None"),
+            Some(DuplexMode::DuplexShortEdge)
+        );
+        assert_eq!(
+            parse_duplex_mode("sides=one-sided"),
+            Some(DuplexMode::Simplex)
+        );
+        assert_eq!(
+            parse_duplex_mode("copies=1 Duplex=DuplexNoTumble"),
+            Some(DuplexMode::DuplexLongEdge)
+        );
+        assert_eq!(parse_duplex_mode("copies=1 printer-is-shared=false"), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_color_mode_from_lpoptions() {
+        use super::parse_color_mode;
+        use crate::ColorMode;
+
+        let stdout = "copies=1 device-uri=ipp://10.0.0.5/ipp/print finishings=3 \
+                      job-sheets=none,none marker-change-time=0 print-color-mode=monochrome \
+                      printer-info='Office Printer' printer-is-shared=false\n";
+        assert_eq!(parse_color_mode(stdout), Some(ColorMode::Monochrome));
+
+        assert_eq!(
+            parse_color_mode("print-color-mode=color ColorModel=Gray"),
+            Some(ColorMode::Color)
+        );
+        assert_eq!(
+            parse_color_mode("print-color-mode=auto"),
+            Some(ColorMode::Auto)
+        );
+        assert_eq!(
+            parse_color_mode("copies=1 ColorModel=RGB"),
+            Some(ColorMode::Color)
+        );
+        assert_eq!(parse_color_mode("copies=1 printer-is-shared=false"), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_set_default_command() {
+        use super::set_default_command;
+
+        let command = set_default_command("Office Printer");
+        let command = command.as_std();
+        assert_eq!(command.get_program(), "lpoptions");
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, ["-d", "Office Printer"]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_queue_control_command() {
+        use super::queue_control_command;
+
+        let pause = queue_control_command("HP_LaserJet", true);
+        assert_eq!(pause.as_std().get_program(), "cupsdisable");
+        assert_eq!(
+            pause.as_std().get_args().collect::<Vec<_>>(),
+            ["HP_LaserJet"]
+        );
+
+        let resume = queue_control_command("HP_LaserJet", false);
+        assert_eq!(resume.as_std().get_program(), "cupsenable");
+        assert_eq!(
+            resume.as_std().get_args().collect::<Vec<_>>(),
+            ["HP_LaserJet"]
+        );
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_printer_control_codes() {
+        use super::printer_control;
+
+        assert_eq!(printer_control(true), 1);
+        assert_eq!(printer_control(false), 2);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_cancel_command() {
+        use super::cancel_command;
+
+        let one = cancel_command("HP_LaserJet", Some(42));
+        assert_eq!(one.as_std().get_program(), "cancel");
+        assert_eq!(
+            one.as_std().get_args().collect::<Vec<_>>(),
+            ["HP_LaserJet-42"]
+        );
+
+        let all = cancel_command("HP_LaserJet", None);
+        assert_eq!(
+            all.as_std().get_args().collect::<Vec<_>>(),
+            ["-a", "HP_LaserJet"]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_cancel_failure_not_found() {
+        use super::cancel_failure;
+        use crate::PrinterError;
+
+        let error = cancel_failure(
+            "HP_LaserJet",
+            Some(42),
+            "cancel: cancel-job failed: Job #42 does not exist.\n",
+        );
+        assert!(matches!(
+            error,
+            PrinterError::JobNotFound { ref printer, job_id: 42 } if printer == "HP_LaserJet"
+        ));
+
+        let error = cancel_failure(
+            "Nope",
+            None,
+            "cancel: cancel-job failed: The printer or class does not exist.\n",
+        );
+        assert!(matches!(error, PrinterError::PrinterNotFound(ref name) if name == "Nope"));
+
+        let error = cancel_failure("HP_LaserJet", Some(42), "cancel: Forbidden\n");
+        assert!(matches!(error, PrinterError::CupsError(_)));
+    }
+
+    #[test]
+    fn test_win32_printer_query_drops_columns() {
+        use super::win32_printer_query;
+
+        assert_eq!(
+            win32_printer_query(&[]),
+            "SELECT Name, PrinterStatus, DetectedErrorState, WorkOffline, PrinterState, \
+             Default, ExtendedPrinterStatus, ExtendedDetectedErrorState, Status, Attributes, \
+             Location, Comment, Priority, PortName, JobCountSinceLastReset, DriverName, \
+             Published, ServerName FROM Win32_Printer"
+        );
+        assert_eq!(
+            win32_printer_query(&["ExtendedDetectedErrorState", "PortName"]),
+            "SELECT Name, PrinterStatus, DetectedErrorState, WorkOffline, PrinterState, \
+             Default, ExtendedPrinterStatus, Status, Attributes, Location, Comment, Priority, \
+             JobCountSinceLastReset, DriverName, Published, ServerName FROM Win32_Printer"
+        );
+        // Core columns are never dropped
+        assert!(win32_printer_query(&["Name"]).starts_with("SELECT Name, "));
+    }
+
+    #[test]
+    fn test_nameless_rows_get_unique_placeholders() {
+        use super::{NamelessPrinterPolicy, NamelessRows};
+
+        // (Name, PortName) of a WMI listing with several nameless rows
+        let rows = [
+            (None, Some("USB001")),
+            (Some("Office"), Some("IP_10.0.0.5")),
+            (None, Some("USB001")),
+            (None, None),
+            (Some(""), Some("  ")),
+            (None, None),
+        ];
+        let resolve = |policy| {
+            let mut names = NamelessRows::new(policy);
+            rows.iter()
+                .filter_map(|(name, port)| names.resolve(name.map(str::to_string), *port))
+                .collect::<Vec<_>>()
+        };
+
+        let named = resolve(NamelessPrinterPolicy::Placeholder);
+        assert_eq!(
+            named,
+            [
+                "Unknown Printer (USB001)",
+                "Office",
+                "Unknown Printer #2",
+                "Unknown Printer #3",
+                "Unknown Printer #4",
+                "Unknown Printer #5",
+            ]
+        );
+        let unique: std::collections::HashSet<_> = named.iter().collect();
+        assert_eq!(unique.len(), named.len());
+        // Same listing order gives the same placeholders
+        assert_eq!(resolve(NamelessPrinterPolicy::Placeholder), named);
+
+        assert_eq!(resolve(NamelessPrinterPolicy::Skip), ["Office"]);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_nameless_wmi_rows_do_not_collide() {
+        use super::{NamelessPrinterPolicy, name_rows};
+        use crate::printer::Win32Printer;
+
+        let row = |name: Option<&str>, port: Option<&str>| Win32Printer {
+            name: name.map(str::to_string),
+            port_name: port.map(str::to_string),
+            ..Default::default()
+        };
+        let rows = || {
+            vec![
+                row(None, Some("LPT1:")),
+                row(None, None),
+                row(Some("HP"), Some("LPT1:")),
+                row(None, None),
+            ]
+        };
+
+        let named: Vec<_> = name_rows(rows(), NamelessPrinterPolicy::Placeholder)
+            .into_iter()
+            .map(|row| row.name.unwrap())
+            .collect();
+        assert_eq!(
+            named,
+            [
+                "Unknown Printer (LPT1:)",
+                "Unknown Printer #2",
+                "HP",
+                "Unknown Printer #3",
+            ]
+        );
+        assert_eq!(name_rows(rows(), NamelessPrinterPolicy::Skip).len(), 1);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_lpinfo_models() {
+        use super::parse_lpinfo_models;
+
+        let stdout = "drv:///sample.drv/generic.ppd Generic PostScript Printer\n\
+                      gutenprint.5.3://bjc-PIXMA-iP4000/expert Canon PIXMA iP4000 - CUPS+Gutenprint v5.3.3\n\
+                      everywhere IPP Everywhere\n\
+                      lsb/usr/HP/hp-laserjet_4.ppd.gz HP LaserJet 4 Postscript (recommended)\n\
+                      \n\
+                      broken-line-without-model\n";
+        let drivers = parse_lpinfo_models(stdout);
+
+        assert_eq!(drivers.len(), 4);
+        assert_eq!(drivers[0].name, "Generic PostScript Printer");
+        assert_eq!(drivers[0].version, None);
+        assert_eq!(
+            drivers[0].environment.as_deref(),
+            Some("drv:///sample.drv/generic.ppd")
+        );
+        assert_eq!(
+            drivers[1].name,
+            "Canon PIXMA iP4000 - CUPS+Gutenprint v5.3.3"
+        );
+        assert_eq!(drivers[1].version.as_deref(), Some("5.3.3"));
+        assert_eq!(drivers[2].environment.as_deref(), Some("everywhere"));
+        assert_eq!(drivers[3].version, None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_lpstat_classes() {
+        use super::parse_lpstat_classes;
+
+        let stdout = "members of class office:\n\
+                      \tHP_LaserJet\n\
+                      \tCanon_Lobby\n\
+                      members of class empty:\n\
+                      members of class lab:\n\
+                      \tBrother\n";
+        let classes = parse_lpstat_classes(stdout);
+
+        assert_eq!(classes.len(), 3);
+        assert_eq!(classes[0].name, "office");
+        assert_eq!(classes[0].members, ["HP_LaserJet", "Canon_Lobby"]);
+        assert_eq!(classes[1].name, "empty");
+        assert!(classes[1].members.is_empty());
+        assert_eq!(classes[2].name, "lab");
+        assert_eq!(classes[2].members, ["Brother"]);
+
+        assert!(parse_lpstat_classes("").is_empty());
+    }
+
+    #[test]
+    fn test_driver_from_wmi_name() {
+        use super::driver_from_wmi;
+
+        let driver = driver_from_wmi(
+            "HP Universal Printing PCL 6,3,Windows x64",
+            Some(3),
+            Some("Windows x64".to_string()),
+        );
+        assert_eq!(driver.name, "HP Universal Printing PCL 6");
+        assert_eq!(driver.version.as_deref(), Some("3"));
+        assert_eq!(driver.environment.as_deref(), Some("Windows x64"));
+
+        // Commas inside the driver name stay part of it
+        let driver = driver_from_wmi("Brother, Inc. Driver,4,Windows NT x86", None, None);
+        assert_eq!(driver.name, "Brother, Inc. Driver");
+        assert_eq!(driver.version.as_deref(), Some("4"));
+        assert_eq!(driver.environment.as_deref(), Some("Windows NT x86"));
+
+        // A bare name falls back to the separate columns
+        let driver = driver_from_wmi("Microsoft Print To PDF", Some(4), None);
+        assert_eq!(driver.name, "Microsoft Print To PDF");
+        assert_eq!(driver.version.as_deref(), Some("4"));
+        assert_eq!(driver.environment, None);
+    }
+
+    #[test]
+    fn test_wql_name_escaping() {
+        use super::{where_name_equals, wql_string_literal};
+
+        assert_eq!(wql_string_literal("HP LaserJet"), "'HP LaserJet'");
+        assert_eq!(wql_string_literal("Kim's Printer"), r"'Kim\'s Printer'");
+        // Double quotes need no escaping inside a single-quoted literal
+        assert_eq!(wql_string_literal(r#"The "Big" One"#), r#"'The "Big" One'"#);
+        assert_eq!(
+            wql_string_literal(r"\\server\Office"),
+            r"'\\\\server\\Office'"
+        );
+        assert_eq!(wql_string_literal(""), "''");
+
+        assert_eq!(
+            where_name_equals("SELECT Name FROM Win32_Printer", "Bob's \\ Printer"),
+            r"SELECT Name FROM Win32_Printer WHERE Name = 'Bob\'s \\ Printer'"
+        );
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_missing_column_detection() {
+        use super::is_missing_column;
+        use windows::Win32::System::Wmi::{WBEM_E_ACCESS_DENIED, WBEM_E_INVALID_QUERY};
+
+        assert!(is_missing_column(&wmi::WMIError::HResultError {
+            hres: WBEM_E_INVALID_QUERY.0
+        }));
+        assert!(!is_missing_column(&wmi::WMIError::HResultError {
+            hres: WBEM_E_ACCESS_DENIED.0
+        }));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_linux_capabilities() {
+        use super::{LinuxBackend, PrinterBackend};
+
+        let capabilities = LinuxBackend.capabilities();
+        assert!(capabilities.supports_jobs);
+        assert_eq!(
+            capabilities.supports_events,
+            cfg!(all(target_os = "linux", feature = "inotify"))
+        );
+        assert!(!capabilities.supports_remote);
+        assert!(!capabilities.supports_supply_levels);
+        assert!(!capabilities.supports_extended_status);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_windows_capabilities() {
+        use super::{BackendOptions, PrinterBackend, WindowsBackend};
+
+        let backend = WindowsBackend::with_options(&BackendOptions::default()).unwrap();
+        let capabilities = backend.capabilities();
+        assert!(capabilities.supports_jobs);
+        assert!(capabilities.supports_extended_status);
+        assert!(!capabilities.supports_events);
+        assert!(!capabilities.supports_remote);
+        assert!(!capabilities.supports_supply_levels);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_page_log() {
+        use super::parse_page_log;
+
+        let log = "\
+HP_LaserJet alice 12 [03/Mar/2025:09:15:02 +0100] total 4 - localhost report.pdf A4 one-sided
+Canon_Office bob 13 [03/Mar/2025:09:20:44 +0100] total 10 - 10.0.0.7 slides.pdf A4 two-sided
+HP_LaserJet carol 14 [03/Mar/2025:10:01:10 +0100] total 2 - localhost memo.txt A4 one-sided
+HP_LaserJet dave 15 [03/Mar/2025:10:30:00 +0100] 1 3 - localhost
+HP_LaserJet dave 15 [03/Mar/2025:10:30:01 +0100] 2 3 - localhost
+HP_LaserJet_2 erin 16 [03/Mar/2025:11:00:00 +0100] total 7 - localhost big.pdf A4 one-sided
+garbage line
+";
+        assert_eq!(parse_page_log(log, "HP_LaserJet"), Some(12));
+        assert_eq!(parse_page_log(log, "Canon_Office"), Some(10));
+        assert_eq!(parse_page_log(log, "Unknown"), None);
+        assert_eq!(parse_page_log("", "HP_LaserJet"), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_device_uri_from_lpoptions() {
+        use super::lpoptions_value;
+
+        let stdout = "copies=1 device-uri=socket://192.168.1.20:9100 printer-is-shared=false";
+        assert_eq!(
+            lpoptions_value(stdout, "device-uri"),
+            Some("socket://192.168.1.20:9100")
+        );
+        assert_eq!(lpoptions_value("copies=1", "device-uri"), None);
+        assert_eq!(lpoptions_value(stdout, "printer-is-shared"), Some("false"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_page_sizes_from_lpoptions() {
+        use super::parse_page_sizes;
+
+        let stdout = "PageSize/Media Size: *A4 Letter Legal\n\
+                      Duplex/2-Sided Printing: *None DuplexNoTumble DuplexTumble\n";
+        let media = parse_page_sizes(stdout);
+        assert_eq!(media.supported, vec!["A4", "Letter", "Legal"]);
+        assert_eq!(media.default.as_deref(), Some("A4"));
+
+        let media = parse_page_sizes("PageRegion/Page Region: Letter *A4\n");
+        assert!(media.supported.is_empty());
+        assert_eq!(media.default, None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_lpstat_output_collects_malformed_lines() {
+        use super::parse_lpstat_output;
+
+        let stdout = "printer HP_LaserJet is idle.  enabled since Mon 01 Jan 2024\n\
+                      printer Broken_Queue\n\
+                      printer Canon_MF is now printing Canon_MF-42.  enabled since Mon 01 Jan 2024\n\
+                      system default destination: HP_LaserJet\n";
+
+        let (printers, errors) = parse_lpstat_output(stdout, usize::MAX);
+
+        let names: Vec<&str> = printers.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["HP_LaserJet", "Canon_MF"]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("Broken_Queue"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_lpstat_output_stops_at_limit() {
+        use super::parse_lpstat_output;
+        use crate::PrinterState;
+
+        let stdout = "printer HP_LaserJet is idle.  enabled since Mon 01 Jan 2024\n\
+                      \tAlerts: none\n\
+                      printer Canon_MF is idle.  enabled since Mon 01 Jan 2024\n\
+                      \tAlerts: media-empty-error\n\
+                      printer Brother is idle.  enabled since Mon 01 Jan 2024\n\
+                      printer Broken_Queue\n";
+
+        let (printers, errors) = parse_lpstat_output(stdout, 2);
+
+        let names: Vec<&str> = printers.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["HP_LaserJet", "Canon_MF"]);
+        // The last kept printer still gets its detail lines
+        assert_eq!(printers[1].state(), Some(&PrinterState::PaperOut));
+        // Lines past the limit are never parsed
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_lpstat_output_maps_alerts_to_state() {
+        use super::parse_lpstat_output;
+        use crate::PrinterState;
+
+        let stdout = "printer HP_LaserJet disabled since Mon 01 Jan 2024 -\n\
+                      \tmedia-empty-error\n\
+                      \tAlerts: media-empty-error toner-low-report\n\
+                      \tDescription: HP LaserJet\n\
+                      printer Canon_MF is now printing Canon_MF-42.  enabled since Mon 01 Jan 2024\n\
+                      \tAlerts: none\n\
+                      printer Brother is idle.  enabled since Mon 01 Jan 2024\n\
+                      \tAlerts: toner-low-report\n";
+
+        let (printers, errors) = parse_lpstat_output(stdout, usize::MAX);
+
+        assert!(errors.is_empty());
+        assert_eq!(printers[0].state(), Some(&PrinterState::PaperOut));
+        assert_eq!(printers[1].state(), Some(&PrinterState::Printing));
+        assert_eq!(printers[2].state(), Some(&PrinterState::TonerLow));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_stopped_queue_with_alert_is_paused() {
+        use super::parse_lpstat_output;
+        use crate::{PauseReason, PrinterState};
+
+        let stdout = "printer HP_LaserJet disabled since Mon 01 Jan 2024 -\n\
+                      \tAlerts: media-jam-error\n\
+                      printer Brother disabled since Mon 01 Jan 2024 -\n\
+                      \tpaused\n\
+                      printer Canon_MF is idle.  enabled since Mon 01 Jan 2024\n\
+                      \tAlerts: media-jam-error\n";
+
+        let (printers, _) = parse_lpstat_output(stdout, usize::MAX);
+
+        // The jam decides the state, but the queue is still stopped
+        assert_eq!(printers[0].state(), Some(&PrinterState::PaperJam));
+        assert!(printers[0].is_paused());
+        assert_eq!(printers[0].pause_reason(), Some(PauseReason::ErrorHold));
+        assert!(printers[1].is_paused());
+        assert!(!printers[2].is_paused());
+        assert_eq!(printers[2].pause_reason(), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_lpstat_output_reads_description_and_location() {
+        use super::parse_lpstat_output;
+
+        let before = "printer HP_LaserJet is idle.  enabled since Mon 01 Jan 2024\n\
+                      \tDescription: HP LaserJet\n\
+                      \tAlerts: none\n\
+                      \tLocation: Floor 3\n\
+                      printer Canon_MF is idle.  enabled since Mon 01 Jan 2024\n\
+                      \tDescription: \n\
+                      \tLocation:\n";
+        let after = "printer HP_LaserJet is idle.  enabled since Mon 01 Jan 2024\n\
+                     \tDescription: HP LaserJet (finance)\n\
+                     \tAlerts: none\n\
+                     \tLocation: Floor 5\n";
+
+        let (printers, _) = parse_lpstat_output(before, usize::MAX);
+        assert_eq!(printers[0].comment(), Some("HP LaserJet"));
+        assert_eq!(printers[0].location(), Some("Floor 3"));
+        assert_eq!(printers[1].comment(), None);
+        assert_eq!(printers[1].location(), None);
+
+        let (relocated, _) = parse_lpstat_output(after, usize::MAX);
+        let changes = printers[0].compare_with(&relocated[0]);
+        let changed: Vec<String> = changes.changes.iter().map(|c| c.description()).collect();
+        assert_eq!(
+            changed,
+            vec![
+                "Location: Some(\"Floor 3\") → Some(\"Floor 5\")",
+                "Comment: Some(\"HP LaserJet\") → Some(\"HP LaserJet (finance)\")",
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_lpstat_jobs() {
+        use super::parse_lpstat_jobs;
+
+        let stdout = "HP_LaserJet-12           alice          1024   Mon 01 Jan 2024 10:00:00\n\
+                      HP_LaserJet-13           bob            2048   Mon 01 Jan 2024 10:01:00\n\
+                      office-color-2-7         carol          512    Mon 01 Jan 2024 10:02:00\n\
+                      \n\
+                      not-a-job                dave\n";
+        let counts = parse_lpstat_jobs(stdout);
+
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["HP_LaserJet"].total, 2);
+        assert_eq!(counts["office-color-2"].total, 1);
+        assert_eq!(counts["HP_LaserJet"].held, 0);
+        assert!(parse_lpstat_jobs("").is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[cfg(unix)]
+    async fn test_run_limited_caps_concurrency_and_keeps_order() {
+        use super::run_limited;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let names = (0..5).map(|i| format!("Printer {}", i)).collect();
+        let results = run_limited(names, 2, |name| {
+            let running = Arc::clone(&running);
+            let peak = Arc::clone(&peak);
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                (name != "Printer 3").then_some(name)
+            }
+        })
+        .await;
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(results[0].as_deref(), Some("Printer 0"));
+        assert_eq!(results[3], None);
+        assert_eq!(results[4].as_deref(), Some("Printer 4"));
+    }
+
+    #[test]
+    #[cfg(all(unix, feature = "stream"))]
+    fn test_lpstat_stream_applies_queued_jobs() {
+        use super::{LpstatParser, LpstatStream, parse_lpstat_jobs};
+        use crate::PrinterState;
+
+        let jobs = "HP_LaserJet-12           alice          1024   Mon 01 Jan 2024 10:00:00\n\
+                    \tAlerts: job-printing\n";
+        let mut stream = LpstatStream {
+            default_printer: None,
+            jobs: Some(parse_lpstat_jobs(jobs)),
+            lines: None,
+            _child: None,
+            parser: LpstatParser::default(),
+            pending: std::collections::VecDeque::new(),
+            yielded: 0,
+            finished: false,
+        };
+
+        let mut parser = LpstatParser::default();
+        let mut results =
+            parser.push_line("printer HP_LaserJet is idle.  enabled since Mon 01 Jan 2024");
+        results
+            .extend(parser.push_line("printer Canon_MF is idle.  enabled since Mon 01 Jan 2024"));
+        results.extend(parser.finish());
+        stream.enqueue(results);
+
+        let printers: Vec<_> = stream.pending.iter().collect();
+        assert_eq!(printers[0].jobs_queued(), Some(1));
+        assert_eq!(printers[0].state(), Some(&PrinterState::Processing));
+        assert_eq!(printers[1].jobs_queued(), Some(0));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_held_jobs_set_waiting_state() {
+        use super::{QueuedJobs, apply_job_states, parse_lpstat_jobs, parse_lpstat_output};
+        use crate::PrinterState;
+
+        let jobs = "HP_LaserJet-12           alice          1024   Mon 01 Jan 2024 10:00:00\n\
+                    \tStatus: \n\
+                    \tAlerts: job-hold-until-specified\n\
+                    \tqueued for HP_LaserJet\n\
+                    HP_LaserJet-13           bob            2048   Mon 01 Jan 2024 10:01:00\n\
+                    \tAlerts: job-held-for-authentication\n\
+                    Canon_MF-7               carol          512    Mon 01 Jan 2024 10:02:00\n\
+                    \tAlerts: job-printing\n\
+                    Canon_MF-8               dave           512    Mon 01 Jan 2024 10:03:00\n\
+                    \tAlerts: job-hold-until-specified\n\
+                    Brother-3                erin           256    Mon 01 Jan 2024 10:04:00\n\
+                    \tAlerts: job-incoming\n";
+        let jobs = parse_lpstat_jobs(jobs);
+        assert_eq!(
+            jobs["HP_LaserJet"],
+            QueuedJobs {
+                total: 2,
+                held: 2,
+                processing: 0,
+            }
+        );
+        assert_eq!(
+            jobs["Canon_MF"],
+            QueuedJobs {
+                total: 2,
+                held: 1,
+                processing: 1,
+            }
+        );
+        assert_eq!(jobs["Brother"].held, 0);
+
+        let printers = "printer HP_LaserJet is idle.  enabled since Mon 01 Jan 2024\n\
+                        printer Canon_MF is idle.  enabled since Mon 01 Jan 2024\n\
+                        printer Brother is idle.  enabled since Mon 01 Jan 2024\n\
+                        printer Epson is idle.  enabled since Mon 01 Jan 2024\n\
+                        \tAlerts: media-jam-error\n";
+        let (mut printers, _) = parse_lpstat_output(printers, usize::MAX);
+        for printer in &mut printers {
+            let queued = jobs.get(printer.name()).copied().unwrap_or_default();
+            apply_job_states(printer, queued);
+        }
+
+        // Only held jobs: the queue waits for them to be released
+        assert_eq!(printers[0].state(), Some(&PrinterState::Waiting));
+        assert_eq!(printers[0].jobs_queued(), Some(2));
+        // A job being processed wins over a held one
+        assert_eq!(printers[1].state(), Some(&PrinterState::Processing));
+        // Jobs that are neither held nor processing leave the state alone
+        assert_eq!(printers[2].state(), Some(&PrinterState::None));
+        assert_eq!(printers[2].jobs_queued(), Some(1));
+        // Printer-level conditions are not overridden
+        assert_eq!(printers[3].state(), Some(&PrinterState::PaperJam));
+        assert_eq!(printers[3].jobs_queued(), Some(0));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_lpstat_names_matches_full_parse() {
+        use super::{parse_lpstat_names, parse_lpstat_output};
+
+        let stdout = "printer HP_LaserJet is idle.  enabled since Mon 01 Jan 2024\n\
+                      \tAlerts: none\n\
+                      printer Canon_MF is now printing Canon_MF-42.  enabled since Mon 01 Jan 2024\n\
+                      printer Brother disabled since Mon 01 Jan 2024 -\n\
+                      \treason unknown\n";
+
+        let (printers, _) = parse_lpstat_output(stdout, usize::MAX);
+        let full: Vec<&str> = printers.iter().map(|p| p.name()).collect();
+        assert_eq!(parse_lpstat_names(stdout), full);
+        assert_eq!(full, ["HP_LaserJet", "Canon_MF", "Brother"]);
+    }
+
+    #[tokio::test]
+    #[cfg(windows)]
+    async fn test_wmi_connection_reused_across_queries() {
+        use super::{PrinterBackend, WindowsBackend};
+        use std::time::Instant;
+
+        // The first query pays for COM initialization and ConnectServer
+        let backend = WindowsBackend::new().await.unwrap();
+        let started = Instant::now();
+        let first = backend.list_printers().await.unwrap();
+        let cold = started.elapsed();
+
+        let started = Instant::now();
+        for _ in 0..25 {
+            let printers = backend.list_printers().await.unwrap();
+            assert_eq!(printers.len(), first.len());
+        }
+        let warm = started.elapsed() / 25;
+
+        println!("First WMI query: {:?}, reused connection: {:?}", cold, warm);
+    }
+
+    #[tokio::test]
+    #[cfg(windows)]
+    async fn test_spooler_running() {
+        use super::{PrinterBackend, WindowsBackend};
+
+        let backend = WindowsBackend::new().await.unwrap();
+        match backend.spooler_running().await {
+            Ok(running) => println!("Print Spooler running: {}", running),
+            Err(e) => println!("Expected error in test environment: {}", e),
+        }
+    }
+}
+
+/// Scripted in-memory backend for exercising the monitor in tests
+#[cfg(test)]
+pub(crate) mod mock {
+    use super::PrinterBackend;
+    use crate::{Printer, PrinterClass, PrinterError, Result};
+    use async_trait::async_trait;
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::{Arc, Mutex};
+
+    /// Backend that returns a predefined sequence of printer lists.
+    ///
+    /// Each `list_printers` call consumes the next snapshot. Once the script is
+    /// exhausted every call fails, which makes the otherwise endless monitoring
+    /// loops return.
+    pub(crate) struct MockBackend {
+        snapshots: Mutex<VecDeque<Result<Vec<Printer>>>>,
+        malformed: Vec<String>,
+        page_counts: HashMap<String, u64>,
+        classes: Vec<PrinterClass>,
+        /// Successful queries left before the simulated connection dies
+        healthy_queries: Mutex<Option<usize>>,
+        /// Whether queries hang instead of failing once the script is exhausted
+        hang_when_exhausted: bool,
+        /// How long each upcoming query takes, consumed one per query
+        query_delays: Mutex<VecDeque<std::time::Duration>>,
+        /// How long `printers_stream` takes to produce each printer
+        entry_delay: Option<std::time::Duration>,
+        /// Where to record the limits passed to `list_printers_up_to`
+        requested_limits: Option<Arc<Mutex<Vec<usize>>>>,
+        /// What a failing `cancel` prints to stderr; `None` lets cancelling succeed
+        cancel_stderr: Option<String>,
+    }
+
+    impl MockBackend {
+        pub(crate) fn with_snapshots(snapshots: Vec<Vec<Printer>>) -> Self {
+            Self::with_results(snapshots.into_iter().map(Ok).collect())
+        }
+
+        /// Scripts failed queries in between snapshots
+        pub(crate) fn with_results(results: Vec<Result<Vec<Printer>>>) -> Self {
+            Self {
+                snapshots: Mutex::new(results.into()),
+                malformed: Vec::new(),
+                page_counts: HashMap::new(),
+                classes: Vec::new(),
+                healthy_queries: Mutex::new(None),
+                hang_when_exhausted: false,
+                query_delays: Mutex::new(VecDeque::new()),
+                entry_delay: None,
+                requested_limits: None,
+                cancel_stderr: None,
+            }
+        }
+
+        /// Simulates a connection that dies after `queries` successful queries and
+        /// fails with a retryable error until `reconnect` is called
+        pub(crate) fn failing_after(self, queries: usize) -> Self {
+            *self.healthy_queries.lock().unwrap() = Some(queries);
+            self
+        }
+
+        /// Simulates a backend that stops responding once the script is exhausted
+        pub(crate) fn hanging_when_exhausted(mut self) -> Self {
+            self.hang_when_exhausted = true;
+            self
+        }
+
+        /// Makes the next queries take the given times, one delay per query
+        pub(crate) fn with_query_delays(self, delays: Vec<std::time::Duration>) -> Self {
+            *self.query_delays.lock().unwrap() = delays.into();
+            self
+        }
+
+        /// Makes `printers_stream` take `delay` to produce each printer
+        #[cfg_attr(not(feature = "stream"), allow(dead_code))]
+        pub(crate) fn with_entry_delay(mut self, delay: std::time::Duration) -> Self {
+            self.entry_delay = Some(delay);
+            self
+        }
+
+        /// Reports page counters for the given printers
+        pub(crate) fn with_page_counts(mut self, page_counts: HashMap<String, u64>) -> Self {
+            self.page_counts = page_counts;
+            self
+        }
+
+        /// Reports the given CUPS printer classes
+        pub(crate) fn with_classes(mut self, classes: Vec<PrinterClass>) -> Self {
+            self.classes = classes;
+            self
+        }
+
+        /// Records every limit passed to `list_printers_up_to` in `limits`
+        pub(crate) fn recording_limits(mut self, limits: Arc<Mutex<Vec<usize>>>) -> Self {
+            self.requested_limits = Some(limits);
+            self
+        }
+
+        /// Makes cancelling fail with the given `cancel` stderr, mapped like the
+        /// Linux backend maps it
+        pub(crate) fn with_cancel_stderr(mut self, stderr: &str) -> Self {
+            self.cancel_stderr = Some(stderr.to_string());
+            self
+        }
+
+        /// Adds entries that fail conversion, like broken WMI rows
+        pub(crate) fn with_malformed(mut self, entries: Vec<String>) -> Self {
+            self.malformed = entries;
+            self
+        }
+
+        async fn next_snapshot(&self) -> Result<Vec<Printer>> {
+            if self.hang_when_exhausted && self.snapshots.lock().unwrap().is_empty() {
+                std::future::pending::<()>().await;
+            }
+            let delay = self.query_delays.lock().unwrap().pop_front();
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+
+            if let Some(remaining) = self.healthy_queries.lock().unwrap().as_mut() {
+                if *remaining == 0 {
+                    return Err(PrinterError::WmiError(
+                        "The RPC server is unavailable. (0x800706BA)".to_string(),
+                    ));
+                }
+                *remaining -= 1;
+            }
+
+            self.snapshots
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| Err(PrinterError::Other("mock script exhausted".to_string())))
+        }
+    }
+
+    #[async_trait]
+    impl PrinterBackend for MockBackend {
+        async fn new() -> Result<Self> {
+            Ok(Self::with_snapshots(Vec::new()))
+        }
+
+        async fn list_printers(&self) -> Result<Vec<Printer>> {
+            let printers = self.next_snapshot().await?;
+            match self.malformed.first() {
+                Some(entry) => Err(PrinterError::Other(format!("malformed entry '{}'", entry))),
+                None => Ok(printers),
+            }
+        }
+
+        async fn list_printers_lenient(&self) -> Result<(Vec<Printer>, Vec<PrinterError>)> {
+            let printers = self.next_snapshot().await?;
+            let errors = self
+                .malformed
+                .iter()
+                .map(|entry| PrinterError::Other(format!("malformed entry '{}'", entry)))
+                .collect();
+            Ok((printers, errors))
+        }
+
+        async fn list_printers_up_to(&self, limit: usize) -> Result<Vec<Printer>> {
+            if let Some(limits) = &self.requested_limits {
+                limits.lock().unwrap().push(limit);
+            }
+            let mut printers = self.list_printers().await?;
+            printers.truncate(limit);
+            Ok(printers)
+        }
+
+        async fn find_printer(&self, name: &str) -> Result<Option<Printer>> {
+            let printers = self.list_printers().await?;
+            Ok(printers
+                .into_iter()
+                .find(|printer| printer.name().to_lowercase() == name.to_lowercase()))
+        }
+
+        #[cfg(feature = "stream")]
+        fn printers_stream(&self) -> super::BoxStream<'_, Result<Printer>> {
+            use futures_util::stream::{self, StreamExt};
+
+            let delay = self.entry_delay;
+            stream::once(self.next_snapshot())
+                .flat_map(move |result| match result {
+                    Ok(printers) => stream::iter(printers)
+                        .then(move |printer| async move {
+                            if let Some(delay) = delay {
+                                tokio::time::sleep(delay).await;
+                            }
+                            Ok(printer)
+                        })
+                        .left_stream(),
+                    Err(e) => stream::iter(vec![Err(e)]).right_stream(),
+                })
+                .boxed()
+        }
+
+        async fn reconnect(&self) -> Result<()> {
+            *self.healthy_queries.lock().unwrap() = None;
+            Ok(())
+        }
+
+        async fn set_default_printer(&self, _name: &str) -> Result<()> {
+            // Whether the change "sticks" is decided by the next scripted snapshot
+            Ok(())
+        }
+
+        async fn set_paused(&self, _name: &str, _paused: bool) -> Result<()> {
+            Ok(())
+        }
+
+        async fn page_count(&self, name: &str) -> Result<Option<u64>> {
+            Ok(self.page_counts.get(name).copied())
+        }
+
+        async fn list_classes(&self) -> Result<Vec<PrinterClass>> {
+            Ok(self.classes.clone())
+        }
+
+        async fn cancel_jobs(&self, printer_name: &str, job_id: Option<u32>) -> Result<()> {
+            match &self.cancel_stderr {
+                Some(stderr) => Err(super::cancel_failure(printer_name, job_id, stderr)),
+                None => Ok(()),
+            }
+        }
     }
 }
@@ -0,0 +1,205 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+/// How long to wait between retries of a failed operation.
+///
+/// A policy maps the number of the retry (starting at 0) to a delay. Delays
+/// start at the initial delay, are multiplied by the growth factor for every
+/// further retry and never exceed the maximum delay. A fixed policy has a factor
+/// of 1, so every retry waits the same time.
+///
+/// Jitter spreads retries of many monitors apart so they do not hit a recovering
+/// service at the same moment: with a jitter of `0.2` each delay is randomly
+/// moved by up to 20% in either direction, but still never exceeds the maximum.
+/// The random values come from the system unless a seed is set with
+/// [`Self::with_jitter_seed`], which makes the delays reproducible.
+///
+/// # Example
+/// ```
+/// use printer_event_handler::BackoffPolicy;
+/// use std::time::Duration;
+///
+/// let policy = BackoffPolicy::exponential(Duration::from_secs(1), Duration::from_secs(10));
+/// assert_eq!(policy.delay(0), Duration::from_secs(1));
+/// assert_eq!(policy.delay(3), Duration::from_secs(8));
+/// assert_eq!(policy.delay(4), Duration::from_secs(10));
+///
+/// let fixed = BackoffPolicy::fixed(Duration::from_secs(5)).with_jitter(0.5);
+/// let delay = fixed.delay(7);
+/// // Never longer than the maximum, which for a fixed policy is the delay itself
+/// assert!(delay >= Duration::from_millis(2500) && delay <= Duration::from_secs(5));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackoffPolicy {
+    initial: Duration,
+    max: Duration,
+    factor: u32,
+    jitter: f64,
+    seed: Option<u64>,
+}
+
+impl BackoffPolicy {
+    /// Waits the same `delay` before every retry
+    pub fn fixed(delay: Duration) -> Self {
+        Self {
+            initial: delay,
+            max: delay,
+            factor: 1,
+            jitter: 0.0,
+            seed: None,
+        }
+    }
+
+    /// Doubles the delay after every retry, starting at `initial` and capped at `max`
+    pub fn exponential(initial: Duration, max: Duration) -> Self {
+        Self {
+            initial,
+            max: max.max(initial),
+            factor: 2,
+            jitter: 0.0,
+            seed: None,
+        }
+    }
+
+    /// Multiplies the delay by `factor` instead of 2 after every retry.
+    ///
+    /// A factor of 0 is treated as 1.
+    pub fn with_factor(mut self, factor: u32) -> Self {
+        self.factor = factor.max(1);
+        self
+    }
+
+    /// Randomly moves each delay by up to `jitter` times its length in either
+    /// direction. The value is clamped to 0.0..=1.0; 0.0 disables jitter.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = if jitter.is_nan() {
+            0.0
+        } else {
+            jitter.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Derives the jitter from `seed` instead of system randomness, so the same
+    /// policy always produces the same delays
+    pub fn with_jitter_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns the delay before the first retry
+    pub fn initial_delay(&self) -> Duration {
+        self.initial
+    }
+
+    /// Returns the longest delay the policy produces
+    pub fn max_delay(&self) -> Duration {
+        self.max
+    }
+
+    /// Returns the delay before retry number `attempt` without jitter
+    pub fn base_delay(&self, attempt: u32) -> Duration {
+        let multiplier = self.factor.checked_pow(attempt).unwrap_or(u32::MAX);
+        self.initial
+            .checked_mul(multiplier)
+            .map_or(self.max, |delay| delay.min(self.max))
+    }
+
+    /// Returns the delay before retry number `attempt`, counted from 0
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let base = self.base_delay(attempt);
+        if self.jitter == 0.0 {
+            return base;
+        }
+
+        let random = match self.seed {
+            Some(seed) => splitmix64(seed ^ u64::from(attempt).wrapping_mul(0x9E37_79B9_7F4A_7C15)),
+            None => RandomState::new().hash_one(attempt),
+        };
+        spread(base, self.jitter, random).min(self.max)
+    }
+}
+
+impl Default for BackoffPolicy {
+    /// Exponential backoff from 1 second up to 1 minute, without jitter
+    fn default() -> Self {
+        Self::exponential(Duration::from_secs(1), Duration::from_secs(60))
+    }
+}
+
+/// Moves `delay` by up to `jitter` times its length in either direction, using
+/// `random` as the source of randomness
+pub(crate) fn spread(delay: Duration, jitter: f64, random: u64) -> Duration {
+    // Uniform in -1.0..1.0 from the top 53 bits
+    let unit = (random >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0;
+    delay.mul_f64(1.0 + jitter * unit)
+}
+
+/// One step of the SplitMix64 generator, used to turn a seed into jitter
+pub(crate) fn splitmix64(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_delays_are_capped() {
+        let policy = BackoffPolicy::exponential(Duration::from_secs(1), Duration::from_secs(60));
+        let delays: Vec<u64> = (0..9).map(|n| policy.delay(n).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 32, 60, 60, 60]);
+
+        // Huge attempt numbers saturate instead of overflowing
+        assert_eq!(policy.delay(u32::MAX), Duration::from_secs(60));
+        assert_eq!(
+            BackoffPolicy::default().delay(5),
+            Duration::from_secs(32),
+            "default matches the previous hard-coded reconnect backoff"
+        );
+
+        let tripling =
+            BackoffPolicy::exponential(Duration::from_millis(100), Duration::from_secs(1))
+                .with_factor(3);
+        assert_eq!(tripling.delay(2), Duration::from_millis(900));
+        assert_eq!(tripling.delay(3), Duration::from_secs(1));
+
+        let fixed = BackoffPolicy::fixed(Duration::from_secs(5));
+        assert!((0..10).all(|n| fixed.delay(n) == Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_jitter_stays_in_bounds() {
+        let policy = BackoffPolicy::exponential(Duration::from_secs(1), Duration::from_secs(30))
+            .with_jitter(0.25);
+        for attempt in 0..12 {
+            let base = policy.base_delay(attempt);
+            for _ in 0..50 {
+                let delay = policy.delay(attempt);
+                assert!(delay >= base.mul_f64(0.75), "{:?} below {:?}", delay, base);
+                assert!(delay <= base.mul_f64(1.25), "{:?} above {:?}", delay, base);
+                assert!(delay <= Duration::from_secs(30));
+            }
+        }
+
+        // Out-of-range jitter is clamped
+        let wild = BackoffPolicy::fixed(Duration::from_secs(2)).with_jitter(5.0);
+        assert!((0..50).all(|n| wild.delay(n) <= Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_seeded_jitter_is_reproducible() {
+        let policy = BackoffPolicy::fixed(Duration::from_secs(10))
+            .with_jitter(0.5)
+            .with_jitter_seed(42);
+        let first: Vec<Duration> = (0..8).map(|n| policy.delay(n)).collect();
+        let second: Vec<Duration> = (0..8).map(|n| policy.clone().delay(n)).collect();
+        assert_eq!(first, second);
+        // Jitter actually varies between attempts
+        assert!(first.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+}
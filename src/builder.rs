@@ -0,0 +1,382 @@
+use crate::backend::{
+    BackendOptions, NamelessPrinterPolicy, PrinterBackend, WmiPrinterClass, create_backend_with,
+};
+use crate::clock::Clock;
+use crate::monitor::ErrorHandler;
+use crate::persistence::PersistenceStore;
+use crate::watch::StallHandler;
+use crate::{BackoffPolicy, ErrorAction, PrinterError, PrinterMonitor, Result};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Builder for a [`PrinterMonitor`] with non-default settings.
+///
+/// Created with [`PrinterMonitor::builder`]. Settings that only apply to one
+/// platform are ignored on the others.
+///
+/// # Example
+/// ```rust,no_run
+/// use printer_event_handler::PrinterMonitor;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let monitor = PrinterMonitor::builder()
+///         .wmi_namespace("root\\cimv2")
+///         .virtual_printer_names(vec!["Fax".to_string()])
+///         .build()
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Default)]
+pub struct PrinterMonitorBuilder {
+    backend: Option<Box<dyn PrinterBackend>>,
+    backend_options: BackendOptions,
+    clock: Option<Arc<dyn Clock>>,
+    tags: HashMap<String, Vec<String>>,
+    virtual_names: Vec<String>,
+    health_ignored: Vec<String>,
+    reconnect_on_error: bool,
+    error_handler: Option<ErrorHandler>,
+    backoff: Option<BackoffPolicy>,
+    poll_jitter: f64,
+    poll_jitter_seed: Option<u64>,
+    normalize_names: bool,
+    max_printers: Option<usize>,
+    exclude_fax: bool,
+    track_renames: bool,
+    normalize_wmi_status: bool,
+    watchdog: Option<(u32, StallHandler)>,
+    history_store: Option<Arc<dyn PersistenceStore>>,
+}
+
+impl PrinterMonitorBuilder {
+    /// Creates a builder with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses the given backend instead of creating the platform backend.
+    ///
+    /// Backend settings such as [`Self::wmi_namespace`] are ignored in that case.
+    pub fn backend(mut self, backend: Box<dyn PrinterBackend>) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    /// Connects to a different WMI namespace than the default `root\cimv2` (Windows only).
+    ///
+    /// The namespace must be a backslash- or slash-separated path starting with
+    /// `root`, e.g. `root\cimv2`; it is validated by [`Self::build`].
+    pub fn wmi_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.backend_options.wmi_namespace = Some(namespace.into());
+        self
+    }
+
+    /// Chooses how a WMI `DetectedErrorState` of 0 is interpreted (Windows only).
+    ///
+    /// The documentation defines 0 as "Unknown", but many printers report it while
+    /// working normally, so by default (`true`) it maps to [`crate::ErrorState::NoError`].
+    /// Pass `false` for the strict reading, which maps it to
+    /// [`crate::ErrorState::UnknownError`].
+    pub fn treat_zero_error_as_no_error(mut self, treat_zero_error_as_no_error: bool) -> Self {
+        self.backend_options.treat_zero_error_as_no_error = treat_zero_error_as_no_error;
+        self
+    }
+
+    /// Chooses what happens to printers WMI reports without a name (Windows only).
+    ///
+    /// By default ([`NamelessPrinterPolicy::Placeholder`]) each one gets a unique
+    /// placeholder name derived from its port or position in the listing, so they
+    /// do not collide in maps keyed by name. [`NamelessPrinterPolicy::Skip`]
+    /// leaves them out instead.
+    pub fn nameless_printers(mut self, policy: NamelessPrinterPolicy) -> Self {
+        self.backend_options.nameless_printers = policy;
+        self
+    }
+
+    /// Chooses the WMI class printers are enumerated from (Windows only).
+    ///
+    /// Defaults to [`WmiPrinterClass::Win32Printer`]. Unless a namespace is set
+    /// with [`Self::wmi_namespace`], the backend connects to the class's own
+    /// namespace, e.g. `root\StandardCimv2` for [`WmiPrinterClass::MsftPrinter`].
+    /// The other `Win32_*` queries keep using `root\cimv2`.
+    pub fn wmi_printer_class(mut self, class: WmiPrinterClass) -> Self {
+        self.backend_options.wmi_printer_class = class;
+        self
+    }
+
+    /// Replaces the clock used to timestamp detected changes (see [`PrinterMonitor::with_clock`])
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Assigns grouping tags to printers by name (see [`PrinterMonitor::with_tags`])
+    pub fn tags(mut self, tags: HashMap<String, Vec<String>>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Adds printer names to treat as virtual (see [`PrinterMonitor::with_virtual_printer_names`])
+    pub fn virtual_printer_names(mut self, names: Vec<String>) -> Self {
+        self.virtual_names = names;
+        self
+    }
+
+    /// Leaves printers out of the fleet health roll-up (see
+    /// [`PrinterMonitor::with_health_ignore_list`])
+    pub fn health_ignore_list(mut self, names: Vec<String>) -> Self {
+        self.health_ignored = names;
+        self
+    }
+
+    /// Re-initializes the backend after retryable monitoring errors (see
+    /// [`PrinterMonitor::with_reconnect_on_error`])
+    pub fn reconnect_on_error(mut self, reconnect_on_error: bool) -> Self {
+        self.reconnect_on_error = reconnect_on_error;
+        self
+    }
+
+    /// Sets the delays between backend reconnect attempts (see
+    /// [`PrinterMonitor::with_backoff_policy`])
+    pub fn backoff_policy(mut self, policy: BackoffPolicy) -> Self {
+        self.backoff = Some(policy);
+        self
+    }
+
+    /// Randomly spreads poll intervals by up to `jitter` times their length (see
+    /// [`PrinterMonitor::with_poll_jitter`])
+    pub fn poll_jitter(mut self, jitter: f64) -> Self {
+        self.poll_jitter = jitter;
+        self
+    }
+
+    /// Makes the poll jitter reproducible (see [`PrinterMonitor::with_poll_jitter_seed`])
+    pub fn poll_jitter_seed(mut self, seed: u64) -> Self {
+        self.poll_jitter_seed = Some(seed);
+        self
+    }
+
+    /// Decides whether monitoring continues after backend errors (see
+    /// [`PrinterMonitor::with_error_handler`])
+    pub fn error_handler<H>(mut self, handler: H) -> Self
+    where
+        H: Fn(&PrinterError, u32) -> ErrorAction + Send + Sync + 'static,
+    {
+        self.error_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Matches printer names across spaces and underscores in `find_printer` (see
+    /// [`PrinterMonitor::with_name_normalization`])
+    pub fn normalize_names(mut self, normalize_names: bool) -> Self {
+        self.normalize_names = normalize_names;
+        self
+    }
+
+    /// Caps how many printers a listing returns (see [`PrinterMonitor::with_max_printers`]).
+    ///
+    /// Unlimited unless set.
+    pub fn max_printers(mut self, max_printers: usize) -> Self {
+        self.max_printers = Some(max_printers);
+        self
+    }
+
+    /// Leaves fax devices out of printer listings (see [`PrinterMonitor::with_fax_excluded`])
+    pub fn exclude_fax(mut self, exclude_fax: bool) -> Self {
+        self.exclude_fax = exclude_fax;
+        self
+    }
+
+    /// Follows monitored printers across renames by their port (see
+    /// [`PrinterMonitor::with_rename_tracking`])
+    pub fn track_renames(mut self, track_renames: bool) -> Self {
+        self.track_renames = track_renames;
+        self
+    }
+
+    /// Reports a blank WMI Status as `None`, so `""` and `None` compare equal (see
+    /// [`PrinterMonitor::with_wmi_status_normalization`])
+    pub fn normalize_wmi_status(mut self, normalize_wmi_status: bool) -> Self {
+        self.normalize_wmi_status = normalize_wmi_status;
+        self
+    }
+
+    /// Reports monitoring loops that stop completing polls for `factor` polling
+    /// intervals (see [`PrinterMonitor::with_watchdog`])
+    pub fn watchdog<H>(mut self, factor: u32, on_stall: H) -> Self
+    where
+        H: Fn(std::time::Duration) + Send + Sync + 'static,
+    {
+        self.watchdog = Some((factor, Arc::new(on_stall)));
+        self
+    }
+
+    /// Persists printer availability history (see [`PrinterMonitor::with_history_store`])
+    pub fn history_store(mut self, store: Arc<dyn PersistenceStore>) -> Self {
+        self.history_store = Some(store);
+        self
+    }
+
+    /// Validates the settings and creates the monitor.
+    ///
+    /// # Errors
+    /// * `PrinterError::InvalidConfig` - If a setting is invalid
+    /// * `PrinterError::PlatformNotSupported` - If the current platform is not supported
+    /// * `PrinterError::WmiError` - If WMI initialization fails on Windows
+    pub async fn build(self) -> Result<PrinterMonitor> {
+        if let Some(namespace) = &self.backend_options.wmi_namespace {
+            validate_wmi_namespace(namespace)?;
+        }
+
+        let backend = match self.backend {
+            Some(backend) => backend,
+            None => create_backend_with(&self.backend_options).await?,
+        };
+
+        let mut monitor = PrinterMonitor::with_backend(backend)
+            .with_tags(self.tags)
+            .with_virtual_printer_names(self.virtual_names)
+            .with_health_ignore_list(self.health_ignored)
+            .with_reconnect_on_error(self.reconnect_on_error)
+            .with_name_normalization(self.normalize_names)
+            .with_fax_excluded(self.exclude_fax)
+            .with_rename_tracking(self.track_renames)
+            .with_wmi_status_normalization(self.normalize_wmi_status);
+        if let Some(clock) = self.clock {
+            monitor = monitor.with_clock(clock);
+        }
+        if let Some(max_printers) = self.max_printers {
+            monitor = monitor.with_max_printers(max_printers);
+        }
+        monitor = monitor.with_poll_jitter(self.poll_jitter);
+        if let Some(seed) = self.poll_jitter_seed {
+            monitor = monitor.with_poll_jitter_seed(seed);
+        }
+        if let Some(policy) = self.backoff {
+            monitor = monitor.with_backoff_policy(policy);
+        }
+        if let Some(handler) = self.error_handler {
+            monitor = monitor.with_error_handler(move |error, failures| handler(error, failures));
+        }
+        if let Some((factor, on_stall)) = self.watchdog {
+            monitor = monitor.with_watchdog(factor, move |stalled| on_stall(stalled));
+        }
+        if let Some(store) = self.history_store {
+            monitor = monitor.with_history_store(store);
+        }
+
+        Ok(monitor)
+    }
+}
+
+/// Checks that a WMI namespace looks like `root\segment\...`
+fn validate_wmi_namespace(namespace: &str) -> Result<()> {
+    let mut segments = namespace.split(['\\', '/']);
+
+    let valid = segments
+        .next()
+        .is_some_and(|root| root.eq_ignore_ascii_case("root"))
+        && segments.all(|segment| {
+            !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(PrinterError::InvalidConfig(format!(
+            "invalid WMI namespace '{}', expected a path like 'root\\cimv2'",
+            namespace
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wmi_namespace_validation() {
+        assert!(validate_wmi_namespace("root\\cimv2").is_ok());
+        assert!(validate_wmi_namespace("ROOT/StandardCimv2").is_ok());
+        assert!(validate_wmi_namespace("root").is_ok());
+
+        assert!(validate_wmi_namespace("").is_err());
+        assert!(validate_wmi_namespace("cimv2").is_err());
+        assert!(validate_wmi_namespace("root\\\\cimv2").is_err());
+        assert!(validate_wmi_namespace("root\\cim v2").is_err());
+    }
+
+    #[test]
+    fn test_zero_error_mapping_defaults_to_no_error() {
+        let builder = PrinterMonitor::builder();
+        assert!(builder.backend_options.treat_zero_error_as_no_error);
+
+        let builder = builder.treat_zero_error_as_no_error(false);
+        assert!(!builder.backend_options.treat_zero_error_as_no_error);
+    }
+
+    #[tokio::test]
+    async fn test_build_rejects_invalid_namespace() {
+        let result = PrinterMonitor::builder()
+            .wmi_namespace("not a namespace")
+            .build()
+            .await;
+        assert!(matches!(result, Err(PrinterError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_printer_class_selects_namespace() {
+        let builder = PrinterMonitor::builder();
+        assert_eq!(builder.backend_options.namespace(), None);
+
+        let builder = builder.wmi_printer_class(WmiPrinterClass::MsftPrinter);
+        assert_eq!(
+            builder.backend_options.namespace().as_deref(),
+            Some("root\\StandardCimv2")
+        );
+
+        // An explicit namespace wins
+        let builder = builder.wmi_namespace("root\\custom");
+        assert_eq!(
+            builder.backend_options.namespace().as_deref(),
+            Some("root\\custom")
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(windows)]
+    async fn test_windows_backend_with_msft_printer_class() {
+        let monitor = PrinterMonitor::builder()
+            .wmi_printer_class(WmiPrinterClass::MsftPrinter)
+            .build()
+            .await
+            .expect("backend should be created for MSFT_Printer");
+
+        // The connection is opened lazily; without the PrintManagement provider this may fail
+        match monitor.list_printers().await {
+            Ok(printers) => println!("Found {} printers", printers.len()),
+            Err(e) => println!("Expected error in test environment: {}", e),
+        }
+    }
+
+    #[tokio::test]
+    #[cfg(windows)]
+    async fn test_windows_backend_with_custom_namespace() {
+        let monitor = PrinterMonitor::builder()
+            .wmi_namespace("root\\cimv2")
+            .build()
+            .await
+            .expect("backend should be created with a custom namespace");
+
+        // The connection is opened lazily; without WMI access this may still fail
+        match monitor.list_printers().await {
+            Ok(printers) => println!("Found {} printers", printers.len()),
+            Err(e) => println!("Expected error in test environment: {}", e),
+        }
+    }
+}
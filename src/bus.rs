@@ -0,0 +1,162 @@
+use crate::{PrinterError, PrinterMonitor, Result, SystemChange};
+use chrono::{DateTime, Utc};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+/// A printer change broadcast by a [`PrinterEventBus`]
+#[derive(Debug, Clone)]
+pub struct PrinterEvent {
+    /// The change that was detected
+    pub change: SystemChange,
+    /// When the poll that detected the change ran
+    pub at: DateTime<Utc>,
+}
+
+/// Runs one system monitoring loop and broadcasts its changes to every subscriber.
+///
+/// Each [`PrinterMonitor`] monitoring call polls on its own, so N consumers of the
+/// same changes would mean N polling loops. The bus polls once and hands every
+/// subscriber its own copy of each [`PrinterEvent`] via [`tokio::sync::broadcast`].
+///
+/// Subscribers only receive events sent after they subscribed. A subscriber that
+/// falls more than `capacity` events behind skips the oldest ones and gets
+/// [`broadcast::error::RecvError::Lagged`]. When the loop stops (see
+/// [`Self::wait`]) receivers get [`broadcast::error::RecvError::Closed`]. Dropping
+/// the bus stops the loop.
+///
+/// # Example
+/// ```rust,no_run
+/// use printer_event_handler::{PrinterEventBus, PrinterMonitor};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let monitor = PrinterMonitor::new().await.unwrap();
+///     let bus = PrinterEventBus::start(monitor, 30000, 64);
+///
+///     let mut ui = bus.subscribe();
+///     let mut logger = bus.subscribe();
+///     tokio::spawn(async move {
+///         while let Ok(event) = logger.recv().await {
+///             println!("[{}] {}", event.at, event.change.printer_name());
+///         }
+///     });
+///
+///     while let Ok(event) = ui.recv().await {
+///         println!("UI update for {}", event.change.printer_name());
+///     }
+/// }
+/// ```
+pub struct PrinterEventBus {
+    // Only the loop holds a strong sender, so receivers see `Closed` once it stops
+    sender: broadcast::WeakSender<PrinterEvent>,
+    task: Option<JoinHandle<Result<()>>>,
+}
+
+impl PrinterEventBus {
+    /// Starts monitoring on a background task, polling every `interval_ms`.
+    ///
+    /// `capacity` is the number of events buffered per subscriber before the
+    /// oldest are dropped.
+    ///
+    /// # Panics
+    /// If `capacity` is 0, or when called outside a Tokio runtime.
+    pub fn start(monitor: PrinterMonitor, interval_ms: u64, capacity: usize) -> Self {
+        let (events, _) = broadcast::channel(capacity);
+        let sender = events.downgrade();
+
+        let task = tokio::spawn(async move {
+            monitor
+                .monitor_system_batched(interval_ms, move |changes, at| {
+                    for change in changes {
+                        // No subscribers is not an error; the event is simply dropped
+                        let _ = events.send(PrinterEvent { change, at });
+                    }
+                })
+                .await
+        });
+
+        Self {
+            sender,
+            task: Some(task),
+        }
+    }
+
+    /// Registers a new subscriber that receives all events from now on.
+    ///
+    /// If the loop has already stopped, the receiver reports `Closed` right away.
+    pub fn subscribe(&self) -> broadcast::Receiver<PrinterEvent> {
+        match self.sender.upgrade() {
+            Some(sender) => sender.subscribe(),
+            None => broadcast::channel(1).1,
+        }
+    }
+
+    /// Returns the number of currently registered subscribers
+    pub fn subscriber_count(&self) -> usize {
+        self.sender
+            .upgrade()
+            .map_or(0, |sender| sender.receiver_count())
+    }
+
+    /// Waits for the monitoring loop to stop and returns its error.
+    ///
+    /// The loop only stops when listing printers fails and cannot be recovered.
+    pub async fn wait(mut self) -> Result<()> {
+        match self.task.take() {
+            Some(task) => task
+                .await
+                .map_err(|e| PrinterError::Other(format!("event bus task failed: {}", e)))?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for PrinterEventBus {
+    fn drop(&mut self) {
+        if let Some(task) = &self.task {
+            task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::mock::MockBackend;
+    use crate::{ErrorState, Printer, PrinterStatus};
+    use broadcast::error::RecvError;
+
+    fn printer(name: &str, status: PrinterStatus) -> Printer {
+        Printer::new(name.to_string(), status, ErrorState::NoError, false, false)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_subscribers_receive_the_same_events() {
+        let backend = MockBackend::with_snapshots(vec![
+            vec![printer("HP", PrinterStatus::Idle)],
+            vec![printer("HP", PrinterStatus::Printing)],
+        ]);
+        let bus = PrinterEventBus::start(PrinterMonitor::with_backend(Box::new(backend)), 10, 16);
+        let mut ui = bus.subscribe();
+        let mut logger = bus.subscribe();
+        assert_eq!(bus.subscriber_count(), 2);
+
+        for receiver in [&mut ui, &mut logger] {
+            let added = receiver.recv().await.unwrap();
+            assert!(matches!(added.change, SystemChange::PrinterAdded(_)));
+
+            let changed = receiver.recv().await.unwrap();
+            match changed.change {
+                SystemChange::PrinterChanged { current, .. } => {
+                    assert_eq!(current.status(), &PrinterStatus::Printing);
+                }
+                other => panic!("unexpected event: {:?}", other),
+            }
+
+            // The mock script is exhausted, which ends the loop
+            assert!(matches!(receiver.recv().await, Err(RecvError::Closed)));
+        }
+
+        assert!(bus.wait().await.is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use std::sync::Mutex;
+
+/// Source of the current time used when timestamping printer changes.
+///
+/// The monitor and `PrinterChanges` take their timestamps from a `Clock` so that
+/// time-dependent behavior can be tested deterministically by swapping in a
+/// [`FixedClock`].
+pub trait Clock: Send + Sync {
+    /// Returns the current time in UTC
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock backed by the system time (the default)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always returns a fixed, manually controlled instant.
+///
+/// Intended for tests. The instant can be moved with [`FixedClock::set`] or
+/// [`FixedClock::advance`].
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use printer_event_handler::{Clock, FixedClock};
+///
+/// let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+/// let clock = FixedClock::new(start);
+/// assert_eq!(clock.now(), start);
+///
+/// clock.advance(chrono::Duration::seconds(30));
+/// assert_eq!(clock.now(), start + chrono::Duration::seconds(30));
+/// ```
+#[derive(Debug)]
+pub struct FixedClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    /// Creates a clock frozen at the given instant
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Moves the clock to the given instant
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// Moves the clock forward by the given duration
+    pub fn advance(&self, by: chrono::Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now += by;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fixed_clock_set_and_advance() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let clock = FixedClock::new(start);
+        assert_eq!(clock.now(), start);
+
+        clock.advance(chrono::Duration::minutes(5));
+        assert_eq!(clock.now(), start + chrono::Duration::minutes(5));
+
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+    }
+}
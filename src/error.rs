@@ -9,14 +9,147 @@ pub enum PrinterError {
     CupsError(String),
     /// Printer was not found
     PrinterNotFound(String),
+    /// A print job does not exist on the given printer
+    JobNotFound { printer: String, job_id: u32 },
     /// Platform not supported
     PlatformNotSupported,
     /// General I/O error
     IoError(std::io::Error),
+    /// An operation did not complete within its time limit
+    Timeout(String),
+    /// A monitor or backend setting is invalid
+    InvalidConfig(String),
     /// Other errors
     Other(String),
 }
 
+/// Broad category of a [`PrinterError`], for handling errors without matching variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The platform printer service (WMI or CUPS) failed
+    Backend,
+    /// The requested printer does not exist
+    NotFound,
+    /// The current platform is not supported
+    Unsupported,
+    /// An operating system I/O error
+    Io,
+    /// An operation ran out of time
+    Timeout,
+    /// The monitor was configured with invalid settings
+    InvalidConfig,
+    /// Anything else
+    Other,
+}
+
+/// Message fragments that mark a WMI or CUPS failure as transient
+const TRANSIENT_MARKERS: &[&str] = &[
+    "busy",
+    "timeout",
+    "timed out",
+    "temporarily",
+    "unavailable",
+    "not running",
+    "unable to connect",
+    "0x800706ba", // RPC_S_SERVER_UNAVAILABLE
+    "0x800706be", // RPC_S_CALL_FAILED
+    "0x80041006", // WBEM_E_OUT_OF_MEMORY
+    "0x80041032", // WBEM_E_CALL_CANCELLED
+];
+
+impl PrinterError {
+    /// Returns the broad category of this error.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorKind, PrinterError};
+    ///
+    /// let error = PrinterError::PrinterNotFound("HP".to_string());
+    /// assert_eq!(error.kind(), ErrorKind::NotFound);
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            PrinterError::WmiError(_) | PrinterError::CupsError(_) => ErrorKind::Backend,
+            PrinterError::PrinterNotFound(_) | PrinterError::JobNotFound { .. } => {
+                ErrorKind::NotFound
+            }
+            PrinterError::PlatformNotSupported => ErrorKind::Unsupported,
+            PrinterError::IoError(_) => ErrorKind::Io,
+            PrinterError::Timeout(_) => ErrorKind::Timeout,
+            PrinterError::InvalidConfig(_) => ErrorKind::InvalidConfig,
+            PrinterError::Other(_) => ErrorKind::Other,
+        }
+    }
+
+    /// Returns a stable identifier for the error variant.
+    ///
+    /// Unlike the [`Display`](fmt::Display) text, codes never change between
+    /// releases, so external systems (FFI callers, structured logs) can branch on
+    /// them.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::PrinterError;
+    ///
+    /// let error = PrinterError::PrinterNotFound("HP".to_string());
+    /// assert_eq!(error.code(), "PRINTER_NOT_FOUND");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            PrinterError::WmiError(_) => "WMI_ERROR",
+            PrinterError::CupsError(_) => "CUPS_ERROR",
+            PrinterError::PrinterNotFound(_) => "PRINTER_NOT_FOUND",
+            PrinterError::JobNotFound { .. } => "JOB_NOT_FOUND",
+            PrinterError::PlatformNotSupported => "PLATFORM_NOT_SUPPORTED",
+            PrinterError::IoError(_) => "IO_ERROR",
+            PrinterError::Timeout(_) => "TIMEOUT",
+            PrinterError::InvalidConfig(_) => "INVALID_CONFIG",
+            PrinterError::Other(_) => "OTHER",
+        }
+    }
+
+    /// Checks whether retrying the failed operation may succeed.
+    ///
+    /// WMI/CUPS errors are retryable when they report a busy, timed-out or
+    /// unavailable service; I/O errors when their kind is transient (timeouts,
+    /// interruptions, dropped connections). Timeouts are always retryable. A
+    /// missing printer, an unsupported platform, invalid configuration and other
+    /// errors are never retryable.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::PrinterError;
+    ///
+    /// assert!(PrinterError::WmiError("Call timed out".to_string()).is_retryable());
+    /// assert!(!PrinterError::PlatformNotSupported.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            PrinterError::WmiError(msg) | PrinterError::CupsError(msg) => {
+                let msg = msg.to_lowercase();
+                TRANSIENT_MARKERS.iter().any(|marker| msg.contains(marker))
+            }
+            PrinterError::IoError(err) => matches!(
+                err.kind(),
+                std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::Interrupted
+                    | std::io::ErrorKind::WouldBlock
+                    | std::io::ErrorKind::ConnectionRefused
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::ResourceBusy
+            ),
+            PrinterError::Timeout(_) => true,
+            PrinterError::PrinterNotFound(_)
+            | PrinterError::JobNotFound { .. }
+            | PrinterError::PlatformNotSupported
+            | PrinterError::InvalidConfig(_)
+            | PrinterError::Other(_) => false,
+        }
+    }
+}
+
 impl fmt::Display for PrinterError {
     /// Formats the error for display to users
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -24,10 +157,15 @@ impl fmt::Display for PrinterError {
             PrinterError::WmiError(msg) => write!(f, "WMI error: {}", msg),
             PrinterError::CupsError(msg) => write!(f, "CUPS error: {}", msg),
             PrinterError::PrinterNotFound(name) => write!(f, "Printer '{}' not found", name),
+            PrinterError::JobNotFound { printer, job_id } => {
+                write!(f, "Job {} not found on printer '{}'", job_id, printer)
+            }
             PrinterError::PlatformNotSupported => {
                 write!(f, "This platform is not supported")
             }
             PrinterError::IoError(err) => write!(f, "I/O error: {}", err),
+            PrinterError::Timeout(msg) => write!(f, "Timed out: {}", msg),
+            PrinterError::InvalidConfig(msg) => write!(f, "Invalid configuration: {}", msg),
             PrinterError::Other(msg) => write!(f, "{}", msg),
         }
     }
@@ -64,3 +202,113 @@ impl From<Box<dyn std::error::Error>> for PrinterError {
         PrinterError::Other(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_retryability_per_variant() {
+        assert!(PrinterError::WmiError("Server is busy".to_string()).is_retryable());
+        assert!(PrinterError::WmiError("HRESULT 0x800706BA".to_string()).is_retryable());
+        assert!(!PrinterError::WmiError("Invalid query".to_string()).is_retryable());
+
+        assert!(PrinterError::CupsError("scheduler is not running".to_string()).is_retryable());
+        assert!(!PrinterError::CupsError("Unrecognized lpstat line".to_string()).is_retryable());
+
+        assert!(PrinterError::IoError(io::Error::from(io::ErrorKind::TimedOut)).is_retryable());
+        assert!(PrinterError::IoError(io::Error::from(io::ErrorKind::Interrupted)).is_retryable());
+        assert!(!PrinterError::IoError(io::Error::from(io::ErrorKind::NotFound)).is_retryable());
+        assert!(
+            !PrinterError::IoError(io::Error::from(io::ErrorKind::PermissionDenied)).is_retryable()
+        );
+
+        assert!(PrinterError::Timeout("waiting for HP".to_string()).is_retryable());
+
+        assert!(!PrinterError::PrinterNotFound("HP".to_string()).is_retryable());
+        assert!(!PrinterError::PlatformNotSupported.is_retryable());
+        assert!(!PrinterError::InvalidConfig("namespace".to_string()).is_retryable());
+        assert!(!PrinterError::Other("timed out".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn test_error_kinds() {
+        assert_eq!(
+            PrinterError::WmiError(String::new()).kind(),
+            ErrorKind::Backend
+        );
+        assert_eq!(
+            PrinterError::CupsError(String::new()).kind(),
+            ErrorKind::Backend
+        );
+        assert_eq!(
+            PrinterError::PrinterNotFound(String::new()).kind(),
+            ErrorKind::NotFound
+        );
+        assert_eq!(
+            PrinterError::JobNotFound {
+                printer: String::new(),
+                job_id: 1
+            }
+            .kind(),
+            ErrorKind::NotFound
+        );
+        assert_eq!(
+            PrinterError::PlatformNotSupported.kind(),
+            ErrorKind::Unsupported
+        );
+        assert_eq!(
+            PrinterError::IoError(io::Error::from(io::ErrorKind::Other)).kind(),
+            ErrorKind::Io
+        );
+        assert_eq!(
+            PrinterError::Timeout(String::new()).kind(),
+            ErrorKind::Timeout
+        );
+        assert_eq!(
+            PrinterError::InvalidConfig(String::new()).kind(),
+            ErrorKind::InvalidConfig
+        );
+        assert_eq!(PrinterError::Other(String::new()).kind(), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let cases = [
+            (PrinterError::WmiError("busy".to_string()), "WMI_ERROR"),
+            (PrinterError::CupsError("busy".to_string()), "CUPS_ERROR"),
+            (
+                PrinterError::PrinterNotFound("HP".to_string()),
+                "PRINTER_NOT_FOUND",
+            ),
+            (
+                PrinterError::JobNotFound {
+                    printer: "HP".to_string(),
+                    job_id: 7,
+                },
+                "JOB_NOT_FOUND",
+            ),
+            (PrinterError::PlatformNotSupported, "PLATFORM_NOT_SUPPORTED"),
+            (
+                PrinterError::IoError(io::Error::from(io::ErrorKind::Other)),
+                "IO_ERROR",
+            ),
+            (PrinterError::Timeout("HP".to_string()), "TIMEOUT"),
+            (
+                PrinterError::InvalidConfig("namespace".to_string()),
+                "INVALID_CONFIG",
+            ),
+            (PrinterError::Other("anything".to_string()), "OTHER"),
+        ];
+        for (error, code) in &cases {
+            assert_eq!(error.code(), *code, "{}", error);
+        }
+
+        // Codes do not depend on the message
+        assert_eq!(
+            PrinterError::WmiError("a".to_string()).code(),
+            PrinterError::WmiError("b".to_string()).code()
+        );
+    }
+}
@@ -56,15 +56,37 @@
 //! ```
 
 pub mod backend;
+pub mod backoff;
+pub mod builder;
+pub mod bus;
+pub mod clock;
 pub mod error;
 pub mod monitor;
+pub mod persistence;
 pub mod printer;
+pub mod stats;
+mod watch;
 
-pub use error::PrinterError;
-pub use monitor::{MonitorableProperty, PrinterMonitor};
+pub use backend::{BackendCapabilities, FleetSnapshot, NamelessPrinterPolicy, WmiPrinterClass};
+pub use backoff::BackoffPolicy;
+pub use builder::PrinterMonitorBuilder;
+pub use bus::{PrinterEvent, PrinterEventBus};
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use error::{ErrorKind, PrinterError};
+pub use monitor::{
+    AutoResumeEvent, ErrorAction, ErrorTransition, MonitorableProperty, PrinterMonitor,
+    PrinterPartition, PrinterProblem, SnapshotToken, SystemChange,
+};
+pub use persistence::{JsonFileStore, JsonlRecorder, PersistenceStore, PrinterHistory};
+#[cfg(feature = "schema")]
+pub use printer::printer_json_schema;
 pub use printer::{
-    ErrorState, Printer, PrinterChanges, PrinterState, PrinterStatus, PropertyChange,
+    ChangeRow, ColorMode, CompareOptions, DuplexMode, ErrorState, Locale, MediaInfo, PauseReason,
+    Printer, PrinterAttributes, PrinterChanges, PrinterClass, PrinterDelta, PrinterDriver,
+    PrinterState, PrinterStatus, PropertyChange, RawStatusCodes, Severity, VIRTUAL_PRINTER_NAMES,
+    WmiOperationalStatus,
 };
+pub use stats::{ChangeStats, IdleDetector, QueryTimings, StateAccumulator};
 
 /// Result type used throughout the library
 pub type Result<T> = std::result::Result<T, PrinterError>;
@@ -1,7 +1,63 @@
 use log::error;
-use printer_event_handler::{PrinterError, PrinterMonitor};
+use printer_event_handler::{PrinterChanges, PrinterError, PrinterMonitor};
 use std::env;
 
+/// Command-line options accepted by the CLI.
+#[derive(Debug, Default, PartialEq)]
+struct CliArgs {
+    /// Emit machine-readable JSON instead of human-readable text
+    json: bool,
+    /// Printer to monitor; `None` selects list mode
+    printer_name: Option<String>,
+}
+
+/// Parses the command-line arguments, skipping the program name.
+///
+/// `--json` may appear anywhere; the first other argument is the printer name.
+fn parse_args(args: &[String]) -> CliArgs {
+    let mut cli = CliArgs::default();
+    for arg in args.iter().skip(1) {
+        if arg == "--json" {
+            cli.json = true;
+        } else if cli.printer_name.is_none() {
+            cli.printer_name = Some(arg.clone());
+        }
+    }
+    cli
+}
+
+/// Monitors a specific printer and writes one JSON object per change event to stdout.
+///
+/// Each line is a serialized `PrinterChanges` (JSON Lines), so the output can be
+/// piped into tools such as `jq`. The first line is the initial state with an
+/// empty change list. Monitoring stops on Ctrl+C.
+///
+/// # Arguments
+/// * `printer_name` - The name of the printer to monitor
+///
+/// # Errors
+/// * Same as [`monitor_printer_cli`]
+async fn monitor_printer_json_cli(printer_name: &str) -> Result<(), PrinterError> {
+    let monitor = PrinterMonitor::new().await?;
+
+    monitor
+        .monitor_printer_until_ctrl_c(printer_name, 60000, |current, previous| {
+            let changes = match previous {
+                Some(prev) => prev.compare_with(current),
+                None => PrinterChanges::new(current.name().to_string()),
+            };
+            // Unchanged polls produce no line; the initial state always does
+            if previous.is_some() && !changes.has_changes() {
+                return;
+            }
+            match serde_json::to_string(&changes) {
+                Ok(line) => println!("{}", line),
+                Err(e) => error!("Cannot serialize changes: {}", e),
+            }
+        })
+        .await
+}
+
 /// Monitors a specific printer and displays status changes in the CLI.
 ///
 /// This function implements the monitoring mode of the CLI application,
@@ -28,7 +84,7 @@ async fn monitor_printer_cli(printer_name: &str) -> Result<(), PrinterError> {
     println!("Press Ctrl+C to stop\n");
 
     monitor
-        .monitor_printer(printer_name, 60000, |current, previous| {
+        .monitor_printer_until_ctrl_c(printer_name, 60000, |current, previous| {
             let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
 
             if let Some(prev) = previous {
@@ -162,6 +218,17 @@ async fn list_printers_cli() -> Result<(), PrinterError> {
     Ok(())
 }
 
+/// Lists all printers on the system as a single JSON document.
+///
+/// The document is the one produced by [`PrinterMonitor::export_json`]:
+/// `{ "generated_at": ..., "printers": [...] }`.
+///
+/// # Errors
+/// * Same as [`list_printers_cli`]
+async fn list_printers_json(monitor: &PrinterMonitor) -> Result<String, PrinterError> {
+    monitor.export_json().await
+}
+
 /// Main entry point for the printer monitoring CLI application.
 ///
 /// This function handles command-line argument parsing and dispatches to
@@ -171,6 +238,7 @@ async fn list_printers_cli() -> Result<(), PrinterError> {
 /// # Command Line Usage
 /// * No arguments: Lists all printers once and exits
 /// * One argument: Monitors the named printer continuously
+/// * `--json`: Emits JSON instead of text (JSON Lines in monitor mode)
 ///
 /// # Returns
 /// * `Result<(), Box<dyn std::error::Error>>` - Ok on successful completion, Err on failure
@@ -182,16 +250,33 @@ async fn list_printers_cli() -> Result<(), PrinterError> {
 ///
 /// # Monitor a specific printer
 /// cargo run -- "HP LaserJet Pro"
+///
+/// # List all printers as JSON
+/// cargo run -- --json
 /// ```
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::init();
 
     let args: Vec<String> = env::args().collect();
+    let cli = parse_args(&args);
 
-    if args.len() > 1 {
-        let printer_name = &args[1];
-
+    if cli.json {
+        let result = match &cli.printer_name {
+            Some(printer_name) => monitor_printer_json_cli(printer_name).await,
+            None => match PrinterMonitor::new().await {
+                Ok(monitor) => list_printers_json(&monitor)
+                    .await
+                    .map(|document| println!("{}", document)),
+                Err(e) => Err(e),
+            },
+        };
+        if let Err(e) = result {
+            error!("Failed to query printers: {}", e);
+            eprintln!("Error: {}", e);
+            return Err(e.into());
+        }
+    } else if let Some(printer_name) = &cli.printer_name {
         match monitor_printer_cli(printer_name).await {
             Ok(()) => {}
             Err(PrinterError::PlatformNotSupported) => {
@@ -212,7 +297,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "  {} <printer_name>    Monitor specific printer every 60 seconds",
             args[0]
         );
-        println!("  {}                   List all printers once\n", args[0]);
+        println!("  {}                   List all printers once", args[0]);
+        println!(
+            "  {} --json [printer]   Emit JSON (JSON Lines when monitoring)\n",
+            args[0]
+        );
 
         match list_printers_cli().await {
             Ok(()) => {}
@@ -232,3 +321,70 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use printer_event_handler::backend::PrinterBackend;
+    use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+
+    struct StubBackend;
+
+    #[async_trait]
+    impl PrinterBackend for StubBackend {
+        async fn new() -> printer_event_handler::Result<Self> {
+            Ok(StubBackend)
+        }
+
+        async fn list_printers(&self) -> printer_event_handler::Result<Vec<Printer>> {
+            Ok(vec![Printer::new(
+                "Office".to_string(),
+                PrinterStatus::Idle,
+                ErrorState::NoError,
+                false,
+                true,
+            )])
+        }
+
+        async fn find_printer(
+            &self,
+            _name: &str,
+        ) -> printer_event_handler::Result<Option<Printer>> {
+            Ok(None)
+        }
+    }
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(&args(&["cli"])), CliArgs::default());
+        assert_eq!(
+            parse_args(&args(&["cli", "--json", "Office"])),
+            CliArgs {
+                json: true,
+                printer_name: Some("Office".to_string()),
+            }
+        );
+        assert_eq!(
+            parse_args(&args(&["cli", "Office", "--json"])),
+            parse_args(&args(&["cli", "--json", "Office"]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_printers_json_parses() {
+        let cli = parse_args(&args(&["cli", "--json"]));
+        assert!(cli.json && cli.printer_name.is_none());
+
+        let monitor = PrinterMonitor::with_backend(Box::new(StubBackend));
+        let document = list_printers_json(&monitor).await.unwrap();
+        let value: serde_json::Value = serde_json::from_str(&document).unwrap();
+        let printers = value["printers"].as_array().unwrap();
+        assert_eq!(printers.len(), 1);
+        assert_eq!(printers[0]["name"], "Office");
+    }
+}
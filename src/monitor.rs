@@ -1,8 +1,21 @@
-use crate::backend::{PrinterBackend, create_backend};
-use crate::{Printer, PrinterChanges, Result};
+use crate::backend::{BackendCapabilities, FleetSnapshot, PrinterBackend, create_backend};
+use crate::backoff::{splitmix64, spread};
+use crate::clock::{Clock, SystemClock};
+use crate::persistence::{PersistenceStore, PrinterHistory};
+use crate::watch::{PollTrigger, StallHandler, Watchdog};
+use crate::{
+    BackoffPolicy, ErrorState, MediaInfo, PauseReason, Printer, PrinterChanges, PrinterClass,
+    PrinterDriver, PrinterError, PropertyChange, QueryTimings, Result, Severity,
+};
+use chrono::{DateTime, Utc};
 use log::{error, info, warn};
-use std::collections::HashMap;
-use tokio::time::{Duration, sleep};
+use serde::Serialize;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::BuildHasher;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::time::{Duration, Instant, sleep};
 
 /// Enum representing all available printer properties that can be monitored.
 ///
@@ -23,6 +36,8 @@ pub enum MonitorableProperty {
     IsOffline,
     /// Default printer designation changes
     IsDefault,
+    /// Directory publication (Windows) or sharing (CUPS) changes
+    IsPublished,
     /// Raw PrinterStatus code changes (1-7)
     PrinterStatusCode,
     /// Raw PrinterState code changes (.NET flags)
@@ -35,6 +50,14 @@ pub enum MonitorableProperty {
     ExtendedPrinterStatusCode,
     /// WMI Status property changes ("OK", "Error", etc.)
     WmiStatus,
+    /// Location text changes
+    Location,
+    /// Comment (Windows) or description (CUPS) text changes
+    Comment,
+    /// Queue priority changes (Windows only)
+    Priority,
+    /// Jobs-since-reset counter changes (Windows only)
+    JobsSinceReset,
 }
 
 impl MonitorableProperty {
@@ -49,12 +72,17 @@ impl MonitorableProperty {
             MonitorableProperty::ErrorState => "ErrorState",
             MonitorableProperty::IsOffline => "IsOffline",
             MonitorableProperty::IsDefault => "IsDefault",
+            MonitorableProperty::IsPublished => "IsPublished",
             MonitorableProperty::PrinterStatusCode => "PrinterStatusCode",
             MonitorableProperty::PrinterStateCode => "PrinterStateCode",
             MonitorableProperty::DetectedErrorStateCode => "DetectedErrorStateCode",
             MonitorableProperty::ExtendedDetectedErrorStateCode => "ExtendedDetectedErrorStateCode",
             MonitorableProperty::ExtendedPrinterStatusCode => "ExtendedPrinterStatusCode",
             MonitorableProperty::WmiStatus => "WmiStatus",
+            MonitorableProperty::Location => "Location",
+            MonitorableProperty::Comment => "Comment",
+            MonitorableProperty::Priority => "Priority",
+            MonitorableProperty::JobsSinceReset => "JobsSinceReset",
         }
     }
 
@@ -67,12 +95,17 @@ impl MonitorableProperty {
             MonitorableProperty::ErrorState => "Current error condition",
             MonitorableProperty::IsOffline => "Online/offline status",
             MonitorableProperty::IsDefault => "Default printer designation",
+            MonitorableProperty::IsPublished => "Published in the directory or shared",
             MonitorableProperty::PrinterStatusCode => "Raw printer status code (1-7)",
             MonitorableProperty::PrinterStateCode => "Raw printer state code (.NET flags)",
             MonitorableProperty::DetectedErrorStateCode => "Raw detected error state code (0-11)",
             MonitorableProperty::ExtendedDetectedErrorStateCode => "Extended error state code",
             MonitorableProperty::ExtendedPrinterStatusCode => "Extended printer status code",
             MonitorableProperty::WmiStatus => "WMI status property",
+            MonitorableProperty::Location => "Printer location",
+            MonitorableProperty::Comment => "Printer comment or description",
+            MonitorableProperty::Priority => "Queue priority",
+            MonitorableProperty::JobsSinceReset => "Jobs printed since the counter was reset",
         }
     }
 
@@ -85,19 +118,107 @@ impl MonitorableProperty {
             MonitorableProperty::ErrorState,
             MonitorableProperty::IsOffline,
             MonitorableProperty::IsDefault,
+            MonitorableProperty::IsPublished,
             MonitorableProperty::PrinterStatusCode,
             MonitorableProperty::PrinterStateCode,
             MonitorableProperty::DetectedErrorStateCode,
             MonitorableProperty::ExtendedDetectedErrorStateCode,
             MonitorableProperty::ExtendedPrinterStatusCode,
             MonitorableProperty::WmiStatus,
+            MonitorableProperty::Location,
+            MonitorableProperty::Comment,
+            MonitorableProperty::Priority,
+            MonitorableProperty::JobsSinceReset,
         ]
     }
 }
 
+/// Number of snapshots kept for [`PrinterMonitor::changed_since`]; older tokens expire
+const MAX_SNAPSHOTS: usize = 32;
+
+/// Number of recent backend queries [`PrinterMonitor::query_timings`] summarizes
+const MAX_QUERY_TIMINGS: usize = 100;
+
+/// Most automatic resumes [`PrinterMonitor::monitor_with_auto_resume`] attempts
+/// until the printer keeps running again
+const MAX_AUTO_RESUME_ATTEMPTS: u32 = 3;
+
+/// An action taken by [`PrinterMonitor::monitor_with_auto_resume`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutoResumeEvent {
+    /// The queue was resumed after the error that paused it cleared
+    Resumed { printer: String, attempt: u32 },
+    /// Resuming the queue failed or did not take effect
+    Failed {
+        printer: String,
+        attempt: u32,
+        error: String,
+    },
+    /// Automatic resuming stopped after too many attempts; the queue needs a person
+    GaveUp { printer: String, attempts: u32 },
+}
+
+/// What a monitoring loop does after a backend error, as decided by the handler
+/// set with [`PrinterMonitor::with_error_handler`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorAction {
+    /// Keep monitoring; the next poll happens after the usual interval
+    Continue,
+    /// Stop monitoring and return the error
+    Abort,
+}
+
+/// Decides whether monitoring continues after an error, given the number of
+/// consecutive failed polls
+pub(crate) type ErrorHandler = Arc<dyn Fn(&PrinterError, u32) -> ErrorAction + Send + Sync>;
+
+/// Opaque handle to a printer snapshot taken by [`PrinterMonitor::list_printers_with_token`]
+/// or [`PrinterMonitor::changed_since`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SnapshotToken(u64);
+
 /// Printer monitoring and querying functionality
 pub struct PrinterMonitor {
-    backend: Box<dyn PrinterBackend>,
+    /// Shared with the tasks spawned by `monitor_multiple_printers`
+    backend: Arc<dyn PrinterBackend>,
+    clock: Arc<dyn Clock>,
+    /// Tags keyed by lowercased printer name
+    tags: HashMap<String, Vec<String>>,
+    /// Extra names treated as virtual printers
+    virtual_names: Vec<String>,
+    /// Lowercased names of printers `all_healthy` leaves out
+    health_ignored: Vec<String>,
+    /// Whether monitoring loops re-initialize the backend after retryable errors
+    reconnect_on_error: bool,
+    /// Consulted by monitoring loops before giving up on an error
+    error_handler: Option<ErrorHandler>,
+    /// Delays between attempts to re-initialize a failed backend
+    backoff: BackoffPolicy,
+    /// Fraction by which poll intervals are randomly spread, 0.0 for none
+    poll_jitter: f64,
+    /// SplitMix64 state the poll jitter is drawn from
+    poll_rng: std::sync::Mutex<u64>,
+    /// Where the system monitor persists printer availability history
+    history_store: Option<Arc<dyn PersistenceStore>>,
+    /// Whether `find_printer` falls back to matching normalized names
+    normalize_names: bool,
+    /// Most printers `list_printers` returns; `None` for no limit
+    max_printers: Option<usize>,
+    /// Whether listings leave out fax devices
+    exclude_fax: bool,
+    /// Whether monitoring follows printers renamed on the same port
+    track_renames: bool,
+    /// Whether an empty or whitespace `wmi_status` is reported as `None`
+    normalize_wmi_status: bool,
+    /// Interval multiple after which a loop without completed polls counts as
+    /// stalled, and the handler told about it
+    watchdog: Option<(u32, StallHandler)>,
+    /// Recent snapshots for `changed_since`, oldest first
+    snapshots: std::sync::Mutex<VecDeque<(SnapshotToken, Vec<Printer>)>>,
+    /// Value of the next snapshot token
+    next_token: AtomicU64,
+    /// Durations of recent printer listings and lookups, oldest first
+    query_durations: Arc<std::sync::Mutex<VecDeque<Duration>>>,
 }
 
 impl PrinterMonitor {
@@ -126,55 +247,149 @@ impl PrinterMonitor {
     pub async fn new() -> Result<Self> {
         info!("Initializing printer monitor...");
         let backend = create_backend().await?;
-        Ok(Self { backend })
+        Ok(Self::with_backend(backend))
     }
 
-    /// Retrieves a list of all printers available on the system.
+    /// Returns a builder for configuring a monitor with non-default settings.
     ///
-    /// This method queries the platform-specific printer service to get
-    /// information about all installed and available printers.
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
     ///
-    /// # Returns
-    /// * `Result<Vec<Printer>>` - A vector of all printers found on the system
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::builder()
+    ///         .wmi_namespace("root\\cimv2")
+    ///         .build()
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn builder() -> crate::PrinterMonitorBuilder {
+        crate::PrinterMonitorBuilder::new()
+    }
+
+    /// Creates a PrinterMonitor on top of an explicitly provided backend.
     ///
-    /// # Errors
-    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
-    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
-    /// * `PrinterError::IoError` - If there are system I/O issues
+    /// This is useful for custom backends or for driving the monitor from a
+    /// scripted backend in tests.
+    pub fn with_backend(backend: Box<dyn PrinterBackend>) -> Self {
+        Self {
+            backend: Arc::from(backend),
+            clock: Arc::new(SystemClock),
+            tags: HashMap::new(),
+            virtual_names: Vec::new(),
+            health_ignored: Vec::new(),
+            reconnect_on_error: false,
+            error_handler: None,
+            backoff: BackoffPolicy::default(),
+            poll_jitter: 0.0,
+            poll_rng: std::sync::Mutex::new(RandomState::new().hash_one(0u64)),
+            history_store: None,
+            normalize_names: false,
+            max_printers: None,
+            exclude_fax: false,
+            track_renames: false,
+            normalize_wmi_status: false,
+            watchdog: None,
+            snapshots: std::sync::Mutex::new(VecDeque::new()),
+            next_token: AtomicU64::new(0),
+            query_durations: Arc::new(std::sync::Mutex::new(VecDeque::new())),
+        }
+    }
+
+    /// Creates a monitor for a spawned monitoring task.
+    ///
+    /// The copy shares this monitor's backend and query timings and has the same
+    /// settings, but draws its poll jitter from `poll_seed` and keeps its own
+    /// snapshots for `changed_since`.
+    fn fork(&self, poll_seed: u64) -> Self {
+        Self {
+            backend: Arc::clone(&self.backend),
+            clock: Arc::clone(&self.clock),
+            tags: self.tags.clone(),
+            virtual_names: self.virtual_names.clone(),
+            health_ignored: self.health_ignored.clone(),
+            reconnect_on_error: self.reconnect_on_error,
+            error_handler: self.error_handler.clone(),
+            backoff: self.backoff.clone(),
+            poll_jitter: self.poll_jitter,
+            poll_rng: std::sync::Mutex::new(poll_seed),
+            history_store: self.history_store.clone(),
+            normalize_names: self.normalize_names,
+            max_printers: self.max_printers,
+            exclude_fax: self.exclude_fax,
+            track_renames: self.track_renames,
+            normalize_wmi_status: self.normalize_wmi_status,
+            watchdog: self.watchdog.clone(),
+            snapshots: std::sync::Mutex::new(VecDeque::new()),
+            next_token: AtomicU64::new(0),
+            query_durations: Arc::clone(&self.query_durations),
+        }
+    }
+
+    /// Replaces the clock used to timestamp detected changes.
+    ///
+    /// Defaults to [`SystemClock`]. Supplying a [`crate::FixedClock`] makes the
+    /// timestamps of emitted `PrinterChanges` deterministic.
     ///
     /// # Example
     /// ```rust,no_run
-    /// use printer_event_handler::PrinterMonitor;
+    /// use std::sync::Arc;
+    /// use chrono::Utc;
+    /// use printer_event_handler::{FixedClock, PrinterMonitor};
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let monitor = PrinterMonitor::new().await.unwrap();
-    ///     let printers = monitor.list_printers().await.unwrap();
-    ///     
-    ///     for printer in printers {
-    ///         println!("{}: {}", printer.name(), printer.status_description());
-    ///     }
+    ///     let monitor = PrinterMonitor::new()
+    ///         .await
+    ///         .unwrap()
+    ///         .with_clock(Arc::new(FixedClock::new(Utc::now())));
     /// }
     /// ```
-    pub async fn list_printers(&self) -> Result<Vec<Printer>> {
-        self.backend.list_printers().await
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
     }
 
-    /// Searches for a specific printer by name using case-insensitive matching.
+    /// Assigns grouping tags to printers by name.
     ///
-    /// This method searches through all available printers to find one with
-    /// a name that matches the provided string (case-insensitive).
+    /// Every printer returned by this monitor (listing, lookups and monitoring
+    /// callbacks) carries the tags configured for its name. Printer names are
+    /// matched case-insensitively, as are tags in [`Self::list_printers_with_tag`].
     ///
     /// # Arguments
-    /// * `name` - The name of the printer to search for
+    /// * `tags` - Map of printer name to the tags assigned to that printer
     ///
-    /// # Returns
-    /// * `Result<Option<Printer>>` - The found printer or None if not found
+    /// # Example
+    /// ```rust,no_run
+    /// use std::collections::HashMap;
+    /// use printer_event_handler::PrinterMonitor;
     ///
-    /// # Errors
-    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
-    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
-    /// * `PrinterError::IoError` - If there are system I/O issues
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let tags = HashMap::from([
+    ///         ("HP LaserJet".to_string(), vec!["floor-3".to_string(), "finance".to_string()]),
+    ///     ]);
+    ///     let monitor = PrinterMonitor::new().await.unwrap().with_tags(tags);
+    ///
+    ///     for printer in monitor.list_printers_with_tag("Finance").await.unwrap() {
+    ///         println!("{}", printer.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn with_tags(mut self, tags: HashMap<String, Vec<String>>) -> Self {
+        self.tags = tags
+            .into_iter()
+            .map(|(name, tags)| (name.to_lowercase(), tags))
+            .collect();
+        self
+    }
+
+    /// Adds printer names to treat as virtual, on top of [`crate::VIRTUAL_PRINTER_NAMES`].
+    ///
+    /// Names are matched case-insensitively as substrings of the printer name and
+    /// affect [`Self::list_physical_printers`].
     ///
     /// # Example
     /// ```rust,no_run
@@ -182,142 +397,336 @@ impl PrinterMonitor {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let monitor = PrinterMonitor::new().await.unwrap();
-    ///     
-    ///     if let Some(printer) = monitor.find_printer("HP LaserJet").await.unwrap() {
-    ///         println!("Found printer: {}", printer.name());
+    ///     let monitor = PrinterMonitor::new()
+    ///         .await
+    ///         .unwrap()
+    ///         .with_virtual_printer_names(vec!["Fax".to_string()]);
+    ///
+    ///     for printer in monitor.list_physical_printers().await.unwrap() {
+    ///         println!("{}", printer.name());
     ///     }
     /// }
     /// ```
-    pub async fn find_printer(&self, name: &str) -> Result<Option<Printer>> {
-        self.backend.find_printer(name).await
+    pub fn with_virtual_printer_names(mut self, names: Vec<String>) -> Self {
+        self.virtual_names = names;
+        self
     }
 
-    /// Continuously monitors a specific printer for status changes.
+    /// Leaves the named printers out of [`Self::all_healthy`].
     ///
-    /// This function runs indefinitely, polling the specified printer every `interval_ms`
-    /// milliseconds and calling the provided callback function whenever the printer's status changes.
-    /// The callback receives both the current printer state and the previous state (if any).
+    /// Useful for printers known to be broken or switched off, which would
+    /// otherwise keep the fleet status red. Names are matched case-insensitively.
+    pub fn with_health_ignore_list(mut self, names: Vec<String>) -> Self {
+        self.health_ignored = names.iter().map(|name| name.to_lowercase()).collect();
+        self
+    }
+
+    /// Keeps monitoring loops running across backend failures.
     ///
-    /// # Arguments
-    /// * `printer_name` - The name of the printer to monitor
-    /// * `interval_ms` - Polling interval in milliseconds
-    /// * `callback` - Function called when printer status changes, receives (current, previous)
+    /// When enabled, a monitoring method that hits a retryable error (see
+    /// [`PrinterError::is_retryable`], e.g. the WMI service restarting) re-initializes
+    /// the backend and then resumes polling instead of returning the error.
+    /// Re-initialization is retried as set by [`Self::with_backoff_policy`], by
+    /// default with exponential backoff from 1 second up to 1 minute.
+    /// Non-retryable errors still end monitoring. Disabled by default.
+    pub fn with_reconnect_on_error(mut self, reconnect_on_error: bool) -> Self {
+        self.reconnect_on_error = reconnect_on_error;
+        self
+    }
+
+    /// Reports monitoring loops that stop completing polls, e.g. because the
+    /// backend hangs.
     ///
-    /// # Returns
-    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    /// While a monitoring method runs, a separate timer task checks when its last
+    /// backend query completed (successfully or not). If none completed within
+    /// `factor` times the polling interval, `on_stall` is called with the time since
+    /// the last completed poll, so a supervisor can log the stall or restart
+    /// monitoring. It is called once per stall, and again only after polls resumed
+    /// and stalled anew. Waiting to reconnect (see [`Self::with_reconnect_on_error`])
+    /// also counts as a stall when it takes that long. Choose a `factor` of at least
+    /// 2 when poll jitter is enabled; a factor of 0 is treated as 1.
     ///
-    /// # Errors
-    /// * `PrinterError::PrinterNotFound` - If the specified printer is not found initially
-    /// * `PrinterError::WmiError` - If WMI queries fail on Windows
-    /// * `PrinterError::CupsError` - If CUPS queries fail on Linux
-    /// * `PrinterError::IoError` - If there are system I/O issues
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
     ///
-    /// # Behavior
-    /// - If the printer disappears during monitoring, the callback is called with a synthetic
-    ///   "unknown" status to indicate the printer is no longer available
-    /// - The first check always triggers the callback to provide the initial status
-    /// - Subsequent calls only trigger the callback if the status actually changes
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new()
+    ///         .await
+    ///         .unwrap()
+    ///         .with_watchdog(3, |stalled| eprintln!("No poll completed for {:?}", stalled));
+    /// }
+    /// ```
+    pub fn with_watchdog<H>(mut self, factor: u32, on_stall: H) -> Self
+    where
+        H: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.watchdog = Some((factor.max(1), Arc::new(on_stall)));
+        self
+    }
+
+    /// Randomly spreads every poll interval by up to `jitter` times its length in
+    /// either direction, e.g. `0.1` for ±10%.
+    ///
+    /// Monitoring many printers at the same interval otherwise makes all polls
+    /// fire together and spike the load on WMI or CUPS. The value is clamped to
+    /// 0.0..=1.0; 0.0 (the default) keeps intervals exact. Use
+    /// [`Self::with_poll_jitter_seed`] for reproducible intervals.
+    pub fn with_poll_jitter(mut self, jitter: f64) -> Self {
+        self.poll_jitter = if jitter.is_nan() {
+            0.0
+        } else {
+            jitter.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Draws the poll jitter from `seed` instead of system randomness, so the
+    /// sequence of poll intervals is reproducible (see [`Self::with_poll_jitter`])
+    pub fn with_poll_jitter_seed(self, seed: u64) -> Self {
+        *self.poll_rng.lock().unwrap() = seed;
+        self
+    }
+
+    /// Sets the delays between attempts to re-initialize a failed backend (see
+    /// [`Self::with_reconnect_on_error`]).
+    ///
+    /// The policy only governs these reconnects. Poll intervals are jittered
+    /// separately (see [`Self::with_poll_jitter`]), and
+    /// [`Self::monitor_with_auto_resume`] retries resuming a fixed number of
+    /// times, once per poll.
     ///
     /// # Example
     /// ```rust,no_run
-    /// use printer_event_handler::PrinterMonitor;
+    /// use printer_event_handler::{BackoffPolicy, PrinterMonitor};
+    /// use std::time::Duration;
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let monitor = PrinterMonitor::new().await.unwrap();
-    ///     
-    ///     monitor.monitor_printer("HP LaserJet", 30000, |current, previous| {
-    ///         if let Some(prev) = previous {
-    ///             if prev != current {
-    ///                 println!("Status changed: {} -> {}",
-    ///                     prev.status_description(),
-    ///                     current.status_description());
+    ///     let policy = BackoffPolicy::exponential(Duration::from_millis(500), Duration::from_secs(30))
+    ///         .with_jitter(0.2);
+    ///     let monitor = PrinterMonitor::new()
+    ///         .await
+    ///         .unwrap()
+    ///         .with_reconnect_on_error(true)
+    ///         .with_backoff_policy(policy);
+    /// }
+    /// ```
+    pub fn with_backoff_policy(mut self, policy: BackoffPolicy) -> Self {
+        self.backoff = policy;
+        self
+    }
+
+    /// Lets monitoring loops survive backend errors instead of returning the first one.
+    ///
+    /// Every monitoring method calls `handler` with the error and the number of
+    /// consecutive failed polls (1 for the first failure, reset by a successful
+    /// poll). [`ErrorAction::Continue`] skips the failed poll and keeps monitoring,
+    /// [`ErrorAction::Abort`] returns the error. When reconnecting is enabled (see
+    /// [`Self::with_reconnect_on_error`]), retryable errors the handler continues
+    /// on still re-initialize the backend first. Without a handler, errors that
+    /// cannot be reconnected end monitoring.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::{ErrorAction, PrinterMonitor};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new()
+    ///         .await
+    ///         .unwrap()
+    ///         .with_error_handler(|error, failures| {
+    ///             eprintln!("Poll failed ({} in a row): {}", failures, error);
+    ///             if failures < 5 {
+    ///                 ErrorAction::Continue
+    ///             } else {
+    ///                 ErrorAction::Abort
     ///             }
-    ///         } else {
-    ///             println!("Initial status: {}", current.status_description());
-    ///         }
-    ///     }).await.unwrap();
+    ///         });
     /// }
     /// ```
-    pub async fn monitor_printer<F>(
-        &self,
-        printer_name: &str,
-        interval_ms: u64,
-        mut callback: F,
-    ) -> Result<()>
+    pub fn with_error_handler<H>(mut self, handler: H) -> Self
     where
-        F: FnMut(&Printer, Option<&Printer>) + Send,
+        H: Fn(&PrinterError, u32) -> ErrorAction + Send + Sync + 'static,
     {
-        info!("Starting printer monitoring service for: {}", printer_name);
+        self.error_handler = Some(Arc::new(handler));
+        self
+    }
 
-        let mut previous_printer: Option<Printer> = None;
+    /// Lets [`Self::find_printer`] match names that differ only in spaces vs. underscores.
+    ///
+    /// CUPS queue names cannot contain spaces, so the same logical printer is often
+    /// `HP LaserJet` on Windows and `HP_LaserJet` on Linux. When enabled and no
+    /// printer has the exact name, `find_printer` compares names with underscores
+    /// treated as spaces and case ignored. Disabled by default.
+    pub fn with_name_normalization(mut self, normalize_names: bool) -> Self {
+        self.normalize_names = normalize_names;
+        self
+    }
 
-        loop {
-            match self.find_printer(printer_name).await {
-                Ok(Some(current_printer)) => {
-                    println!(
-                        "[{}] Checking printer: {}",
-                        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
-                        current_printer.name()
-                    );
-                    let has_changed = previous_printer
-                        .as_ref()
-                        .map(|prev| prev != &current_printer)
-                        .unwrap_or(true);
+    /// Caps how many printers [`Self::list_printers`],
+    /// [`Self::list_printers_lenient`] and [`Self::list_printer_names`] return.
+    ///
+    /// Protects long-running services from a misbehaving spooler reporting an
+    /// enormous list: the backend stops enumerating just past the first
+    /// `max_printers` printers, any extra ones are dropped with a warning, and
+    /// everything built on listing (system monitoring, snapshots, filters) only
+    /// sees the truncated set. With [`Self::with_fax_excluded`] the backend still
+    /// enumerates every printer, since fax devices are only recognized
+    /// afterwards. Lookups by name and [`Self::all_healthy`] still see every
+    /// printer. Unlimited by default.
+    pub fn with_max_printers(mut self, max_printers: usize) -> Self {
+        self.max_printers = Some(max_printers);
+        self
+    }
 
-                    if has_changed {
-                        callback(&current_printer, previous_printer.as_ref());
-                        info!(
-                            "Printer '{}' - Status: {}, Error: {}",
-                            printer_name,
-                            current_printer.status_description(),
-                            current_printer.error_description()
-                        );
-                        previous_printer = Some(current_printer);
-                    } else {
-                        info!("Printer '{}' status unchanged", printer_name);
-                    }
+    /// Leaves fax devices (see [`Printer::is_fax`]) out of printer listings.
+    ///
+    /// Applies to [`Self::list_printers`] and everything built on it, including
+    /// system monitoring, as well as [`Self::list_printers_lenient`] and
+    /// [`Self::list_printer_names`]. A fax device
+    /// can still be looked up by name, e.g. with [`Self::find_printer`] or
+    /// [`Self::find_printers`], and still counts for [`Self::all_healthy`].
+    /// Disabled by default.
+    pub fn with_fax_excluded(mut self, exclude_fax: bool) -> Self {
+        self.exclude_fax = exclude_fax;
+        self
+    }
+
+    /// Follows a monitored printer across renames by matching its port.
+    ///
+    /// Without it, a printer renamed by an administrator looks like it
+    /// disappeared. With it, when the monitored name is no longer found, the
+    /// single printer on the same port (see [`Printer::port`]) is taken to be the
+    /// renamed printer: monitoring continues under the new name and the change
+    /// reports a [`PropertyChange::Name`]. Printers without a port, or a port
+    /// shared by several printers, cannot be followed. Applies to
+    /// [`Self::monitor_printer`], [`Self::monitor_printer_changes`] and their
+    /// variants. Disabled by default.
+    pub fn with_rename_tracking(mut self, track_renames: bool) -> Self {
+        self.track_renames = track_renames;
+        self
+    }
+
+    /// Reports an empty or whitespace-only WMI Status as `None`.
+    ///
+    /// Some drivers flip the Status string between `"OK"` and `""` while
+    /// printing, which shows up as a constant stream of
+    /// [`PropertyChange::WmiStatus`] changes. With this enabled, a blank status is
+    /// cleared on every printer the monitor returns, so `""` and `None` compare
+    /// equal. This also changes what [`Printer::wmi_status`] returns: `None`
+    /// instead of the blank string. Disabled by default.
+    pub fn with_wmi_status_normalization(mut self, normalize_wmi_status: bool) -> Self {
+        self.normalize_wmi_status = normalize_wmi_status;
+        self
+    }
+
+    /// Persists when each printer was last seen online and offline.
+    ///
+    /// While [`Self::monitor_system`] or [`Self::monitor_system_batched`] runs, the
+    /// history is loaded from `store` on startup, updated for every printer on each
+    /// poll and saved back, so availability data survives monitor restarts.
+    /// Failures to save are logged and do not stop monitoring.
+    pub fn with_history_store(mut self, store: Arc<dyn PersistenceStore>) -> Self {
+        self.history_store = Some(store);
+        self
+    }
+
+    /// Handles an error from a monitoring poll.
+    ///
+    /// `failures` counts the consecutive failed polls of the calling loop, which
+    /// resets it after a successful poll. The reconnect backoff continues from the
+    /// failed polls before this one, since a reconnect that succeeds does not prove
+    /// the backend works again (the WMI connection, for one, is only opened by the
+    /// next query). Returns `Ok` once polling can resume, or the error if
+    /// monitoring has to stop.
+    async fn recover(&self, error: PrinterError, failures: &mut u32) -> Result<()> {
+        *failures += 1;
+        let reconnect = self.reconnect_on_error && error.is_retryable();
+
+        if let Some(handler) = &self.error_handler {
+            match handler(&error, *failures) {
+                ErrorAction::Abort => return Err(error),
+                ErrorAction::Continue if !reconnect => {
+                    warn!("Backend error ({}), continuing monitoring", error);
+                    return Ok(());
                 }
-                Ok(None) => {
-                    warn!("Printer '{}' not found", printer_name);
-                    if previous_printer.is_some() {
-                        // Printer was previously found but now missing
-                        callback(
-                            &Printer::new(
-                                printer_name.to_string(),
-                                crate::PrinterStatus::StatusUnknown,
-                                crate::ErrorState::UnknownError,
-                                true,
-                                false,
-                            ),
-                            previous_printer.as_ref(),
-                        );
-                        previous_printer = None;
-                    }
+                ErrorAction::Continue => {}
+            }
+        }
+
+        if !reconnect {
+            return Err(error);
+        }
+
+        let mut attempt = *failures - 1;
+        loop {
+            let delay = self.backoff.delay(attempt);
+            warn!("Backend error ({}), reconnecting in {:?}", error, delay);
+            sleep(delay).await;
+
+            match self.backend.reconnect().await {
+                Ok(()) => {
+                    info!("Backend reconnected, resuming monitoring");
+                    return Ok(());
                 }
-                Err(e) => {
-                    error!("Failed to check printer status: {}", e);
-                    return Err(e);
+                Err(e) if e.is_retryable() => {
+                    warn!("Reconnect failed: {}", e);
+                    attempt = attempt.saturating_add(1);
                 }
+                Err(e) => return Err(e),
             }
+        }
+    }
+
+    /// Starts the watchdog configured with [`Self::with_watchdog`] for a loop polling
+    /// every `interval`
+    fn start_watchdog(&self, interval: Duration) -> Option<Watchdog> {
+        let (factor, on_stall) = self.watchdog.as_ref()?;
+        Some(Watchdog::start(interval * *factor, Arc::clone(on_stall)))
+    }
+
+    /// Lists the printers through the backend, recording how long it took
+    async fn query_printers(&self) -> Result<Vec<Printer>> {
+        let started = Instant::now();
+        let result = self.backend.list_printers().await;
+        self.record_query(started.elapsed());
+        result
+    }
+
+    /// Lists at most `limit` printers through the backend, recording how long it took
+    async fn query_printers_up_to(&self, limit: usize) -> Result<Vec<Printer>> {
+        let started = Instant::now();
+        let result = self.backend.list_printers_up_to(limit).await;
+        self.record_query(started.elapsed());
+        result
+    }
+
+    /// Looks up a printer through the backend, recording how long it took
+    async fn query_printer(&self, name: &str) -> Result<Option<Printer>> {
+        let started = Instant::now();
+        let result = self.backend.find_printer(name).await;
+        self.record_query(started.elapsed());
+        result
+    }
 
-            sleep(Duration::from_millis(interval_ms)).await;
+    /// Keeps the duration of a backend query for [`Self::query_timings`]
+    fn record_query(&self, elapsed: Duration) {
+        let mut durations = self.query_durations.lock().unwrap();
+        if durations.len() == MAX_QUERY_TIMINGS {
+            durations.pop_front();
         }
+        durations.push_back(elapsed);
     }
 
-    /// Retrieves a comprehensive summary of all printers and their current states.
-    ///
-    /// This method provides a convenient way to get an overview of all printers
-    /// in a structured format, useful for status dashboards or reports.
-    ///
-    /// # Returns
-    /// * `Result<HashMap<String, PrinterSummary>>` - Map of printer names to their summaries
+    /// Returns how long the last 100 printer listings and lookups took.
     ///
-    /// # Errors
-    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
-    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
-    /// * `PrinterError::IoError` - If there are system I/O issues
+    /// Every listing and lookup the monitor sends to the backend is timed,
+    /// including those of the monitoring loops and failed ones. Compare the
+    /// figures with the polling interval: an interval close to the query time
+    /// leaves the backend no rest. `None` until the first query.
     ///
     /// # Example
     /// ```rust,no_run
@@ -326,46 +735,119 @@ impl PrinterMonitor {
     /// #[tokio::main]
     /// async fn main() {
     ///     let monitor = PrinterMonitor::new().await.unwrap();
-    ///     let summary = monitor.printer_summary().await.unwrap();
-    ///     
-    ///     for (name, info) in summary {
-    ///         println!("{}: {} ({})", name, info.status,
-    ///             if info.has_error { "ERROR" } else { "OK" });
+    ///     monitor.list_printers().await.unwrap();
+    ///     if let Some(timings) = monitor.query_timings() {
+    ///         println!("Queries take {:?} on average, up to {:?}", timings.average, timings.max);
     ///     }
     /// }
     /// ```
-    pub async fn printer_summary(&self) -> Result<HashMap<String, PrinterSummary>> {
-        let printers = self.list_printers().await?;
-        let mut summary = HashMap::new();
+    pub fn query_timings(&self) -> Option<QueryTimings> {
+        QueryTimings::from_samples(self.query_durations.lock().unwrap().iter())
+    }
 
-        for printer in printers {
-            summary.insert(
-                printer.name().to_string(),
-                PrinterSummary {
-                    status: printer.status().clone(),
-                    error_state: printer.error_state().clone(),
-                    is_offline: printer.is_offline(),
-                    is_default: printer.is_default(),
-                    has_error: printer.has_error(),
-                },
-            );
+    /// Returns the delay before the next poll, with the configured jitter applied
+    fn poll_delay(&self, interval: Duration) -> Duration {
+        if self.poll_jitter == 0.0 {
+            return interval;
         }
-
-        Ok(summary)
+        let random = {
+            let mut state = self.poll_rng.lock().unwrap();
+            *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            splitmix64(*state)
+        };
+        spread(interval, self.poll_jitter, random)
     }
 
-    /// Monitors a printer with detailed property change detection.
-    ///
-    /// This enhanced monitoring method provides detailed information about exactly which
-    /// properties changed between checks, enabling fine-grained monitoring and alerting.
+    /// Looks up a monitored printer, following a rename when
+    /// [`Self::with_rename_tracking`] is enabled.
     ///
-    /// # Arguments
-    /// * `printer_name` - The name of the printer to monitor
-    /// * `interval_ms` - Polling interval in milliseconds
-    /// * `callback` - Function called when properties change, receives PrinterChanges
+    /// `name` is updated to the new name when a rename is detected.
+    async fn find_tracked_printer(
+        &self,
+        name: &mut String,
+        previous: Option<&Printer>,
+    ) -> Result<Option<Printer>> {
+        let found = self.find_printer(name).await?;
+        if found.is_some() || !self.track_renames {
+            return Ok(found);
+        }
+        let Some(port) = previous
+            .and_then(|previous| previous.port())
+            .filter(|port| !port.trim().is_empty())
+        else {
+            return Ok(None);
+        };
+
+        let printers = self.list_unfiltered().await?;
+        let mut same_port = printers
+            .into_iter()
+            .filter(|printer| printer.port().is_some_and(|p| p.eq_ignore_ascii_case(port)));
+        match (same_port.next(), same_port.next()) {
+            (Some(renamed), None) => {
+                info!("Printer '{}' was renamed to '{}'", name, renamed.name());
+                *name = renamed.name().to_string();
+                Ok(Some(renamed))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Lists every printer for a lookup by name.
+    ///
+    /// Unlike [`Self::list_printers`], neither fax devices nor printers beyond the
+    /// `max_printers` cap are left out, since they exist all the same.
+    async fn list_unfiltered(&self) -> Result<Vec<Printer>> {
+        let printers = self.query_printers().await?;
+        Ok(printers
+            .into_iter()
+            .map(|printer| self.finish_printer(printer))
+            .collect())
+    }
+
+    /// Applies the `max_printers` cap and [`Self::finish_printer`] to a backend listing
+    fn finish_listing(&self, mut printers: Vec<Printer>) -> Vec<Printer> {
+        if self.exclude_fax {
+            printers.retain(|printer| !printer.is_fax());
+        }
+        if let Some(max) = self.max_printers
+            && printers.len() > max
+        {
+            warn!(
+                "Backend returned more than {} printers, keeping only the first {}",
+                max, max
+            );
+            printers.truncate(max);
+        }
+        printers
+            .into_iter()
+            .map(|printer| self.finish_printer(printer))
+            .collect()
+    }
+
+    /// Attaches the configured tags to a printer returned by the backend and
+    /// normalizes its WMI Status if configured
+    fn finish_printer(&self, mut printer: Printer) -> Printer {
+        if self.normalize_wmi_status {
+            printer.clear_blank_wmi_status();
+        }
+        match self.tags.get(&printer.name().to_lowercase()) {
+            Some(tags) => printer.with_tags(tags.clone()),
+            None => printer,
+        }
+    }
+
+    /// Retrieves a list of all printers available on the system.
+    ///
+    /// This method queries the platform-specific printer service to get
+    /// information about all installed and available printers.
     ///
     /// # Returns
-    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    /// * `Result<Vec<Printer>>` - A vector of all printers found on the system
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
     ///
     /// # Example
     /// ```rust,no_run
@@ -374,135 +856,320 @@ impl PrinterMonitor {
     /// #[tokio::main]
     /// async fn main() {
     ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let printers = monitor.list_printers().await.unwrap();
     ///     
-    ///     monitor.monitor_printer_changes("HP LaserJet", 30000, |changes| {
-    ///         if changes.has_changes() {
-    ///             println!("Detected {} changes:", changes.change_count());
-    ///             for change in &changes.changes {
-    ///                 println!("  - {}", change.description());
-    ///             }
-    ///         }
-    ///     }).await.unwrap();
+    ///     for printer in printers {
+    ///         println!("{}: {}", printer.name(), printer.status_description());
+    ///     }
     /// }
     /// ```
-    pub async fn monitor_printer_changes<F>(
+    pub async fn list_printers(&self) -> Result<Vec<Printer>> {
+        let printers = match self.max_printers {
+            // Fax devices are dropped after enumeration, so the cap can only be
+            // pushed down when they are kept. One extra entry tells whether the
+            // cap was hit.
+            Some(max) if !self.exclude_fax => {
+                self.query_printers_up_to(max.saturating_add(1)).await?
+            }
+            _ => self.query_printers().await?,
+        };
+        Ok(self.finish_listing(printers))
+    }
+
+    /// Retrieves all printers together with a token for [`Self::changed_since`].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let (printers, mut token) = monitor.list_printers_with_token().await.unwrap();
+    ///     println!("{} printers", printers.len());
+    ///
+    ///     // Later, e.g. on the next dashboard refresh
+    ///     let (changes, next) = monitor.changed_since(&token).await.unwrap();
+    ///     println!("{} printer(s) changed", changes.len());
+    ///     token = next;
+    /// }
+    /// ```
+    pub async fn list_printers_with_token(&self) -> Result<(Vec<Printer>, SnapshotToken)> {
+        let printers = self.list_printers().await?;
+        let token = self.store_snapshot(printers.clone());
+        Ok((printers, token))
+    }
+
+    /// Returns the printers added, removed or changed since the snapshot behind
+    /// `token`, together with a token for the current snapshot.
+    ///
+    /// This is the pull-based counterpart of [`Self::monitor_system`]. Only the 32
+    /// most recent snapshots are kept, so a token expires once 32 newer ones have
+    /// been taken.
+    ///
+    /// # Errors
+    /// * `PrinterError::Other` - If the token is unknown or has expired
+    /// * Any error from [`Self::list_printers`]
+    pub async fn changed_since(
         &self,
-        printer_name: &str,
-        interval_ms: u64,
-        mut callback: F,
-    ) -> Result<()>
-    where
-        F: FnMut(&PrinterChanges) + Send,
-    {
-        info!(
-            "Starting detailed printer change monitoring for: {}",
-            printer_name
-        );
+        token: &SnapshotToken,
+    ) -> Result<(Vec<SystemChange>, SnapshotToken)> {
+        let previous = self
+            .snapshots
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(stored, _)| stored == token)
+            .map(|(_, printers)| printers.clone())
+            .ok_or_else(|| PrinterError::Other("unknown or expired snapshot token".to_string()))?;
 
-        let mut previous_printer: Option<Printer> = None;
+        let current = self.list_printers().await?;
+        let changes = diff_printer_sets(&previous, &current, self.clock.as_ref());
+        Ok((changes, self.store_snapshot(current)))
+    }
 
-        loop {
-            match self.find_printer(printer_name).await {
-                Ok(Some(current_printer)) => {
-                    if let Some(ref prev) = previous_printer {
-                        let changes = prev.compare_with(&current_printer);
-                        if changes.has_changes() {
-                            info!(
-                                "Printer '{}' - {} properties changed",
-                                printer_name,
-                                changes.change_count()
-                            );
-                            callback(&changes);
-                        }
-                    } else {
-                        // Initial state - report as "initial" (no previous state)
-                        let changes = PrinterChanges::new(current_printer.name().to_string());
-                        callback(&changes);
-                        info!("Printer '{}' - Initial state captured", printer_name);
-                    }
-                    previous_printer = Some(current_printer);
-                }
-                Ok(None) => {
-                    warn!("Printer '{}' not found", printer_name);
-                    if let Some(prev) = previous_printer.take() {
-                        // Printer disappeared - create a change showing it went offline
-                        let mut changes = PrinterChanges::new(printer_name.to_string());
-                        changes.changes.push(crate::PropertyChange::IsOffline {
-                            old: prev.is_offline(),
-                            new: true,
-                        });
-                        callback(&changes);
-                    }
-                }
-                Err(e) => {
-                    error!("Failed to check printer status: {}", e);
-                    return Err(e);
-                }
+    /// Keeps a snapshot for `changed_since`, evicting the oldest beyond the limit
+    fn store_snapshot(&self, printers: Vec<Printer>) -> SnapshotToken {
+        let token = SnapshotToken(self.next_token.fetch_add(1, Ordering::Relaxed));
+        let mut snapshots = self.snapshots.lock().unwrap();
+        if snapshots.len() == MAX_SNAPSHOTS {
+            snapshots.pop_front();
+        }
+        snapshots.push_back((token, printers));
+        token
+    }
+
+    /// Retrieves only the names of all printers.
+    ///
+    /// Cheaper than [`Self::list_printers`] when only names are needed (e.g. to
+    /// populate a dropdown): Windows queries just the `Name` and `PortName`
+    /// columns and Linux only reads the printer lines of `lpstat -p`. The names
+    /// match those of [`Self::list_printers`], including the
+    /// [`Self::with_max_printers`] cap. Fax devices can only be recognized from
+    /// their driver and port, so with [`Self::with_fax_excluded`] this falls back
+    /// to a full listing.
+    ///
+    /// # Returns
+    /// * `Result<Vec<String>>` - The names of all printers found on the system
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    pub async fn list_printer_names(&self) -> Result<Vec<String>> {
+        if self.exclude_fax {
+            let printers = self.list_printers().await?;
+            return Ok(printers
+                .into_iter()
+                .map(|printer| printer.name().to_string())
+                .collect());
+        }
+
+        let mut names = self.backend.list_printer_names().await?;
+        if let Some(max) = self.max_printers
+            && names.len() > max
+        {
+            warn!(
+                "Backend returned more than {} printers, keeping only the first {}",
+                max, max
+            );
+            names.truncate(max);
+        }
+        Ok(names)
+    }
+
+    /// Checks whether the Windows Print Spooler service is running.
+    ///
+    /// When the spooler is stopped, WMI printer queries return stale or empty
+    /// results, so call this first to prompt the user to start the service.
+    ///
+    /// # Returns
+    /// * `Result<bool>` - `true` if the `Spooler` service is running, `false` if it is stopped
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails or the service is not installed
+    #[cfg(windows)]
+    pub async fn spooler_running(&self) -> Result<bool> {
+        self.backend.spooler_running().await
+    }
+
+    /// Makes the named printer the system default printer.
+    ///
+    /// **This is a write operation**: unlike the rest of the API it changes system
+    /// configuration. Windows calls the spooler's `SetDefaultPrinter` for the current
+    /// user; Linux runs `lpoptions -d <name>`, which sets the per-user default (or the
+    /// system-wide one when run as root). The default is read back afterwards to make
+    /// sure the change took effect.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the printer to make the default
+    ///
+    /// # Errors
+    /// * `PrinterError::PrinterNotFound` - If no printer with that name exists
+    /// * `PrinterError::CupsError` - If `lpoptions` rejects the change on Linux
+    /// * `PrinterError::IoError` - If the spooler call or command fails
+    /// * `PrinterError::Other` - If the printer is still not the default afterwards
+    pub async fn set_default_printer(&self, name: &str) -> Result<()> {
+        self.backend.set_default_printer(name).await?;
+
+        match self.query_printer(name).await? {
+            Some(printer) if printer.is_default() => {
+                info!("Default printer set to '{}'", printer.name());
+                Ok(())
             }
+            Some(_) => Err(PrinterError::Other(format!(
+                "'{}' is still not the default printer after setting it",
+                name
+            ))),
+            None => Err(PrinterError::PrinterNotFound(name.to_string())),
+        }
+    }
+
+    /// Pauses a printer's queue so it stops sending jobs to the device.
+    ///
+    /// **This is a write operation**. Windows calls the spooler's `SetPrinter` with
+    /// `PRINTER_CONTROL_PAUSE` (requires administer rights on the printer); Linux runs
+    /// `cupsdisable <name>`. The printer is read back afterwards to confirm it reports
+    /// a paused state.
+    ///
+    /// # Errors
+    /// * `PrinterError::PrinterNotFound` - If no printer with that name exists
+    /// * `PrinterError::CupsError` - If `cupsdisable` fails on Linux
+    /// * `PrinterError::IoError` - If the spooler call or command fails
+    /// * `PrinterError::Other` - If the printer does not report being paused afterwards
+    pub async fn pause_printer(&self, name: &str) -> Result<()> {
+        self.set_paused(name, true).await
+    }
+
+    /// Resumes a paused printer queue.
+    ///
+    /// **This is a write operation**, the counterpart of [`Self::pause_printer`]:
+    /// Windows uses `PRINTER_CONTROL_RESUME` and Linux runs `cupsenable <name>`. The
+    /// printer is read back afterwards to confirm it is no longer paused.
+    ///
+    /// # Errors
+    /// Same as [`Self::pause_printer`].
+    pub async fn resume_printer(&self, name: &str) -> Result<()> {
+        self.set_paused(name, false).await
+    }
+
+    /// Cancels a single print job.
+    ///
+    /// **This is a write operation**. Windows deletes the matching `Win32_PrintJob`
+    /// instance; Linux runs `cancel <printer>-<job_id>`.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The printer the job is queued on
+    /// * `job_id` - The spooler job ID
+    ///
+    /// # Errors
+    /// * `PrinterError::JobNotFound` - If the job does not exist on that printer
+    /// * `PrinterError::WmiError` - If deleting the job fails on Windows
+    /// * `PrinterError::CupsError` - If `cancel` fails on Linux
+    pub async fn cancel_job(&self, printer_name: &str, job_id: u32) -> Result<()> {
+        self.backend.cancel_jobs(printer_name, Some(job_id)).await
+    }
+
+    /// Cancels every job queued on a printer, e.g. to clear a stuck queue.
+    ///
+    /// **This is a write operation**. Windows deletes each of the printer's
+    /// `Win32_PrintJob` instances; Linux runs `cancel -a <printer>`. An empty queue
+    /// is not an error.
+    ///
+    /// # Errors
+    /// * `PrinterError::PrinterNotFound` - If CUPS does not know the printer on Linux
+    /// * `PrinterError::WmiError` - If listing or deleting jobs fails on Windows
+    /// * `PrinterError::CupsError` - If `cancel` fails on Linux
+    pub async fn cancel_all_jobs(&self, printer_name: &str) -> Result<()> {
+        self.backend.cancel_jobs(printer_name, None).await
+    }
 
-            sleep(Duration::from_millis(interval_ms)).await;
+    /// Pauses or resumes a queue and verifies the resulting state
+    async fn set_paused(&self, name: &str, paused: bool) -> Result<()> {
+        self.backend.set_paused(name, paused).await?;
+
+        match self.query_printer(name).await? {
+            Some(printer) if printer.is_paused() == paused => {
+                info!(
+                    "Printer '{}' {}",
+                    printer.name(),
+                    if paused { "paused" } else { "resumed" }
+                );
+                Ok(())
+            }
+            Some(printer) => Err(PrinterError::Other(format!(
+                "'{}' still reports {} after {}",
+                name,
+                printer.status_description(),
+                if paused { "pausing" } else { "resuming" }
+            ))),
+            None => Err(PrinterError::PrinterNotFound(name.to_string())),
         }
     }
 
-    /// Monitors a specific property of a printer for changes.
+    /// Lists the paper sizes a printer supports and its default paper size.
     ///
-    /// This method allows monitoring just a single property, useful for alerting
-    /// on specific conditions like offline status or error state changes.
+    /// Linux reads the `PageSize` option from `lpoptions -p <name> -l`; Windows reads
+    /// `PrinterPaperNames` and the configured `PaperSize` via WMI. When the platform
+    /// has no media information for the printer, the list is empty and the default
+    /// is `None`.
     ///
     /// # Arguments
-    /// * `printer_name` - The name of the printer to monitor
-    /// * `property` - The specific property to watch using MonitorableProperty enum
-    /// * `interval_ms` - Polling interval in milliseconds
-    /// * `callback` - Function called when the property changes
+    /// * `name` - The name of the printer (case-insensitive on Windows)
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    pub async fn media_sizes(&self, name: &str) -> Result<MediaInfo> {
+        self.backend.media_sizes(name).await
+    }
+
+    /// Lists the printer drivers installed on the system, for driver auditing.
+    ///
+    /// Windows queries `Win32_PrinterDriver`; Linux lists the drivers CUPS offers
+    /// via `lpinfo -m`, which can take a few seconds on systems with many PPDs.
     ///
     /// # Example
     /// ```rust,no_run
-    /// use printer_event_handler::{PrinterMonitor, MonitorableProperty};
+    /// use printer_event_handler::PrinterMonitor;
     ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let monitor = PrinterMonitor::new().await.unwrap();
-    ///     
-    ///     monitor.monitor_property("HP LaserJet", MonitorableProperty::IsOffline, 60000, |change| {
-    ///         println!("Offline status changed: {}", change.description());
-    ///     }).await.unwrap();
+    ///     for driver in monitor.list_drivers().await.unwrap() {
+    ///         println!("{} {:?} ({:?})", driver.name, driver.version, driver.environment);
+    ///     }
     /// }
     /// ```
-    pub async fn monitor_property<F>(
-        &self,
-        printer_name: &str,
-        property: MonitorableProperty,
-        interval_ms: u64,
-        mut callback: F,
-    ) -> Result<()>
-    where
-        F: FnMut(&crate::PropertyChange) + Send,
-    {
-        let property_name = property.as_str();
-        info!(
-            "Starting property '{}' monitoring for printer: {}",
-            property_name, printer_name
-        );
-
-        self.monitor_printer_changes(printer_name, interval_ms, move |changes| {
-            for change in &changes.changes {
-                if change.property_name() == property_name {
-                    callback(change);
-                }
-            }
-        })
-        .await
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If `lpinfo` fails on Linux
+    /// * `PrinterError::IoError` - If `lpinfo` cannot be run on Linux
+    /// * `PrinterError::PlatformNotSupported` - If the backend cannot list drivers
+    pub async fn list_drivers(&self) -> Result<Vec<PrinterDriver>> {
+        self.backend.list_drivers().await
     }
 
-    /// Monitors multiple printers concurrently and reports changes for any of them.
+    /// Lists the CUPS printer classes and their member printers (Linux only).
     ///
-    /// This method allows monitoring several printers simultaneously, with a single
-    /// callback that receives changes from any of the monitored printers.
+    /// A class is a named group of printers that CUPS load-balances jobs across;
+    /// the class name can be printed to like a printer. Reads `lpstat -c`.
     ///
-    /// # Arguments
-    /// * `printer_names` - List of printer names to monitor
-    /// * `interval_ms` - Polling interval in milliseconds
-    /// * `callback` - Function called when any printer changes
+    /// # Errors
+    /// * `PrinterError::CupsError` - If `lpstat` fails
+    /// * `PrinterError::IoError` - If `lpstat` cannot be run
+    /// * `PrinterError::PlatformNotSupported` - On Windows, which has no printer classes
+    pub async fn list_classes(&self) -> Result<Vec<PrinterClass>> {
+        self.backend.list_classes().await
+    }
+
+    /// Finds a printer by name, or the member printers if the name is a CUPS class.
+    ///
+    /// For a class (matched case-insensitively, like CUPS destinations) the current
+    /// state of each member is returned in class order; members that no longer
+    /// exist are skipped. Otherwise this returns the printer found by
+    /// [`Self::find_printer`], or an empty list. On platforms without classes it
+    /// behaves like [`Self::find_printer`].
     ///
     /// # Example
     /// ```rust,no_run
@@ -511,54 +1178,1747 @@ impl PrinterMonitor {
     /// #[tokio::main]
     /// async fn main() {
     ///     let monitor = PrinterMonitor::new().await.unwrap();
-    ///     let printers = vec!["HP LaserJet".to_string(), "Canon Printer".to_string()];
-    ///     
-    ///     monitor.monitor_multiple_printers(printers, 30000, |changes| {
-    ///         println!("Printer '{}' changed: {}", changes.printer_name, changes.summary());
-    ///     }).await.unwrap();
+    ///     for printer in monitor.find_printer_or_class("office").await.unwrap() {
+    ///         println!("{}: {}", printer.name(), printer.status_description());
+    ///     }
     /// }
     /// ```
-    pub async fn monitor_multiple_printers<F>(
-        &self,
-        printer_names: Vec<String>,
-        interval_ms: u64,
-        callback: F,
-    ) -> Result<()>
-    where
-        F: Fn(&PrinterChanges) + Send + Sync + 'static,
-    {
-        use std::sync::Arc;
-        use tokio::task::JoinHandle;
-
-        info!(
-            "Starting concurrent monitoring of {} printers",
-            printer_names.len()
-        );
+    pub async fn find_printer_or_class(&self, name: &str) -> Result<Vec<Printer>> {
+        let classes = match self.backend.list_classes().await {
+            Ok(classes) => classes,
+            Err(PrinterError::PlatformNotSupported) => Vec::new(),
+            Err(e) => return Err(e),
+        };
 
-        let callback = Arc::new(callback);
-        let mut tasks: Vec<JoinHandle<Result<()>>> = Vec::new();
-
-        for printer_name in printer_names {
-            let callback_clone = callback.clone();
-            let printer_name_clone = printer_name.clone();
-
-            let task = tokio::spawn(async move {
-                // This is a bit tricky - we can't easily clone self, so we need to create a new monitor
-                // In practice, you'd want to refactor this to share the backend more efficiently
-                let new_monitor = PrinterMonitor::new().await?;
-                new_monitor
-                    .monitor_printer_changes(&printer_name_clone, interval_ms, move |changes| {
-                        callback_clone(changes);
+        match classes
+            .into_iter()
+            .find(|class| class.name.to_lowercase() == name.to_lowercase())
+        {
+            Some(class) => {
+                let printers = self.list_unfiltered().await?;
+                Ok(class
+                    .members
+                    .iter()
+                    .filter_map(|member| {
+                        printers
+                            .iter()
+                            .find(|printer| printer.name().to_lowercase() == member.to_lowercase())
+                            .cloned()
                     })
-                    .await
-            });
-
-            tasks.push(task);
+                    .collect())
+            }
+            None => Ok(self.find_printer(name).await?.into_iter().collect()),
         }
+    }
 
-        // Wait for all monitoring tasks (this will run indefinitely unless one fails)
-        for task in tasks {
-            match task.await {
+    /// Reads how many pages a printer has printed, for usage and cost tracking.
+    ///
+    /// Windows reads the spooler's `TotalPagesPrinted` performance counter, which
+    /// counts since the spooler last started. Linux sums the printer's entries in
+    /// the CUPS page log (`/var/log/cups/page_log`), so the total covers whatever
+    /// the log has retained. Returns `None` when no counter is available, e.g. when
+    /// page logging is disabled or the log is not readable, and for printers that
+    /// do not exist. Sample the value over time to track consumption.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the printer (case-insensitive on Windows)
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI worker is unavailable on Windows
+    /// * `PrinterError::IoError` - If the page log cannot be read on Linux
+    pub async fn page_count(&self, name: &str) -> Result<Option<u64>> {
+        self.backend.page_count(name).await
+    }
+
+    /// Gathers all printers and their page counters in one coordinated pass.
+    ///
+    /// A dashboard would otherwise list printers and then query each page counter
+    /// on its own. Windows collects everything in a single round-trip to the WMI
+    /// worker and Linux reads the CUPS page log once for all printers. Tags and
+    /// the [`Self::with_max_printers`] cap apply as in [`Self::list_printers`].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let snapshot = monitor.snapshot_all().await.unwrap();
+    ///
+    ///     for printer in &snapshot.printers {
+    ///         match snapshot.page_count(printer.name()) {
+    ///             Some(pages) => println!("{}: {} pages", printer.name(), pages),
+    ///             None => println!("{}: no page counter", printer.name()),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If the page log cannot be read on Linux
+    pub async fn snapshot_all(&self) -> Result<FleetSnapshot> {
+        let snapshot = self.backend.snapshot_all().await?;
+        let printers = self.finish_listing(snapshot.printers);
+        let mut page_counts = snapshot.page_counts;
+        page_counts.retain(|name, _| printers.iter().any(|printer| printer.name() == name));
+        Ok(FleetSnapshot {
+            printers,
+            page_counts,
+        })
+    }
+
+    /// Exports the whole fleet as a single JSON document for inventory systems.
+    ///
+    /// The document has the form `{ "generated_at": ..., "printers": [...] }`,
+    /// where `generated_at` is the RFC 3339 time from the monitor's clock and
+    /// `printers` holds every printer from [`Self::list_printers`], fully
+    /// serialized.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let document = monitor.export_json().await.unwrap();
+    ///     println!("{}", document);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// * Any error from [`Self::list_printers`]
+    /// * `PrinterError::Other` - If the document cannot be serialized
+    pub async fn export_json(&self) -> Result<String> {
+        #[derive(Serialize)]
+        struct FleetExport {
+            generated_at: DateTime<Utc>,
+            printers: Vec<Printer>,
+        }
+
+        let export = FleetExport {
+            generated_at: self.clock.now(),
+            printers: self.list_printers().await?,
+        };
+        serde_json::to_string(&export)
+            .map_err(|e| PrinterError::Other(format!("cannot serialize fleet export: {}", e)))
+    }
+
+    /// Reports which optional features the current backend supports.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     if monitor.backend_capabilities().supports_jobs {
+    ///         monitor.cancel_all_jobs("HP LaserJet").await.unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn backend_capabilities(&self) -> BackendCapabilities {
+        self.backend.capabilities()
+    }
+
+    /// Retrieves all printers, tolerating individual entries that fail to parse.
+    ///
+    /// Unlike [`Self::list_printers`], a malformed entry (e.g. a broken queue whose
+    /// WMI row cannot be deserialized) does not fail the whole call. Successfully
+    /// parsed printers are returned alongside one error per skipped entry.
+    ///
+    /// # Returns
+    /// * `Result<(Vec<Printer>, Vec<PrinterError>)>` - Parsed printers and per-entry errors
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query itself fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query itself fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let (printers, errors) = monitor.list_printers_lenient().await.unwrap();
+    ///
+    ///     println!("{} printers, {} skipped entries", printers.len(), errors.len());
+    /// }
+    /// ```
+    pub async fn list_printers_lenient(&self) -> Result<(Vec<Printer>, Vec<PrinterError>)> {
+        let started = Instant::now();
+        let result = self.backend.list_printers_lenient().await;
+        self.record_query(started.elapsed());
+        let (printers, errors) = result?;
+        Ok((self.finish_listing(printers), errors))
+    }
+
+    /// Retrieves all printers except per-session redirected ones.
+    ///
+    /// On RDS/Citrix servers every client session adds its own redirected
+    /// printers; see [`Printer::is_redirected`] for how they are detected.
+    ///
+    /// # Returns
+    /// * `Result<Vec<Printer>>` - All printers that are not session-redirected
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    pub async fn list_non_redirected_printers(&self) -> Result<Vec<Printer>> {
+        let printers = self.list_printers().await?;
+        Ok(printers
+            .into_iter()
+            .filter(|printer| !printer.is_redirected())
+            .collect())
+    }
+
+    /// Retrieves all printers except virtual ones such as "Microsoft Print to PDF".
+    ///
+    /// See [`Printer::is_virtual`] for the built-in names and
+    /// [`Self::with_virtual_printer_names`] to extend them.
+    ///
+    /// # Returns
+    /// * `Result<Vec<Printer>>` - All printers backed by real hardware
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    pub async fn list_physical_printers(&self) -> Result<Vec<Printer>> {
+        let printers = self.list_printers().await?;
+        Ok(printers
+            .into_iter()
+            .filter(|printer| !printer.is_virtual_with(&self.virtual_names))
+            .collect())
+    }
+
+    /// Retrieves all printers carrying the given tag (case-insensitive).
+    ///
+    /// # Arguments
+    /// * `tag` - The tag to filter by, as assigned through [`Self::with_tags`]
+    ///
+    /// # Returns
+    /// * `Result<Vec<Printer>>` - The printers tagged with `tag`
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    pub async fn list_printers_with_tag(&self, tag: &str) -> Result<Vec<Printer>> {
+        let printers = self.list_printers().await?;
+        Ok(printers
+            .into_iter()
+            .filter(|printer| printer.has_tag(tag))
+            .collect())
+    }
+
+    /// Retrieves all printers split into online and offline ones.
+    ///
+    /// The listing is partitioned in a single pass, preserving the backend order
+    /// within each group.
+    ///
+    /// # Returns
+    /// * `Result<PrinterPartition>` - The printers grouped by availability
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    pub async fn list_partitioned(&self) -> Result<PrinterPartition> {
+        let printers = self.list_printers().await?;
+        let (online, offline) = printers
+            .into_iter()
+            .partition(|printer| !printer.is_offline());
+        Ok(PrinterPartition { online, offline })
+    }
+
+    /// Lists printers like [`Self::list_printers`], but gives up once `budget` has passed.
+    ///
+    /// Printers are collected from [`Self::printers_stream`] as the backend
+    /// produces them, so a slow `lpstat` still yields the printers parsed so far.
+    /// The flag is `true` when the listing completed within the budget and
+    /// `false` when the printers are only a partial result.
+    ///
+    /// Requires the `stream` feature.
+    ///
+    /// # Errors
+    /// * Any error the backend reports before the budget runs out
+    ///
+    /// # Example
+    /// ```no_run
+    /// use printer_event_handler::PrinterMonitor;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> printer_event_handler::Result<()> {
+    /// let monitor = PrinterMonitor::new().await?;
+    /// let (printers, complete) = monitor
+    ///     .list_printers_budget(Duration::from_millis(500))
+    ///     .await?;
+    /// if !complete {
+    ///     println!("Showing the first {} printers, more are loading", printers.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub async fn list_printers_budget(&self, budget: Duration) -> Result<(Vec<Printer>, bool)> {
+        use futures_util::StreamExt;
+
+        let deadline = Instant::now() + budget;
+        let mut stream = std::pin::pin!(self.printers_stream());
+        let mut printers = Vec::new();
+        loop {
+            match tokio::time::timeout_at(deadline, stream.next()).await {
+                Ok(Some(printer)) => printers.push(printer?),
+                Ok(None) => return Ok((printers, true)),
+                Err(_) => {
+                    warn!(
+                        "Printer listing exceeded its {:?} budget, returning {} printers",
+                        budget,
+                        printers.len()
+                    );
+                    return Ok((printers, false));
+                }
+            }
+        }
+    }
+
+    /// Streams printers one at a time instead of collecting them into a `Vec`.
+    ///
+    /// On Linux each printer is yielded as soon as its `lpstat` entry has been
+    /// parsed; on Windows the rows are yielded once the WMI query has completed.
+    /// Tags configured through [`Self::with_tags`] are applied to every item, and
+    /// the stream ends after [`Self::with_max_printers`] items.
+    ///
+    /// Requires the `stream` feature.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// # async fn example() -> printer_event_handler::Result<()> {
+    /// let monitor = PrinterMonitor::new().await?;
+    /// let mut printers = monitor.printers_stream();
+    /// while let Some(printer) = printers.next().await {
+    ///     println!("{}", printer?.name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn printers_stream(&self) -> impl futures_util::Stream<Item = Result<Printer>> + '_ {
+        use futures_util::StreamExt;
+
+        self.backend
+            .printers_stream()
+            .filter(move |printer| {
+                let fax = matches!(printer, Ok(printer) if printer.is_fax());
+                std::future::ready(!(self.exclude_fax && fax))
+            })
+            .take(self.max_printers.unwrap_or(usize::MAX))
+            .map(move |printer| printer.map(|printer| self.finish_printer(printer)))
+    }
+
+    /// Searches for a specific printer by name using case-insensitive matching.
+    ///
+    /// This method searches through all available printers to find one with
+    /// a name that matches the provided string (case-insensitive). With
+    /// [`Self::with_name_normalization`] enabled, `HP LaserJet` also finds
+    /// `HP_LaserJet` and vice versa.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the printer to search for
+    ///
+    /// # Returns
+    /// * `Result<Option<Printer>>` - The found printer or None if not found
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     
+    ///     if let Some(printer) = monitor.find_printer("HP LaserJet").await.unwrap() {
+    ///         println!("Found printer: {}", printer.name());
+    ///     }
+    /// }
+    /// ```
+    pub async fn find_printer(&self, name: &str) -> Result<Option<Printer>> {
+        let mut printer = self.query_printer(name).await?;
+        if printer.is_none() && self.normalize_names {
+            let wanted = normalize_name(name);
+            printer = self
+                .query_printers()
+                .await?
+                .into_iter()
+                .find(|printer| normalize_name(printer.name()) == wanted);
+        }
+        Ok(printer.map(|printer| self.finish_printer(printer)))
+    }
+
+    /// Finds several printers by name with a single listing.
+    ///
+    /// Looking up each name with [`Self::find_printer`] lists the printers once per
+    /// name; this lists them once and matches every name against the result,
+    /// ignoring case (and applying name normalization when enabled). The map has an
+    /// entry for every requested name, keyed as given, with `None` for printers
+    /// that do not exist.
+    ///
+    /// # Arguments
+    /// * `names` - The names of the printers to look up
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let found = monitor.find_printers(&["HP LaserJet", "Canon MF"]).await.unwrap();
+    ///     for (name, printer) in &found {
+    ///         match printer {
+    ///             Some(printer) => println!("{}: {}", name, printer.status()),
+    ///             None => println!("{}: not found", name),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn find_printers(&self, names: &[&str]) -> Result<HashMap<String, Option<Printer>>> {
+        let printers = self.list_unfiltered().await?;
+        Ok(names
+            .iter()
+            .map(|&name| {
+                let found = printers
+                    .iter()
+                    .find(|printer| printer.name().to_lowercase() == name.to_lowercase())
+                    .or_else(|| {
+                        if !self.normalize_names {
+                            return None;
+                        }
+                        let wanted = normalize_name(name);
+                        printers
+                            .iter()
+                            .find(|printer| normalize_name(printer.name()) == wanted)
+                    });
+                (name.to_string(), found.cloned())
+            })
+            .collect())
+    }
+
+    /// Re-fetches a printer and reports what changed since it was obtained.
+    ///
+    /// A pull-style alternative to the monitoring loops: keep the returned
+    /// printer and pass it in again next time. The printer is looked up by name
+    /// with [`Self::find_printer`], and the changes are the diff from `printer`
+    /// to the fresh copy, timestamped with the monitor's clock.
+    ///
+    /// # Arguments
+    /// * `printer` - A previously obtained copy of the printer
+    ///
+    /// # Errors
+    /// * `PrinterError::PrinterNotFound` - If the printer no longer exists
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let printer = monitor.find_printer("HP LaserJet").await.unwrap().unwrap();
+    ///
+    ///     // ... later
+    ///     let (printer, changes) = monitor.refresh(&printer).await.unwrap();
+    ///     println!("{}: {}", printer.name(), changes.summary());
+    /// }
+    /// ```
+    pub async fn refresh(&self, printer: &Printer) -> Result<(Printer, PrinterChanges)> {
+        let current = self
+            .find_printer(printer.name())
+            .await?
+            .ok_or_else(|| PrinterError::PrinterNotFound(printer.name().to_string()))?;
+        let changes = printer.compare_with_clock(&current, self.clock.as_ref());
+        Ok((current, changes))
+    }
+
+    /// Finds all printers whose name matches a glob pattern.
+    ///
+    /// `*` matches any run of characters and `?` matches exactly one; every other
+    /// character matches itself, ignoring case like [`Self::find_printer`]. Handy
+    /// for naming conventions, e.g. `HP_*` or `Floor?-*`.
+    ///
+    /// # Arguments
+    /// * `pattern` - The glob pattern to match printer names against
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    pub async fn find_printers_glob(&self, pattern: &str) -> Result<Vec<Printer>> {
+        let printers = self.list_printers().await?;
+        Ok(printers
+            .into_iter()
+            .filter(|printer| glob_match(pattern, printer.name()))
+            .collect())
+    }
+
+    /// Finds all printers whose name matches a regular expression.
+    ///
+    /// The expression is matched as-is (add `(?i)` for case-insensitive matching
+    /// and anchors for whole-name matching). Requires the `regex` feature.
+    ///
+    /// # Errors
+    /// Same as [`Self::find_printers_glob`].
+    #[cfg(feature = "regex")]
+    pub async fn find_printers_regex(&self, re: &regex::Regex) -> Result<Vec<Printer>> {
+        let printers = self.list_printers().await?;
+        Ok(printers
+            .into_iter()
+            .filter(|printer| re.is_match(printer.name()))
+            .collect())
+    }
+
+    /// Continuously monitors a specific printer for status changes.
+    ///
+    /// This function runs indefinitely, polling the specified printer every `interval_ms`
+    /// milliseconds and calling the provided callback function whenever the printer's status changes.
+    /// The callback receives both the current printer state and the previous state (if any).
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `callback` - Function called when printer status changes, receives (current, previous)
+    ///
+    /// # Returns
+    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    ///
+    /// # Errors
+    /// * `PrinterError::InvalidConfig` - If `interval_ms` is 0 (every `monitor_*` and
+    ///   `wait_*` method rejects a zero interval, which would otherwise busy-loop)
+    /// * `PrinterError::PrinterNotFound` - If the specified printer is not found initially
+    /// * `PrinterError::WmiError` - If WMI queries fail on Windows
+    /// * `PrinterError::CupsError` - If CUPS queries fail on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    ///
+    /// # Behavior
+    /// - If the printer disappears during monitoring, the callback is called with a synthetic
+    ///   "unknown" status to indicate the printer is no longer available
+    /// - The first check always triggers the callback to provide the initial status
+    /// - Subsequent calls only trigger the callback if the status actually changes
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     
+    ///     monitor.monitor_printer("HP LaserJet", 30000, |current, previous| {
+    ///         if let Some(prev) = previous {
+    ///             if prev != current {
+    ///                 println!("Status changed: {} -> {}",
+    ///                     prev.status_description(),
+    ///                     current.status_description());
+    ///             }
+    ///         } else {
+    ///             println!("Initial status: {}", current.status_description());
+    ///         }
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_printer<F>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&Printer, Option<&Printer>) + Send,
+    {
+        self.monitor_printer_with_heartbeat(printer_name, interval_ms, 0, callback, |_| {})
+            .await
+    }
+
+    /// Monitors a printer like [`Self::monitor_printer`] until Ctrl-C is pressed.
+    ///
+    /// Returns `Ok(())` on interrupt instead of the process being killed mid-poll,
+    /// so a CLI can clean up and exit normally. Errors end monitoring as usual. If
+    /// the Ctrl-C handler cannot be installed, monitoring runs until it fails.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     println!("Press Ctrl+C to stop");
+    ///     monitor.monitor_printer_until_ctrl_c("HP LaserJet", 5000, |current, _previous| {
+    ///         println!("Changed: {}", current.status_description());
+    ///     }).await.unwrap();
+    ///     println!("Stopped");
+    /// }
+    /// ```
+    pub async fn monitor_printer_until_ctrl_c<F>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&Printer, Option<&Printer>) + Send,
+    {
+        let ctrl_c = async {
+            if let Err(e) = tokio::signal::ctrl_c().await {
+                warn!("Cannot listen for Ctrl-C: {}", e);
+                std::future::pending::<()>().await;
+            }
+        };
+        self.monitor_printer_until(printer_name, interval_ms, callback, ctrl_c)
+            .await
+    }
+
+    /// Monitors a printer like [`Self::monitor_printer`] until `shutdown` completes.
+    ///
+    /// Any future works as the shutdown signal, e.g. a
+    /// [`tokio::sync::oneshot::Receiver`] or a signal other than Ctrl-C. Monitoring
+    /// stops at the next `.await` point once it resolves and `Ok(())` is returned.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `callback` - Function called when printer status changes, receives (current, previous)
+    /// * `shutdown` - Future whose completion stops monitoring
+    pub async fn monitor_printer_until<F, S>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        callback: F,
+        shutdown: S,
+    ) -> Result<()>
+    where
+        F: FnMut(&Printer, Option<&Printer>) + Send,
+        S: Future<Output = ()> + Send,
+    {
+        tokio::select! {
+            result = self.monitor_printer(printer_name, interval_ms, callback) => result,
+            () = shutdown => {
+                info!("Monitoring of '{}' stopped on request", printer_name);
+                Ok(())
+            }
+        }
+    }
+
+    /// Monitors a printer like [`Self::monitor_printer`], additionally emitting periodic heartbeats.
+    ///
+    /// The change callback behaves exactly as in `monitor_printer`. Independently of it,
+    /// `on_heartbeat` is invoked with the current printer on every `heartbeat_every`-th
+    /// successful poll, even when nothing changed. This lets watchdogs confirm that
+    /// monitoring is still alive.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `heartbeat_every` - Number of polls between heartbeats (0 disables heartbeats)
+    /// * `callback` - Function called when printer status changes, receives (current, previous)
+    /// * `on_heartbeat` - Function called every `heartbeat_every` polls with the current printer
+    ///
+    /// # Returns
+    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     // Poll every 5 seconds, heartbeat once a minute
+    ///     monitor.monitor_printer_with_heartbeat(
+    ///         "HP LaserJet",
+    ///         5000,
+    ///         12,
+    ///         |current, _previous| println!("Changed: {}", current.status_description()),
+    ///         |current| println!("Still watching {}", current.name()),
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_printer_with_heartbeat<F, H>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        heartbeat_every: u32,
+        callback: F,
+        on_heartbeat: H,
+    ) -> Result<()>
+    where
+        F: FnMut(&Printer, Option<&Printer>) + Send,
+        H: FnMut(&Printer) + Send,
+    {
+        validate_interval(interval_ms)?;
+        let mut trigger = PollTrigger::Interval(Duration::from_millis(interval_ms));
+        self.watch_printer(
+            printer_name,
+            &mut trigger,
+            heartbeat_every,
+            callback,
+            on_heartbeat,
+        )
+        .await
+    }
+
+    /// Monitors a printer like [`Self::monitor_printer`], re-querying only when CUPS state changes.
+    ///
+    /// Instead of running `lpstat` on a fixed interval, this watches the CUPS state
+    /// directories (`/var/spool/cups`, `/var/cache/cups`, `/etc/cups`, `/run/cups`)
+    /// with inotify and queries the printer once something in them changes, and at
+    /// the latest every `fallback_interval_ms`. If none of the directories can be
+    /// watched (e.g. insufficient permissions) it falls back to polling every
+    /// `fallback_interval_ms`.
+    ///
+    /// Requires the `inotify` feature; Linux only.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `fallback_interval_ms` - Longest time between queries, and the polling interval when watching is unavailable
+    /// * `callback` - Function called when printer status changes, receives (current, previous)
+    ///
+    /// # Returns
+    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     monitor.monitor_printer_on_cups_events("HP_LaserJet", 60_000, |current, _previous| {
+    ///         println!("Changed: {}", current.status_description());
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    #[cfg(all(target_os = "linux", feature = "inotify"))]
+    pub async fn monitor_printer_on_cups_events<F>(
+        &self,
+        printer_name: &str,
+        fallback_interval_ms: u64,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&Printer, Option<&Printer>) + Send,
+    {
+        validate_interval(fallback_interval_ms)?;
+        let mut trigger = PollTrigger::cups_events(Duration::from_millis(fallback_interval_ms));
+        self.watch_printer(printer_name, &mut trigger, 0, callback, |_| {})
+            .await
+    }
+
+    /// Runs the single-printer monitoring loop, waiting on `trigger` between queries
+    async fn watch_printer<F, H>(
+        &self,
+        printer_name: &str,
+        trigger: &mut PollTrigger,
+        heartbeat_every: u32,
+        mut callback: F,
+        mut on_heartbeat: H,
+    ) -> Result<()>
+    where
+        F: FnMut(&Printer, Option<&Printer>) + Send,
+        H: FnMut(&Printer) + Send,
+    {
+        info!("Starting printer monitoring service for: {}", printer_name);
+
+        let mut name = printer_name.to_string();
+        let mut previous_printer: Option<Printer> = None;
+        let mut polls: u64 = 0;
+        let mut failures = 0;
+        let watchdog = self.start_watchdog(trigger.interval());
+
+        loop {
+            let result = self
+                .find_tracked_printer(&mut name, previous_printer.as_ref())
+                .await;
+            if let Some(watchdog) = &watchdog {
+                watchdog.poll_completed();
+            }
+            if result.is_ok() {
+                failures = 0;
+            }
+            match result {
+                Ok(Some(current_printer)) => {
+                    polls += 1;
+                    if heartbeat_every > 0 && polls.is_multiple_of(u64::from(heartbeat_every)) {
+                        on_heartbeat(&current_printer);
+                    }
+
+                    println!(
+                        "[{}] Checking printer: {}",
+                        self.clock.now().format("%Y-%m-%d %H:%M:%S UTC"),
+                        current_printer.name()
+                    );
+                    let has_changed = previous_printer
+                        .as_ref()
+                        .map(|prev| prev != &current_printer)
+                        .unwrap_or(true);
+
+                    if has_changed {
+                        callback(&current_printer, previous_printer.as_ref());
+                        info!(
+                            "Printer '{}' - Status: {}, Error: {}",
+                            name,
+                            current_printer.status_description(),
+                            current_printer.error_description()
+                        );
+                        previous_printer = Some(current_printer);
+                    } else {
+                        info!("Printer '{}' status unchanged", name);
+                    }
+                }
+                Ok(None) => {
+                    warn!("Printer '{}' not found", name);
+                    if previous_printer.is_some() {
+                        // Printer was previously found but now missing
+                        callback(
+                            &Printer::new(
+                                name.clone(),
+                                crate::PrinterStatus::StatusUnknown,
+                                crate::ErrorState::UnknownError,
+                                true,
+                                false,
+                            ),
+                            previous_printer.as_ref(),
+                        );
+                        previous_printer = None;
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to check printer status: {}", e);
+                    self.recover(e, &mut failures).await?;
+                }
+            }
+
+            trigger.wait(|delay| self.poll_delay(delay)).await;
+        }
+    }
+
+    /// Polls a printer until it satisfies `predicate`, returning the matching state.
+    ///
+    /// A printer that is temporarily missing is waited for like any other
+    /// non-matching state.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to wait for
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `timeout` - Maximum time to wait
+    /// * `predicate` - Condition the printer must satisfy
+    ///
+    /// # Returns
+    /// * `Result<Printer>` - The first printer state satisfying `predicate`
+    ///
+    /// # Errors
+    /// * `PrinterError::Timeout` - If the condition was not met within `timeout`
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::{PrinterMonitor, PrinterStatus};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let printer = monitor
+    ///         .wait_until("HP LaserJet", 1000, Duration::from_secs(60), |p| {
+    ///             *p.status() == PrinterStatus::Idle
+    ///         })
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{} is idle", printer.name());
+    /// }
+    /// ```
+    pub async fn wait_until<P>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        timeout: Duration,
+        predicate: P,
+    ) -> Result<Printer>
+    where
+        P: Fn(&Printer) -> bool + Send,
+    {
+        validate_interval(interval_ms)?;
+        let poll = async {
+            loop {
+                if let Some(printer) = self.find_printer(printer_name).await?
+                    && predicate(&printer)
+                {
+                    return Ok(printer);
+                }
+                sleep(self.poll_delay(Duration::from_millis(interval_ms))).await;
+            }
+        };
+
+        tokio::time::timeout(timeout, poll).await.map_err(|_| {
+            PrinterError::Timeout(format!(
+                "printer '{}' did not reach the expected state within {:?}",
+                printer_name, timeout
+            ))
+        })?
+    }
+
+    /// Waits until a printer is back online (see [`Self::wait_until`]).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let printer = monitor
+    ///         .wait_online("HP LaserJet", 2000, Duration::from_secs(300))
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{} is back: {}", printer.name(), printer.status_description());
+    /// }
+    /// ```
+    pub async fn wait_online(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        timeout: Duration,
+    ) -> Result<Printer> {
+        self.wait_until(printer_name, interval_ms, timeout, |printer| {
+            !printer.is_offline()
+        })
+        .await
+    }
+
+    /// Waits until a printer reports no error, e.g. after a jam was cleared
+    /// (see [`Self::wait_until`]).
+    pub async fn wait_no_error(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        timeout: Duration,
+    ) -> Result<Printer> {
+        self.wait_until(printer_name, interval_ms, timeout, |printer| {
+            !printer.has_error()
+        })
+        .await
+    }
+
+    /// Waits until a raw status code of a printer reaches `target` (see [`Self::wait_until`]).
+    ///
+    /// For automation that depends on exact hardware states, e.g. waiting for
+    /// DetectedErrorState to return to 2 ("No Error") after a jam.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to wait for
+    /// * `property` - The raw code to watch: one of the `*Code` properties
+    /// * `target` - The code value to wait for
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `timeout` - Maximum time to wait
+    ///
+    /// # Errors
+    /// * `PrinterError::InvalidConfig` - If `property` is not a raw status code
+    /// * `PrinterError::Timeout` - If the code did not reach `target` within `timeout`
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::{MonitorableProperty, PrinterMonitor};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     monitor
+    ///         .monitor_until_code(
+    ///             "HP LaserJet",
+    ///             MonitorableProperty::DetectedErrorStateCode,
+    ///             2,
+    ///             1000,
+    ///             Duration::from_secs(300),
+    ///         )
+    ///         .await
+    ///         .unwrap();
+    ///     println!("Jam cleared");
+    /// }
+    /// ```
+    pub async fn monitor_until_code(
+        &self,
+        printer_name: &str,
+        property: MonitorableProperty,
+        target: u32,
+        interval_ms: u64,
+        timeout: Duration,
+    ) -> Result<Printer> {
+        let code: fn(&Printer) -> Option<u32> = match property {
+            MonitorableProperty::PrinterStatusCode => Printer::printer_status_code,
+            MonitorableProperty::PrinterStateCode => Printer::printer_state_code,
+            MonitorableProperty::DetectedErrorStateCode => Printer::detected_error_state_code,
+            MonitorableProperty::ExtendedDetectedErrorStateCode => {
+                Printer::extended_detected_error_state_code
+            }
+            MonitorableProperty::ExtendedPrinterStatusCode => Printer::extended_printer_status_code,
+            _ => {
+                return Err(PrinterError::InvalidConfig(format!(
+                    "{} is not a raw status code",
+                    property.as_str()
+                )));
+            }
+        };
+        self.wait_until(printer_name, interval_ms, timeout, move |printer| {
+            code(printer) == Some(target)
+        })
+        .await
+    }
+
+    /// Retrieves a comprehensive summary of all printers and their current states.
+    ///
+    /// This method provides a convenient way to get an overview of all printers
+    /// in a structured format, useful for status dashboards or reports.
+    ///
+    /// # Returns
+    /// * `Result<HashMap<String, PrinterSummary>>` - Map of printer names to their summaries
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let summary = monitor.printer_summary().await.unwrap();
+    ///     
+    ///     for (name, info) in summary {
+    ///         println!("{}: {} ({})", name, info.status,
+    ///             if info.has_error { "ERROR" } else { "OK" });
+    ///     }
+    /// }
+    /// ```
+    pub async fn printer_summary(&self) -> Result<HashMap<String, PrinterSummary>> {
+        let printers = self.list_printers().await?;
+        let mut summary = HashMap::new();
+
+        for printer in printers {
+            summary.insert(
+                printer.name().to_string(),
+                PrinterSummary {
+                    status: printer.status().clone(),
+                    error_state: printer.error_state().clone(),
+                    is_offline: printer.is_offline(),
+                    is_default: printer.is_default(),
+                    has_error: printer.has_error(),
+                },
+            );
+        }
+
+        Ok(summary)
+    }
+
+    /// Lists only the printers that currently have problems, with the reasons why.
+    ///
+    /// Gathers offline, error, low-supply, door-open, out-of-memory and manual-feed
+    /// conditions, as well as conflicting status signals (see
+    /// [`Printer::has_status_conflict`]), for every printer into human-readable
+    /// reasons; printers without issues are omitted.
+    /// Useful as a single morning health check across the fleet.
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     for problem in monitor.problems().await.unwrap() {
+    ///         println!("[{}] {}: {}", problem.severity, problem.name, problem.reasons.join("; "));
+    ///     }
+    /// }
+    /// ```
+    pub async fn problems(&self) -> Result<Vec<PrinterProblem>> {
+        let printers = self.list_printers().await?;
+        Ok(printers.iter().filter_map(PrinterProblem::of).collect())
+    }
+
+    /// Rolls the whole fleet up into a single healthy/unhealthy flag.
+    ///
+    /// Returns `false` if any physical printer (see [`Printer::is_virtual`]) is
+    /// offline or has an error, `true` otherwise. Every printer counts, including
+    /// those [`Self::with_max_printers`] or [`Self::with_fax_excluded`] leave out of
+    /// listings; only printers on the ignore list (see
+    /// [`Self::with_health_ignore_list`]) are not considered.
+    ///
+    /// # Errors
+    /// * `PrinterError::WmiError` - If the WMI query fails on Windows
+    /// * `PrinterError::CupsError` - If the CUPS query fails on Linux
+    /// * `PrinterError::IoError` - If there are system I/O issues
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::builder()
+    ///         .health_ignore_list(vec!["Old Plotter".to_string()])
+    ///         .build()
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     let light = if monitor.all_healthy().await.unwrap() { "green" } else { "red" };
+    ///     println!("Fleet status: {}", light);
+    /// }
+    /// ```
+    pub async fn all_healthy(&self) -> Result<bool> {
+        let printers = self.list_unfiltered().await?;
+        Ok(printers
+            .iter()
+            .filter(|printer| !printer.is_virtual_with(&self.virtual_names))
+            .filter(|printer| !self.health_ignored.contains(&printer.name().to_lowercase()))
+            .all(|printer| !printer.is_offline() && !printer.has_error()))
+    }
+
+    /// Monitors a printer with detailed property change detection.
+    ///
+    /// This enhanced monitoring method provides detailed information about exactly which
+    /// properties changed between checks, enabling fine-grained monitoring and alerting.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `callback` - Function called when properties change, receives PrinterChanges
+    ///
+    /// # Returns
+    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     
+    ///     monitor.monitor_printer_changes("HP LaserJet", 30000, |changes| {
+    ///         if changes.has_changes() {
+    ///             println!("Detected {} changes:", changes.change_count());
+    ///             for change in &changes.changes {
+    ///                 println!("  - {}", change.description());
+    ///             }
+    ///         }
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_printer_changes<F>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        mut callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&PrinterChanges) + Send,
+    {
+        self.monitor_printer_changes_async(printer_name, interval_ms, move |changes| {
+            callback(&changes);
+            std::future::ready(())
+        })
+        .await
+    }
+
+    /// Monitors a printer like [`Self::monitor_printer_changes`] with an async callback.
+    ///
+    /// The future returned by `callback` is awaited before the next poll, so the
+    /// callback can do async work (HTTP requests, database writes) without blocking
+    /// tricks, and changes are always handled in order.
+    ///
+    /// Both the callback and its future must be `Send`: the monitoring future holds
+    /// them across `.await` points, and it must stay `Send` to be usable with
+    /// `tokio::spawn` on a multi-threaded runtime.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `callback` - Async function called when properties change, receives PrinterChanges
+    ///
+    /// # Returns
+    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     monitor.monitor_printer_changes_async("HP LaserJet", 30000, |changes| async move {
+    ///         // e.g. post the changes to a web hook
+    ///         println!("{}: {}", changes.printer_name, changes.summary());
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_printer_changes_async<F, Fut>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        mut callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(PrinterChanges) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+    {
+        validate_interval(interval_ms)?;
+        info!(
+            "Starting detailed printer change monitoring for: {}",
+            printer_name
+        );
+
+        let mut name = printer_name.to_string();
+        let mut previous_printer: Option<Printer> = None;
+        let mut failures = 0;
+        let watchdog = self.start_watchdog(Duration::from_millis(interval_ms));
+
+        loop {
+            let result = self
+                .find_tracked_printer(&mut name, previous_printer.as_ref())
+                .await;
+            if let Some(watchdog) = &watchdog {
+                watchdog.poll_completed();
+            }
+            if result.is_ok() {
+                failures = 0;
+            }
+            match result {
+                Ok(Some(current_printer)) => {
+                    if let Some(ref prev) = previous_printer {
+                        let changes =
+                            prev.compare_with_clock(&current_printer, self.clock.as_ref());
+                        if changes.has_changes() {
+                            info!(
+                                "Printer '{}' - {} properties changed",
+                                name,
+                                changes.change_count()
+                            );
+                            callback(changes).await;
+                        }
+                    } else {
+                        // Initial state - report as "initial" (no previous state)
+                        let changes = PrinterChanges::new_with_clock(
+                            current_printer.name().to_string(),
+                            self.clock.as_ref(),
+                        );
+                        callback(changes).await;
+                        info!("Printer '{}' - Initial state captured", name);
+                    }
+                    previous_printer = Some(current_printer);
+                }
+                Ok(None) => {
+                    warn!("Printer '{}' not found", name);
+                    if let Some(prev) = previous_printer.take() {
+                        // Printer disappeared - create a change showing it went offline
+                        let mut changes =
+                            PrinterChanges::new_with_clock(name.clone(), self.clock.as_ref());
+                        changes.changes.push(crate::PropertyChange::IsOffline {
+                            old: prev.is_offline(),
+                            new: true,
+                        });
+                        callback(changes).await;
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to check printer status: {}", e);
+                    self.recover(e, &mut failures).await?;
+                }
+            }
+
+            sleep(self.poll_delay(Duration::from_millis(interval_ms))).await;
+        }
+    }
+
+    /// Monitors a printer like [`Self::monitor_printer_changes`], consolidating changes over a window.
+    ///
+    /// The first change opens a window of length `window`; further changes during
+    /// it are merged (see [`PrinterChanges::merge`]) and the callback receives the
+    /// net changes once the window has elapsed. Properties that changed and
+    /// changed back within a window are not reported. Pending changes are flushed
+    /// before an error is returned.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `window` - How long to accumulate changes before reporting them
+    /// * `callback` - Function called with the consolidated changes of each window
+    ///
+    /// # Returns
+    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     // Poll every 5 seconds, alert at most every 30 seconds
+    ///     monitor.monitor_printer_windowed("HP LaserJet", 5000, Duration::from_secs(30), |changes| {
+    ///         println!("{}", changes.summary());
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_printer_windowed<F>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        window: Duration,
+        mut callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&PrinterChanges) + Send,
+    {
+        validate_interval(interval_ms)?;
+        info!(
+            "Starting windowed printer change monitoring for: {}",
+            printer_name
+        );
+
+        let mut name = printer_name.to_string();
+        let mut previous_printer: Option<Printer> = None;
+        let mut pending: Option<(PrinterChanges, Instant)> = None;
+        let mut failures = 0;
+        let watchdog = self.start_watchdog(Duration::from_millis(interval_ms));
+
+        loop {
+            let result = self
+                .find_tracked_printer(&mut name, previous_printer.as_ref())
+                .await;
+            if let Some(watchdog) = &watchdog {
+                watchdog.poll_completed();
+            }
+            if result.is_ok() {
+                failures = 0;
+            }
+            match result {
+                Ok(Some(current_printer)) => {
+                    if let Some(ref prev) = previous_printer {
+                        let changes =
+                            prev.compare_with_clock(&current_printer, self.clock.as_ref());
+                        if changes.has_changes() {
+                            match pending.as_mut() {
+                                Some((accumulated, _)) => accumulated.merge(&changes),
+                                None => pending = Some((changes, Instant::now())),
+                            }
+                        }
+                    }
+                    previous_printer = Some(current_printer);
+                }
+                Ok(None) => {
+                    warn!("Printer '{}' not found", name);
+                }
+                Err(e) => {
+                    error!("Failed to check printer status: {}", e);
+                    if let Err(e) = self.recover(e, &mut failures).await {
+                        if let Some((accumulated, _)) = pending.take()
+                            && accumulated.has_changes()
+                        {
+                            callback(&accumulated);
+                        }
+                        return Err(e);
+                    }
+                }
+            }
+
+            if let Some((_, opened)) = &pending
+                && opened.elapsed() >= window
+                && let Some((accumulated, _)) = pending.take()
+                && accumulated.has_changes()
+            {
+                info!(
+                    "Printer '{}' - {} net changes in window",
+                    printer_name,
+                    accumulated.change_count()
+                );
+                callback(&accumulated);
+            }
+
+            sleep(self.poll_delay(Duration::from_millis(interval_ms))).await;
+        }
+    }
+
+    /// Monitors a specific property of a printer for changes.
+    ///
+    /// This method allows monitoring just a single property, useful for alerting
+    /// on specific conditions like offline status or error state changes.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `property` - The specific property to watch using MonitorableProperty enum
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `callback` - Function called when the property changes
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::{PrinterMonitor, MonitorableProperty};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     
+    ///     monitor.monitor_property("HP LaserJet", MonitorableProperty::IsOffline, 60000, |change| {
+    ///         println!("Offline status changed: {}", change.description());
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_property<F>(
+        &self,
+        printer_name: &str,
+        property: MonitorableProperty,
+        interval_ms: u64,
+        mut callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&crate::PropertyChange) + Send,
+    {
+        let property_name = property.as_str();
+        info!(
+            "Starting property '{}' monitoring for printer: {}",
+            property_name, printer_name
+        );
+
+        self.monitor_printer_changes(printer_name, interval_ms, move |changes| {
+            for change in &changes.changes {
+                if change.property_name() == property_name {
+                    callback(change);
+                }
+            }
+        })
+        .await
+    }
+
+    /// Monitors a printer, reporting only when it enters or leaves an error state.
+    ///
+    /// Unlike [`Self::monitor_property`] with [`MonitorableProperty::ErrorState`],
+    /// a change from one error to another (e.g. jam → out of paper) is not reported,
+    /// which keeps paging noise down. Use [`Self::monitor_error_transitions_with`]
+    /// to include those as well.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `callback` - Function called with every OK → error and error → OK transition
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     monitor.monitor_error_transitions("HP LaserJet", 30000, |transition| {
+    ///         if transition.became_error {
+    ///             println!("Page on-call: {} since {}", transition.to, transition.at);
+    ///         }
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_error_transitions<F>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(ErrorTransition) + Send,
+    {
+        self.monitor_error_transitions_with(printer_name, interval_ms, false, callback)
+            .await
+    }
+
+    /// Monitors error transitions like [`Self::monitor_error_transitions`], optionally
+    /// also reporting changes from one error state to another.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `include_error_changes` - Whether error → different error changes are reported
+    /// * `callback` - Function called with every reported transition
+    pub async fn monitor_error_transitions_with<F>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        include_error_changes: bool,
+        mut callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(ErrorTransition) + Send,
+    {
+        self.monitor_printer_changes(printer_name, interval_ms, move |changes| {
+            for change in &changes.changes {
+                let PropertyChange::ErrorState { old, new } = change else {
+                    continue;
+                };
+                if old.is_error() == new.is_error() && !include_error_changes {
+                    continue;
+                }
+                callback(ErrorTransition {
+                    became_error: new.is_error(),
+                    from: old.clone(),
+                    to: new.clone(),
+                    at: changes.timestamp,
+                });
+            }
+        })
+        .await
+    }
+
+    /// Monitors a printer and resumes its queue once an error that paused it clears.
+    ///
+    /// Meant for unattended printers (e.g. kiosks): when a poll finds the queue
+    /// held by an error (see [`PauseReason::ErrorHold`]) and a later poll finds it
+    /// still paused but with the error gone, the queue is resumed with
+    /// [`Self::resume_printer`]. Queues paused on purpose, or for an unknown reason,
+    /// are left alone. Every attempt is reported to `on_action`.
+    ///
+    /// To avoid fighting a persistent error, at most 3 resumes are attempted until
+    /// the printer keeps running for two consecutive polls; after that a single
+    /// [`AutoResumeEvent::GaveUp`] is reported and the queue is left paused.
+    ///
+    /// **This performs write operations** and needs the rights
+    /// [`Self::resume_printer`] needs.
+    ///
+    /// # Arguments
+    /// * `printer_name` - The name of the printer to watch
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `on_action` - Function called with every automatic resume attempt
+    ///
+    /// # Returns
+    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    ///
+    /// # Errors
+    /// Same as [`Self::monitor_printer`]. Failed resumes are reported to
+    /// `on_action` rather than returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::{AutoResumeEvent, PrinterMonitor};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     monitor.monitor_with_auto_resume("Kiosk Printer", 10000, |event| match event {
+    ///         AutoResumeEvent::GaveUp { printer, .. } => eprintln!("{} needs attention", printer),
+    ///         other => println!("{:?}", other),
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_with_auto_resume<F>(
+        &self,
+        printer_name: &str,
+        interval_ms: u64,
+        mut on_action: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&AutoResumeEvent) + Send,
+    {
+        validate_interval(interval_ms)?;
+        info!("Starting auto-resume monitoring for: {}", printer_name);
+
+        let mut name = printer_name.to_string();
+        let mut previous_printer: Option<Printer> = None;
+        // Whether the queue was seen held by an error since it last ran
+        let mut error_held = false;
+        let mut attempts = 0;
+        let mut gave_up = false;
+        let mut running_polls = 0;
+        let mut failures = 0;
+        let watchdog = self.start_watchdog(Duration::from_millis(interval_ms));
+
+        loop {
+            let result = self
+                .find_tracked_printer(&mut name, previous_printer.as_ref())
+                .await;
+            if let Some(watchdog) = &watchdog {
+                watchdog.poll_completed();
+            }
+            match result {
+                Ok(Some(current_printer)) => {
+                    failures = 0;
+                    if !current_printer.is_paused() {
+                        error_held = false;
+                        running_polls += 1;
+                        if running_polls >= 2 {
+                            attempts = 0;
+                            gave_up = false;
+                        }
+                    } else {
+                        running_polls = 0;
+                        match current_printer.pause_reason() {
+                            Some(PauseReason::ErrorHold) => error_held = true,
+                            Some(PauseReason::Administrative) if error_held => {
+                                if attempts < MAX_AUTO_RESUME_ATTEMPTS {
+                                    attempts += 1;
+                                    let event = match self.resume_printer(&name).await {
+                                        Ok(()) => {
+                                            info!("Printer '{}' resumed automatically", name);
+                                            error_held = false;
+                                            AutoResumeEvent::Resumed {
+                                                printer: name.clone(),
+                                                attempt: attempts,
+                                            }
+                                        }
+                                        Err(e) => {
+                                            warn!("Could not resume printer '{}': {}", name, e);
+                                            AutoResumeEvent::Failed {
+                                                printer: name.clone(),
+                                                attempt: attempts,
+                                                error: e.to_string(),
+                                            }
+                                        }
+                                    };
+                                    on_action(&event);
+                                } else if !gave_up {
+                                    warn!(
+                                        "Printer '{}' keeps pausing, not resuming it again",
+                                        name
+                                    );
+                                    gave_up = true;
+                                    on_action(&AutoResumeEvent::GaveUp {
+                                        printer: name.clone(),
+                                        attempts,
+                                    });
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    previous_printer = Some(current_printer);
+                }
+                Ok(None) => warn!("Printer '{}' not found", name),
+                Err(e) => {
+                    error!("Failed to check printer status: {}", e);
+                    self.recover(e, &mut failures).await?;
+                }
+            }
+
+            sleep(self.poll_delay(Duration::from_millis(interval_ms))).await;
+        }
+    }
+
+    /// Monitors multiple printers concurrently and reports changes for any of them.
+    ///
+    /// This method allows monitoring several printers simultaneously, with a single
+    /// callback that receives changes from any of the monitored printers.
+    /// Each printer is polled by its own task, which shares this monitor's backend
+    /// and settings. Each printer reports its initial state on its own; use
+    /// [`Self::monitor_multiple_printers_with_snapshot`] to receive one consolidated
+    /// initial snapshot instead.
+    ///
+    /// # Arguments
+    /// * `printer_names` - List of printer names to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `callback` - Function called when any printer changes
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let printers = vec!["HP LaserJet".to_string(), "Canon Printer".to_string()];
+    ///     
+    ///     monitor.monitor_multiple_printers(printers, 30000, |changes| {
+    ///         println!("Printer '{}' changed: {}", changes.printer_name, changes.summary());
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_multiple_printers<F>(
+        &self,
+        printer_names: Vec<String>,
+        interval_ms: u64,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: Fn(&PrinterChanges) + Send + Sync + 'static,
+    {
+        use tokio::task::JoinHandle;
+
+        validate_interval(interval_ms)?;
+        info!(
+            "Starting concurrent monitoring of {} printers",
+            printer_names.len()
+        );
+
+        let callback = Arc::new(callback);
+        let mut tasks: Vec<JoinHandle<Result<()>>> = Vec::new();
+
+        for (index, printer_name) in printer_names.into_iter().enumerate() {
+            let callback_clone = callback.clone();
+            // Each printer gets its own jitter sequence so their polls drift apart
+            let poll_seed = splitmix64(*self.poll_rng.lock().unwrap() ^ index as u64);
+            let monitor = self.fork(poll_seed);
+
+            let task = tokio::spawn(async move {
+                monitor
+                    .monitor_printer_changes(&printer_name, interval_ms, move |changes| {
+                        callback_clone(changes);
+                    })
+                    .await
+            });
+
+            tasks.push(task);
+        }
+
+        // Wait for all monitoring tasks (this will run indefinitely unless one fails)
+        for task in tasks {
+            match task.await {
                 Ok(Ok(())) => {
                     info!("Monitoring task completed successfully");
                 }
@@ -567,55 +2927,2398 @@ impl PrinterMonitor {
                     return Err(e);
                 }
                 Err(e) => {
-                    error!("Monitoring task panicked: {}", e);
-                    return Err(crate::PrinterError::Other(format!("Task panicked: {}", e)));
+                    error!("Monitoring task panicked: {}", e);
+                    return Err(PrinterError::Other(format!("Task panicked: {}", e)));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Monitors multiple printers with a two-phase startup: one consolidated
+    /// initial snapshot, then per-printer changes.
+    ///
+    /// Unlike [`Self::monitor_multiple_printers`], where every printer reports its
+    /// initial state on its own, `on_initial` is called exactly once with every
+    /// printer that was found in the first round, in the order of
+    /// `printer_names`, so a UI can render a complete table before streaming
+    /// updates. After that, `on_change` receives the changes of each printer as in
+    /// [`Self::monitor_printer_changes`], including a printer going missing.
+    /// Printers are polled one after another through this monitor's backend.
+    ///
+    /// # Arguments
+    /// * `printer_names` - List of printer names to monitor
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `on_initial` - Function called once with the initial state of all found printers
+    /// * `on_change` - Function called when any printer changes
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///     let printers = vec!["HP LaserJet".to_string(), "Canon Printer".to_string()];
+    ///
+    ///     monitor
+    ///         .monitor_multiple_printers_with_snapshot(
+    ///             printers,
+    ///             30000,
+    ///             |initial| {
+    ///                 for printer in initial {
+    ///                     println!("{}: {}", printer.name(), printer.status_description());
+    ///                 }
+    ///             },
+    ///             |changes| println!("{}: {}", changes.printer_name, changes.summary()),
+    ///         )
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_multiple_printers_with_snapshot<I, F>(
+        &self,
+        printer_names: Vec<String>,
+        interval_ms: u64,
+        on_initial: I,
+        mut on_change: F,
+    ) -> Result<()>
+    where
+        I: FnOnce(&[Printer]) + Send,
+        F: FnMut(&PrinterChanges) + Send,
+    {
+        validate_interval(interval_ms)?;
+        info!(
+            "Starting two-phase monitoring of {} printers",
+            printer_names.len()
+        );
+
+        let mut names = printer_names;
+        let mut previous: Vec<Option<Printer>> = vec![None; names.len()];
+        let mut on_initial = Some(on_initial);
+        let mut failures = 0;
+        let watchdog = self.start_watchdog(Duration::from_millis(interval_ms));
+
+        loop {
+            let mut round_failed = false;
+            for (name, previous) in names.iter_mut().zip(previous.iter_mut()) {
+                let current = match self.find_tracked_printer(name, previous.as_ref()).await {
+                    Ok(current) => current,
+                    Err(e) => {
+                        error!("Failed to check printer status: {}", e);
+                        self.recover(e, &mut failures).await?;
+                        round_failed = true;
+                        break;
+                    }
+                };
+
+                if on_initial.is_some() {
+                    if current.is_none() {
+                        warn!("Printer '{}' not found", name);
+                    }
+                    *previous = current;
+                    continue;
+                }
+
+                match (previous.take(), current) {
+                    (Some(prev), Some(current)) => {
+                        let changes = prev.compare_with_clock(&current, self.clock.as_ref());
+                        if changes.has_changes() {
+                            on_change(&changes);
+                        }
+                        *previous = Some(current);
+                    }
+                    (Some(prev), None) => {
+                        warn!("Printer '{}' not found", name);
+                        let mut changes =
+                            PrinterChanges::new_with_clock(name.clone(), self.clock.as_ref());
+                        changes.changes.push(PropertyChange::IsOffline {
+                            old: prev.is_offline(),
+                            new: true,
+                        });
+                        on_change(&changes);
+                    }
+                    (None, Some(current)) => {
+                        // Reappeared or found late; report it like an initial state
+                        on_change(&PrinterChanges::new_with_clock(
+                            current.name().to_string(),
+                            self.clock.as_ref(),
+                        ));
+                        *previous = Some(current);
+                    }
+                    (None, None) => {}
+                }
+            }
+
+            if let Some(watchdog) = &watchdog {
+                watchdog.poll_completed();
+            }
+            if !round_failed {
+                failures = 0;
+                if let Some(on_initial) = on_initial.take() {
+                    let initial: Vec<Printer> = previous.iter().flatten().cloned().collect();
+                    info!("Initial snapshot of {} printers captured", initial.len());
+                    on_initial(&initial);
+                }
+            }
+
+            sleep(self.poll_delay(Duration::from_millis(interval_ms))).await;
+        }
+    }
+
+    /// Monitors the whole printer set, reporting every added, removed or changed printer.
+    ///
+    /// On the first poll every printer is reported as [`SystemChange::PrinterAdded`];
+    /// afterwards each poll is diffed against the previous one.
+    ///
+    /// # Arguments
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `callback` - Function called once for each change
+    ///
+    /// # Returns
+    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::{PrinterMonitor, SystemChange};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     monitor.monitor_system(30000, |change| {
+    ///         if let SystemChange::PrinterRemoved(printer) = change {
+    ///             println!("Printer '{}' was removed", printer.name());
+    ///         }
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_system<F>(&self, interval_ms: u64, mut callback: F) -> Result<()>
+    where
+        F: FnMut(&SystemChange) + Send,
+    {
+        self.monitor_system_batched(interval_ms, move |changes, _| {
+            for change in &changes {
+                callback(change);
+            }
+        })
+        .await
+    }
+
+    /// Monitors the whole printer set, invoking the callback exactly once per poll.
+    ///
+    /// Unlike [`Self::monitor_system`], the callback receives all changes found in a
+    /// poll together with the poll timestamp, so a reconciliation loop can process a
+    /// consistent snapshot of the fleet at once. The vector is empty when nothing
+    /// changed.
+    ///
+    /// # Arguments
+    /// * `interval_ms` - Polling interval in milliseconds; must be greater than 0
+    /// * `callback` - Function called after every poll with the changes and poll time
+    ///
+    /// # Returns
+    /// * `Result<()>` - Never returns Ok normally (runs indefinitely), only Err on failure
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use printer_event_handler::PrinterMonitor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let monitor = PrinterMonitor::new().await.unwrap();
+    ///
+    ///     monitor.monitor_system_batched(30000, |changes, at| {
+    ///         println!("[{}] {} printer(s) changed", at, changes.len());
+    ///     }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn monitor_system_batched<F>(&self, interval_ms: u64, mut callback: F) -> Result<()>
+    where
+        F: FnMut(Vec<SystemChange>, DateTime<Utc>) + Send,
+    {
+        validate_interval(interval_ms)?;
+        info!("Starting system-wide printer monitoring");
+
+        let mut previous: Vec<Printer> = Vec::new();
+        let mut history = match &self.history_store {
+            Some(store) => store.load().await?,
+            None => HashMap::new(),
+        };
+
+        let mut failures = 0;
+        let watchdog = self.start_watchdog(Duration::from_millis(interval_ms));
+
+        loop {
+            let result = self.list_printers().await;
+            if let Some(watchdog) = &watchdog {
+                watchdog.poll_completed();
+            }
+            match result {
+                Ok(current) => {
+                    failures = 0;
+                    let now = self.clock.now();
+                    if let Some(store) = &self.history_store {
+                        for printer in &current {
+                            history
+                                .entry(printer.name().to_string())
+                                .or_insert_with(PrinterHistory::default)
+                                .record(printer, now);
+                        }
+                        if let Err(e) = store.save(&history).await {
+                            warn!("Failed to save printer history: {}", e);
+                        }
+                    }
+
+                    let changes = diff_printer_sets(&previous, &current, self.clock.as_ref());
+                    if !changes.is_empty() {
+                        info!("{} printer(s) changed", changes.len());
+                    }
+                    callback(changes, now);
+                    previous = current;
+                }
+                Err(e) => {
+                    error!("Failed to list printers: {}", e);
+                    self.recover(e, &mut failures).await?;
+                }
+            }
+
+            sleep(self.poll_delay(Duration::from_millis(interval_ms))).await;
+        }
+    }
+}
+
+/// A change to the set of printers on the system
+// Changes are produced a handful at a time per poll, so boxing the larger variant buys nothing
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum SystemChange {
+    /// A printer appeared
+    PrinterAdded(Printer),
+    /// A printer disappeared; carries its last known state
+    PrinterRemoved(Printer),
+    /// An existing printer's properties changed
+    PrinterChanged {
+        /// State before the change
+        previous: Printer,
+        /// State after the change
+        current: Printer,
+        /// The individual property changes
+        changes: PrinterChanges,
+    },
+}
+
+impl SystemChange {
+    /// Returns the name of the printer this change applies to
+    pub fn printer_name(&self) -> &str {
+        match self {
+            SystemChange::PrinterAdded(printer) | SystemChange::PrinterRemoved(printer) => {
+                printer.name()
+            }
+            SystemChange::PrinterChanged { current, .. } => current.name(),
+        }
+    }
+}
+
+/// Folds a printer name for cross-platform matching: underscores become spaces, case is ignored
+fn normalize_name(name: &str) -> String {
+    name.trim().replace('_', " ").to_lowercase()
+}
+
+/// Rejects a zero polling interval, which would turn a monitoring loop into a busy-loop
+fn validate_interval(interval_ms: u64) -> Result<()> {
+    if interval_ms == 0 {
+        return Err(PrinterError::InvalidConfig(
+            "interval must be > 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Diffs two printer sets by name: changed and added printers in `current` order,
+/// followed by removed printers in `previous` order
+pub(crate) fn diff_printer_sets(
+    previous: &[Printer],
+    current: &[Printer],
+    clock: &dyn Clock,
+) -> Vec<SystemChange> {
+    let mut changes = Vec::new();
+
+    // Index both sides by name; a repeated name keeps its first printer
+    let mut previous_by_name: HashMap<&str, &Printer> = HashMap::with_capacity(previous.len());
+    for printer in previous {
+        previous_by_name.entry(printer.name()).or_insert(printer);
+    }
+    let current_names: HashSet<&str> = current.iter().map(Printer::name).collect();
+
+    for printer in current {
+        match previous_by_name.get(printer.name()).copied() {
+            Some(before) if before != printer => {
+                changes.push(SystemChange::PrinterChanged {
+                    previous: before.clone(),
+                    current: printer.clone(),
+                    changes: before.compare_with_clock(printer, clock),
+                });
+            }
+            Some(_) => {}
+            None => changes.push(SystemChange::PrinterAdded(printer.clone())),
+        }
+    }
+
+    for printer in previous {
+        if !current_names.contains(printer.name()) {
+            changes.push(SystemChange::PrinterRemoved(printer.clone()));
+        }
+    }
+
+    changes
+}
+
+/// A change of a printer's error state, as reported by
+/// [`PrinterMonitor::monitor_error_transitions`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorTransition {
+    /// Whether the printer is in an error state after the transition
+    pub became_error: bool,
+    /// The error state before the transition
+    pub from: ErrorState,
+    /// The error state after the transition
+    pub to: ErrorState,
+    /// When the transition was detected
+    pub at: DateTime<Utc>,
+}
+
+/// A printer that needs attention, as reported by [`PrinterMonitor::problems`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrinterProblem {
+    /// Name of the printer
+    pub name: String,
+    /// The most urgent severity among the reasons
+    pub severity: Severity,
+    /// Human-readable descriptions of each issue
+    pub reasons: Vec<String>,
+}
+
+impl PrinterProblem {
+    /// Collects the issues of a printer; `None` when it has none
+    fn of(printer: &Printer) -> Option<Self> {
+        let mut reasons = Vec::new();
+        let mut severity = Severity::Info;
+
+        if printer.is_offline() {
+            reasons.push("Offline".to_string());
+            severity = Severity::Error;
+        }
+
+        // Conditions can be signalled by raw codes even when ErrorState says otherwise
+        let conditions = [
+            (printer.is_door_open(), ErrorState::DoorOpen),
+            (printer.is_jammed(), ErrorState::Jammed),
+            (printer.is_out_of_paper(), ErrorState::NoPaper),
+            (printer.is_low_toner(), ErrorState::LowToner),
+        ];
+        let mut found: Vec<ErrorState> = conditions
+            .into_iter()
+            .filter_map(|(present, condition)| present.then_some(condition))
+            .collect();
+
+        let error_state = printer.error_state();
+        // Offline CUPS queues report a generic error state that adds nothing
+        let generic_offline = printer.is_offline() && *error_state == ErrorState::Other;
+        if error_state.is_error() && !generic_offline && !found.contains(error_state) {
+            found.push(error_state.clone());
+        }
+
+        for condition in found {
+            reasons.push(format!(
+                "{}: {}",
+                condition.description(),
+                condition.recommended_action()
+            ));
+            severity = severity.max(condition.severity());
+        }
+
+        // No ErrorState carries this condition, so it gets its own reason
+        if printer.is_out_of_memory() {
+            reasons.push("Printer out of memory; reduce job complexity or add RAM.".to_string());
+            severity = severity.max(Severity::Error);
+        }
+
+        // Contradicting signals point at a misreporting driver
+        if printer.has_status_conflict() {
+            reasons.push(format!(
+                "Conflicting Status: {} with no error, but WMI Status is '{}'; check the printer driver",
+                printer.status_description(),
+                printer.wmi_status().unwrap_or_default()
+            ));
+            severity = severity.max(Severity::Warning);
+        }
+
+        // Not an error state, but jobs stay stuck until someone loads paper
+        if printer.needs_manual_feed() {
+            reasons.push("Manual Feed Required: Load paper into the manual feed slot".to_string());
+            severity = severity.max(Severity::Warning);
+        }
+
+        (!reasons.is_empty()).then(|| PrinterProblem {
+            name: printer.name().to_string(),
+            severity,
+            reasons,
+        })
+    }
+}
+
+/// Matches `text` against a `*`/`?` glob pattern, ignoring case
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text index it is currently matched up to
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` swallow one more character and retry
+                Some((star, matched)) => {
+                    backtrack = Some((star, matched + 1));
+                    p = star + 1;
+                    t = matched + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Printers grouped by availability, as returned by
+/// [`PrinterMonitor::list_partitioned`]
+#[derive(Debug, Clone, Default)]
+pub struct PrinterPartition {
+    /// Printers that are currently reachable
+    pub online: Vec<Printer>,
+    /// Printers that are currently offline
+    pub offline: Vec<Printer>,
+}
+
+/// Summary information about a printer's current state.
+///
+/// This struct provides a snapshot of a printer's essential status information
+/// in a convenient format for reporting and monitoring applications.
+#[derive(Debug, Clone)]
+pub struct PrinterSummary {
+    /// Current operational status of the printer
+    pub status: crate::PrinterStatus,
+    /// Current error state of the printer
+    pub error_state: crate::ErrorState,
+    /// Whether the printer is currently offline
+    pub is_offline: bool,
+    /// Whether this is the system's default printer
+    pub is_default: bool,
+    /// Whether the printer currently has any error conditions
+    pub has_error: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::mock::MockBackend;
+    use crate::clock::FixedClock;
+    use crate::{ErrorState, PrinterState, PrinterStatus};
+    use chrono::TimeZone;
+    use std::sync::Mutex;
+
+    fn named_printer(name: &str) -> Printer {
+        Printer::new(
+            name.to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        )
+    }
+
+    fn test_printer(status: PrinterStatus) -> Printer {
+        Printer::new(
+            "Test Printer".to_string(),
+            status,
+            ErrorState::NoError,
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn test_printers_stream_yields_every_printer() {
+        use futures_util::StreamExt;
+
+        let printers = vec![
+            named_printer("Office"),
+            named_printer("Lab"),
+            named_printer("Reception"),
+        ];
+        let backend = MockBackend::with_snapshots(vec![printers.clone()]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let streamed: Vec<Printer> = monitor
+            .printers_stream()
+            .map(|printer| printer.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(streamed.len(), printers.len());
+        assert_eq!(streamed, printers);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test(start_paused = true)]
+    async fn test_list_printers_budget_returns_partial_results() {
+        let fleet: Vec<Printer> = ["Office", "Lab", "Reception", "Warehouse"]
+            .into_iter()
+            .map(named_printer)
+            .collect();
+        let backend = MockBackend::with_snapshots(vec![fleet.clone(), fleet.clone()])
+            .with_entry_delay(Duration::from_millis(100));
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let (printers, complete) = monitor
+            .list_printers_budget(Duration::from_millis(250))
+            .await
+            .unwrap();
+        assert!(!complete);
+        assert_eq!(printers, fleet[..2]);
+
+        let (printers, complete) = monitor
+            .list_printers_budget(Duration::from_secs(1))
+            .await
+            .unwrap();
+        assert!(complete);
+        assert_eq!(printers, fleet);
+
+        // Backend errors are reported rather than passed off as an empty listing
+        assert!(
+            monitor
+                .list_printers_budget(Duration::from_secs(1))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(windows)]
+    async fn test_monitor_creation() {
+        let result = PrinterMonitor::new().await;
+        // This might fail in CI/test environments without proper WMI access
+        // but it should at least compile and attempt the connection
+        match result {
+            Ok(_) => println!("Monitor created successfully"),
+            Err(e) => println!("Expected error in test environment: {}", e),
+        }
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_monitor_unix_creation() {
+        let result = PrinterMonitor::new().await;
+        // On Unix/Linux, the monitor should be created successfully
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_monitor_changes_use_injected_clock() {
+        let at = chrono::Utc.with_ymd_and_hms(2025, 6, 1, 8, 30, 0).unwrap();
+        let backend = MockBackend::with_snapshots(vec![
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![test_printer(PrinterStatus::Printing)],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_clock(Arc::new(FixedClock::new(at)));
+
+        let emitted = Mutex::new(Vec::new());
+        let result = monitor
+            .monitor_printer_changes("Test Printer", 1, |changes| {
+                emitted.lock().unwrap().push(changes.clone());
+            })
+            .await;
+
+        // The loop ends once the scripted backend runs dry
+        assert!(result.is_err());
+
+        let emitted = emitted.into_inner().unwrap();
+        assert_eq!(emitted.len(), 2);
+        assert!(!emitted[0].has_changes());
+        assert_eq!(emitted[1].change_count(), 1);
+        assert!(emitted.iter().all(|changes| changes.timestamp == at));
+    }
+
+    #[test]
+    fn test_poll_jitter_stays_in_band() {
+        let interval = Duration::from_millis(10_000);
+        let monitor = |seed| {
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![])))
+                .with_poll_jitter(0.2)
+                .with_poll_jitter_seed(seed)
+        };
+
+        let seeded = monitor(7);
+        let delays: Vec<Duration> = (0..200).map(|_| seeded.poll_delay(interval)).collect();
+        assert!(delays.iter().all(|delay| {
+            *delay >= Duration::from_millis(8_000) && *delay <= Duration::from_millis(12_000)
+        }));
+        // Intervals actually spread out
+        assert!(
+            delays
+                .iter()
+                .any(|delay| *delay < Duration::from_millis(9_500))
+        );
+        assert!(
+            delays
+                .iter()
+                .any(|delay| *delay > Duration::from_millis(10_500))
+        );
+
+        // The same seed gives the same sequence
+        let replay = monitor(7);
+        let replayed: Vec<Duration> = (0..200).map(|_| replay.poll_delay(interval)).collect();
+        assert_eq!(replayed, delays);
+
+        // Without jitter intervals are exact
+        let exact = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![])));
+        assert_eq!(exact.poll_delay(interval), interval);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rename_followed_by_port() {
+        let on_port =
+            |name: &str, port: &str| named_printer(name).with_port(Some(port.to_string()));
+        let script = || {
+            vec![
+                // Initial lookup of "HP"
+                vec![on_port("HP", "IP_10.0.0.5"), on_port("Lab", "USB001")],
+                // "HP" is gone after the rename
+                vec![
+                    on_port("HP Office", "IP_10.0.0.5"),
+                    on_port("Lab", "USB001"),
+                ],
+                // Listing used to match the port
+                vec![
+                    on_port("HP Office", "IP_10.0.0.5"),
+                    on_port("Lab", "USB001"),
+                ],
+                // Monitoring continues under the new name
+                vec![on_port("HP Office", "IP_10.0.0.5")],
+            ]
+        };
+        let run = |monitor: PrinterMonitor| async move {
+            let emitted = Mutex::new(Vec::new());
+            let result = monitor
+                .monitor_printer_changes("HP", 1, |changes| {
+                    emitted.lock().unwrap().push(changes.changes.clone());
+                })
+                .await;
+            assert!(result.is_err());
+            emitted.into_inner().unwrap()
+        };
+
+        let tracking =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(script())))
+                .with_rename_tracking(true);
+        let emitted = run(tracking).await;
+        assert_eq!(
+            emitted,
+            vec![
+                vec![],
+                vec![PropertyChange::Name {
+                    old: "HP".to_string(),
+                    new: "HP Office".to_string(),
+                }],
+            ]
+        );
+
+        // Without tracking the rename looks like the printer disappeared
+        let plain = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(script())));
+        let emitted = run(plain).await;
+        assert_eq!(
+            emitted[1],
+            vec![PropertyChange::IsOffline {
+                old: false,
+                new: true,
+            }]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rename_not_followed_on_shared_port() {
+        let on_port = |name: &str| named_printer(name).with_port(Some("nul:".to_string()));
+        let backend = MockBackend::with_snapshots(vec![
+            vec![on_port("Fax")],
+            vec![on_port("PDF"), on_port("XPS")],
+            vec![on_port("PDF"), on_port("XPS")],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend)).with_rename_tracking(true);
+
+        let seen = Mutex::new(Vec::new());
+        let result = monitor
+            .monitor_printer("Fax", 1, |current, _| {
+                seen.lock().unwrap().push(current.name().to_string());
+            })
+            .await;
+        assert!(result.is_err());
+        // Ambiguous port: reported as missing under the old name
+        assert_eq!(seen.into_inner().unwrap(), ["Fax", "Fax"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_query_timings_record_backend_latency() {
+        let fleet = || vec![named_printer("HP")];
+        let backend = MockBackend::with_snapshots(vec![fleet(), fleet(), fleet()])
+            .with_query_delays(vec![
+                Duration::from_millis(100),
+                Duration::from_millis(300),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+            ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+        assert_eq!(monitor.query_timings(), None);
+
+        monitor.list_printers().await.unwrap();
+        monitor.find_printer("HP").await.unwrap();
+        monitor.list_printers().await.unwrap();
+        // Failed queries are timed too
+        assert!(monitor.list_printers().await.is_err());
+
+        let timings = monitor.query_timings().unwrap();
+        assert_eq!(timings.samples, 4);
+        assert_eq!(timings.min, Duration::from_millis(100));
+        assert_eq!(timings.max, Duration::from_millis(400));
+        assert_eq!(timings.average, Duration::from_millis(250));
+        assert_eq!(timings.last, Duration::from_millis(400));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watchdog_reports_hung_backend() {
+        // Answers the first poll, then never responds again
+        let backend =
+            MockBackend::with_snapshots(vec![vec![named_printer("HP")]]).hanging_when_exhausted();
+        let stalls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&stalls);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_watchdog(3, move |stalled| recorded.lock().unwrap().push(stalled));
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(20),
+            monitor.monitor_printer_changes("HP", 1000, |_| {}),
+        )
+        .await;
+        assert!(result.is_err(), "the hung loop never returns");
+        // Reported once, three intervals after the last completed poll
+        assert_eq!(*stalls.lock().unwrap(), [Duration::from_secs(3)]);
+
+        // The watchdog stops with the monitoring loop
+        sleep(Duration::from_secs(60)).await;
+        assert_eq!(stalls.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watchdog_covers_snapshot_monitoring() {
+        let backend =
+            MockBackend::with_snapshots(vec![vec![named_printer("HP")]]).hanging_when_exhausted();
+        let stalls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&stalls);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_watchdog(3, move |stalled| recorded.lock().unwrap().push(stalled));
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(20),
+            monitor.monitor_multiple_printers_with_snapshot(
+                vec!["HP".to_string()],
+                1000,
+                |_| {},
+                |_| {},
+            ),
+        )
+        .await;
+        assert!(result.is_err(), "the hung loop never returns");
+        assert_eq!(*stalls.lock().unwrap(), [Duration::from_secs(3)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watchdog_quiet_while_polls_complete() {
+        let fleet = || vec![named_printer("HP")];
+        let backend = MockBackend::with_snapshots(vec![fleet(), fleet(), fleet(), fleet()]);
+        let stalls = Arc::new(Mutex::new(0));
+        let recorded = Arc::clone(&stalls);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_watchdog(2, move |_| *recorded.lock().unwrap() += 1);
+
+        // Fails once the script is exhausted, after polls kept completing
+        let result = monitor.monitor_printer_changes("HP", 1000, |_| {}).await;
+        assert!(result.is_err());
+        assert_eq!(*stalls.lock().unwrap(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_multiple_printers_single_initial_snapshot() {
+        let printer = |name: &str, status| {
+            Printer::new(name.to_string(), status, ErrorState::NoError, false, false)
+        };
+        // One scripted listing per lookup: "Office" then "Lab" each round
+        let backend = MockBackend::with_snapshots(vec![
+            vec![printer("Office", PrinterStatus::Idle)],
+            vec![printer("Lab", PrinterStatus::Idle)],
+            vec![printer("Office", PrinterStatus::Printing)],
+            vec![printer("Lab", PrinterStatus::Idle)],
+            vec![printer("Office", PrinterStatus::Printing)],
+            vec![],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let initial = Mutex::new(Vec::new());
+        let changed = Mutex::new(Vec::new());
+        let result = monitor
+            .monitor_multiple_printers_with_snapshot(
+                vec!["Office".to_string(), "Lab".to_string()],
+                1,
+                |printers| {
+                    let names: Vec<String> =
+                        printers.iter().map(|p| p.name().to_string()).collect();
+                    initial.lock().unwrap().push(names);
+                },
+                |changes| {
+                    changed.lock().unwrap().push((
+                        changes.printer_name.clone(),
+                        changes
+                            .changes
+                            .iter()
+                            .map(|c| c.property_name())
+                            .collect::<Vec<_>>(),
+                    ));
+                },
+            )
+            .await;
+        assert!(result.is_err());
+
+        assert_eq!(
+            initial.into_inner().unwrap(),
+            vec![vec!["Office".to_string(), "Lab".to_string()]]
+        );
+        assert_eq!(
+            changed.into_inner().unwrap(),
+            vec![
+                ("Office".to_string(), vec!["Status"]),
+                ("Lab".to_string(), vec!["IsOffline"]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_printer_or_class_resolves_members() {
+        let fleet = || {
+            vec![
+                named_printer("HP_LaserJet"),
+                named_printer("Canon_Lobby"),
+                named_printer("Brother"),
+            ]
+        };
+        let backend =
+            MockBackend::with_snapshots(vec![fleet(), fleet()]).with_classes(vec![PrinterClass {
+                name: "office".to_string(),
+                members: vec![
+                    "Canon_Lobby".to_string(),
+                    "Retired".to_string(),
+                    "HP_LaserJet".to_string(),
+                ],
+            }]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let members = monitor.find_printer_or_class("Office").await.unwrap();
+        let names: Vec<&str> = members.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["Canon_Lobby", "HP_LaserJet"]);
+
+        // Plain printer names still resolve to the printer itself
+        let single = monitor.find_printer_or_class("Brother").await.unwrap();
+        assert_eq!(single.len(), 1);
+        assert_eq!(single[0].name(), "Brother");
+    }
+
+    #[tokio::test]
+    async fn test_tags_assigned_and_filtered_case_insensitively() {
+        let fleet = vec![
+            named_printer("HP_Floor3"),
+            named_printer("Canon_Lobby"),
+            named_printer("Brother"),
+        ];
+        let backend = MockBackend::with_snapshots(vec![fleet.clone(), fleet.clone(), fleet]);
+        let tags = HashMap::from([
+            (
+                "hp_floor3".to_string(),
+                vec!["Floor-3".to_string(), "Finance".to_string()],
+            ),
+            ("CANON_LOBBY".to_string(), vec!["floor-3".to_string()]),
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend)).with_tags(tags);
+
+        let printers = monitor.list_printers().await.unwrap();
+        assert_eq!(printers[0].tags(), ["Floor-3", "Finance"]);
+        assert_eq!(printers[1].tags(), ["floor-3"]);
+        assert!(printers[2].tags().is_empty());
+
+        let floor3 = monitor.list_printers_with_tag("FLOOR-3").await.unwrap();
+        let names: Vec<&str> = floor3.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["HP_Floor3", "Canon_Lobby"]);
+
+        let found = monitor.find_printer("hp_floor3").await.unwrap().unwrap();
+        assert!(found.has_tag("finance"));
+    }
+
+    #[tokio::test]
+    async fn test_names_and_tags_match_beyond_ascii() {
+        let backend = MockBackend::with_snapshots(vec![vec![named_printer("Drucker_Büro")]]);
+        let tags = HashMap::from([("DRUCKER_BÜRO".to_string(), vec!["Büro".to_string()])]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend)).with_tags(tags);
+
+        // Lookups, tag assignment and tag checks all fold case the same way
+        let found = monitor.find_printer("drucker_büro").await.unwrap().unwrap();
+        assert_eq!(found.tags(), ["Büro"]);
+        assert!(found.has_tag("BÜRO"));
+    }
+
+    #[tokio::test]
+    async fn test_list_printers_lenient_keeps_valid_entries() {
+        let fleet = vec![
+            test_printer(PrinterStatus::Idle),
+            Printer::new(
+                "Second Printer".to_string(),
+                PrinterStatus::Printing,
+                ErrorState::NoError,
+                false,
+                false,
+            ),
+        ];
+        let backend = MockBackend::with_snapshots(vec![fleet.clone(), fleet])
+            .with_malformed(vec!["Broken Queue".to_string()]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        // The strict listing fails entirely on the malformed entry
+        assert!(monitor.list_printers().await.is_err());
+
+        let (printers, errors) = monitor.list_printers_lenient().await.unwrap();
+        assert_eq!(printers.len(), 2);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("Broken Queue"));
+    }
+
+    #[tokio::test]
+    async fn test_list_non_redirected_printers() {
+        let fleet = vec![
+            named_printer("HP LaserJet"),
+            named_printer("HP LaserJet (redirected 4)"),
+            named_printer("Session Printer").with_attributes(Some(0x8000)),
+        ];
+        let backend = MockBackend::with_snapshots(vec![fleet]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let printers = monitor.list_non_redirected_printers().await.unwrap();
+        let names: Vec<&str> = printers.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["HP LaserJet"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_physical_printers_with_extra_virtual_names() {
+        let fleet = vec![
+            named_printer("HP LaserJet"),
+            named_printer("Microsoft Print to PDF"),
+            named_printer("Microsoft XPS Document Writer"),
+            named_printer("Fax"),
+        ];
+        let backend = MockBackend::with_snapshots(vec![fleet]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_virtual_printer_names(vec!["fax".to_string()]);
+
+        let printers = monitor.list_physical_printers().await.unwrap();
+        let names: Vec<&str> = printers.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["HP LaserJet"]);
+    }
+
+    #[tokio::test]
+    async fn test_all_healthy_skips_virtual_and_ignored_printers() {
+        let jammed = Printer::new(
+            "Old Plotter".to_string(),
+            PrinterStatus::Other,
+            ErrorState::Jammed,
+            false,
+            false,
+        );
+        let fleet = vec![
+            named_printer("HP LaserJet"),
+            offline_printer("Microsoft Print to PDF"),
+            jammed,
+        ];
+
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            fleet.clone(),
+        ])));
+        assert!(!monitor.all_healthy().await.unwrap());
+
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![fleet])))
+                .with_health_ignore_list(vec!["old plotter".to_string()]);
+        assert!(monitor.all_healthy().await.unwrap());
+
+        // A physical printer going offline turns the light red again
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            vec![named_printer("HP LaserJet"), offline_printer("Lab")],
+        ])))
+        .with_health_ignore_list(vec!["Old Plotter".to_string()]);
+        assert!(!monitor.all_healthy().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_all_combines_printers_and_page_counts() {
+        let backend = MockBackend::with_snapshots(vec![vec![
+            named_printer("Office"),
+            named_printer("Lab"),
+            named_printer("Reception"),
+        ]])
+        .with_page_counts(HashMap::from([
+            ("Office".to_string(), 1200),
+            ("Lab".to_string(), 35),
+            ("Reception".to_string(), 7),
+        ]));
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_tags(HashMap::from([(
+                "office".to_string(),
+                vec!["finance".to_string()],
+            )]))
+            .with_max_printers(2);
+
+        // The single scripted listing is enough for the whole snapshot
+        let snapshot = monitor.snapshot_all().await.unwrap();
+        let names: Vec<&str> = snapshot.printers.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["Office", "Lab"]);
+        assert!(snapshot.printers[0].has_tag("finance"));
+        assert_eq!(snapshot.page_count("Office"), Some(1200));
+        assert_eq!(snapshot.page_count("Lab"), Some(35));
+        // Dropped by the cap along with its printer
+        assert_eq!(snapshot.page_count("Reception"), None);
+        assert_eq!(snapshot.page_counts.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_export_json_wraps_printer_listing() {
+        let fleet = || vec![named_printer("Office"), offline_printer("Lab")];
+        let backend = MockBackend::with_snapshots(vec![fleet(), fleet()]);
+        let generated_at = Utc.with_ymd_and_hms(2025, 3, 1, 9, 30, 0).unwrap();
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_clock(Arc::new(FixedClock::new(generated_at)));
+
+        let document: serde_json::Value =
+            serde_json::from_str(&monitor.export_json().await.unwrap()).unwrap();
+        let object = document.as_object().unwrap();
+        assert_eq!(object.len(), 2);
+        assert_eq!(document["generated_at"], "2025-03-01T09:30:00Z");
+
+        let listed = monitor.list_printers().await.unwrap();
+        let exported: Vec<Printer> = serde_json::from_value(document["printers"].clone()).unwrap();
+        assert_eq!(exported, listed);
+        assert_eq!(document["printers"], serde_json::to_value(&listed).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_fax_devices_excluded_from_listing() {
+        let fleet = || {
+            vec![
+                named_printer("HP LaserJet"),
+                named_printer("Front Desk")
+                    .with_driver(Some("Microsoft Shared Fax Driver".to_string())),
+                named_printer("Lab"),
+            ]
+        };
+        let backend = MockBackend::with_snapshots(vec![fleet(), fleet()]);
+        let monitor = PrinterMonitor::builder()
+            .backend(Box::new(backend))
+            .exclude_fax(true)
+            .build()
+            .await
+            .unwrap();
+
+        let names: Vec<String> = monitor
+            .list_printers()
+            .await
+            .unwrap()
+            .iter()
+            .map(|p| p.name().to_string())
+            .collect();
+        assert_eq!(names, ["HP LaserJet", "Lab"]);
+        // Still reachable by name
+        assert!(monitor.find_printer("Front Desk").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_list_partitioned() {
+        let fleet = vec![
+            named_printer("Office"),
+            offline_printer("Lab"),
+            named_printer("Reception"),
+            offline_printer("Warehouse"),
+        ];
+        let backend = MockBackend::with_snapshots(vec![fleet]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let partition = monitor.list_partitioned().await.unwrap();
+        let online: Vec<&str> = partition.online.iter().map(|p| p.name()).collect();
+        let offline: Vec<&str> = partition.offline.iter().map(|p| p.name()).collect();
+        assert_eq!(online, ["Office", "Reception"]);
+        assert_eq!(offline, ["Lab", "Warehouse"]);
+    }
+
+    #[tokio::test]
+    async fn test_monitor_system_batched_one_callback_per_poll() {
+        let jammed = Printer::new(
+            "Office".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::Jammed,
+            false,
+            false,
+        );
+        let backend = MockBackend::with_snapshots(vec![
+            vec![named_printer("Office"), named_printer("Lab")],
+            vec![named_printer("Office"), named_printer("Lab")],
+            vec![jammed, named_printer("Reception")],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let mut batches: Vec<Vec<String>> = Vec::new();
+        let result = monitor
+            .monitor_system_batched(1, |changes, _| {
+                batches.push(
+                    changes
+                        .iter()
+                        .map(|change| match change {
+                            SystemChange::PrinterAdded(p) => format!("+{}", p.name()),
+                            SystemChange::PrinterRemoved(p) => format!("-{}", p.name()),
+                            SystemChange::PrinterChanged { changes, .. } => {
+                                format!("~{}:{}", changes.printer_name, changes.change_count())
+                            }
+                        })
+                        .collect(),
+                );
+            })
+            .await;
+
+        // The mock runs dry after the third poll
+        assert!(result.is_err());
+        assert_eq!(
+            batches,
+            vec![
+                vec!["+Office", "+Lab"],
+                vec![],
+                vec!["~Office:1", "+Reception", "-Lab"],
+            ]
+        );
+    }
+
+    fn offline_printer(name: &str) -> Printer {
+        Printer::new(
+            name.to_string(),
+            PrinterStatus::Offline,
+            ErrorState::Other,
+            true,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_diff_printer_sets_orders_changes() {
+        let previous = vec![
+            named_printer("Gone"),
+            named_printer("Office"),
+            named_printer("Lab"),
+        ];
+        let current = vec![
+            named_printer("New"),
+            offline_printer("Office"),
+            named_printer("Lab"),
+        ];
+
+        let changes = diff_printer_sets(&previous, &current, &crate::SystemClock);
+        let summary: Vec<(&str, &str)> = changes
+            .iter()
+            .map(|change| {
+                let kind = match change {
+                    SystemChange::PrinterAdded(_) => "added",
+                    SystemChange::PrinterRemoved(_) => "removed",
+                    SystemChange::PrinterChanged { .. } => "changed",
+                };
+                (kind, change.printer_name())
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [("added", "New"), ("changed", "Office"), ("removed", "Gone")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_online_returns_recovered_printer() {
+        let backend = MockBackend::with_snapshots(vec![
+            vec![offline_printer("Office")],
+            vec![],
+            vec![offline_printer("Office")],
+            vec![named_printer("Office")],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let printer = monitor
+            .wait_online("office", 1, Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert!(!printer.is_offline());
+        assert_eq!(printer.name(), "Office");
+    }
+
+    #[tokio::test]
+    async fn test_wait_no_error_times_out() {
+        let backend = MockBackend::with_snapshots(vec![vec![offline_printer("Office")]; 50]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let result = monitor
+            .wait_no_error("Office", 20, Duration::from_millis(50))
+            .await;
+        assert!(matches!(result, Err(PrinterError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_monitor_until_code_reaches_target() {
+        let with_error_code = |code: u32| {
+            Printer::from_raw_codes(
+                "Office".to_string(),
+                PrinterStatus::Idle,
+                None,
+                ErrorState::NoError,
+                false,
+                false,
+                crate::RawStatusCodes {
+                    detected_error_state_code: Some(code),
+                    ..crate::RawStatusCodes::default()
+                },
+            )
+        };
+        let backend = MockBackend::with_snapshots(vec![
+            vec![with_error_code(8)],
+            vec![],
+            vec![with_error_code(8)],
+            vec![with_error_code(2)],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let printer = monitor
+            .monitor_until_code(
+                "Office",
+                MonitorableProperty::DetectedErrorStateCode,
+                2,
+                1,
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        assert_eq!(printer.detected_error_state_code(), Some(2));
+
+        // Never reaching the target times out
+        let backend = MockBackend::with_snapshots(vec![vec![with_error_code(8)]; 50]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+        let result = monitor
+            .monitor_until_code(
+                "Office",
+                MonitorableProperty::DetectedErrorStateCode,
+                2,
+                20,
+                Duration::from_millis(50),
+            )
+            .await;
+        assert!(matches!(result, Err(PrinterError::Timeout(_))));
+
+        // Only raw codes can be watched
+        let result = monitor
+            .monitor_until_code(
+                "Office",
+                MonitorableProperty::Status,
+                2,
+                20,
+                Duration::from_millis(50),
+            )
+            .await;
+        assert!(matches!(result, Err(PrinterError::InvalidConfig(_))));
+    }
+
+    #[tokio::test]
+    async fn test_async_callback_awaited_per_change() {
+        let backend = MockBackend::with_snapshots(vec![
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![test_printer(PrinterStatus::Printing)],
+            vec![test_printer(PrinterStatus::Printing)],
+            vec![test_printer(PrinterStatus::Idle)],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let recorded = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+        let result = monitor
+            .monitor_printer_changes_async("Test Printer", 1, |changes| {
+                let recorded = recorded.clone();
+                async move {
+                    tokio::task::yield_now().await;
+                    recorded.lock().await.push(changes.change_count());
                 }
-            }
+            })
+            .await;
+
+        assert!(result.is_err());
+        // Initial snapshot, then Idle -> Printing and Printing -> Idle
+        assert_eq!(*recorded.lock().await, vec![0, 1, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_list_printer_names_matches_full_list() {
+        let fleet = vec![
+            named_printer("Office"),
+            named_printer("Front Desk")
+                .with_driver(Some("Microsoft Shared Fax Driver".to_string())),
+            named_printer("Lab"),
+        ];
+        let names_and_printers = |monitor: PrinterMonitor| async move {
+            let names = monitor.list_printer_names().await.unwrap();
+            let printers = monitor.list_printers().await.unwrap();
+            let full: Vec<String> = printers.iter().map(|p| p.name().to_string()).collect();
+            (names, full)
+        };
+        let monitor = || {
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+                fleet.clone(),
+                fleet.clone(),
+            ])))
+        };
+
+        let (names, full) = names_and_printers(monitor()).await;
+        assert_eq!(names, full);
+        assert_eq!(names.len(), 3);
+
+        let (names, full) = names_and_printers(monitor().with_max_printers(2)).await;
+        assert_eq!(names, full);
+        assert_eq!(names, ["Office", "Front Desk"]);
+
+        let (names, full) = names_and_printers(monitor().with_fax_excluded(true)).await;
+        assert_eq!(names, full);
+        assert_eq!(names, ["Office", "Lab"]);
+    }
+
+    #[tokio::test]
+    async fn test_windowed_monitor_reports_net_changes() {
+        let backend = MockBackend::with_snapshots(vec![
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![test_printer(PrinterStatus::Printing)],
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![test_printer(PrinterStatus::Offline)],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let mut reported = Vec::new();
+        let result = monitor
+            .monitor_printer_windowed("Test Printer", 1, Duration::from_secs(3600), |changes| {
+                reported.push(changes.changes.clone());
+            })
+            .await;
+
+        // The window never elapses; pending changes are flushed when the mock runs dry
+        assert!(result.is_err());
+        assert_eq!(
+            reported,
+            vec![vec![crate::PropertyChange::Status {
+                old: PrinterStatus::Idle,
+                new: PrinterStatus::Offline,
+            }]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_default_printer_verifies_change() {
+        let mut default_printer = test_printer(PrinterStatus::Idle);
+        default_printer.set_default(true);
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            vec![default_printer],
+        ])));
+        assert!(monitor.set_default_printer("Test Printer").await.is_ok());
+
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            vec![test_printer(PrinterStatus::Idle)],
+        ])));
+        assert!(matches!(
+            monitor.set_default_printer("Test Printer").await,
+            Err(PrinterError::Other(_))
+        ));
+
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![vec![]])));
+        assert!(matches!(
+            monitor.set_default_printer("Test Printer").await,
+            Err(PrinterError::PrinterNotFound(_))
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_auto_resume_after_error_clears() {
+        let paused = |error_state| {
+            Printer::new_with_state(
+                "Test Printer".to_string(),
+                PrinterStatus::StoppedPrinting,
+                Some(PrinterState::Paused),
+                error_state,
+                false,
+                false,
+            )
+        };
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            // Paused on purpose: left alone
+            vec![paused(ErrorState::NoError)],
+            // Held by a jam, then the jam is cleared
+            vec![paused(ErrorState::Jammed)],
+            vec![paused(ErrorState::NoError)],
+            // Read back by resume_printer
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![test_printer(PrinterStatus::Idle)],
+        ])));
+
+        let events = Mutex::new(Vec::new());
+        let result = monitor
+            .monitor_with_auto_resume("Test Printer", 1, |event| {
+                events.lock().unwrap().push(event.clone());
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            events.into_inner().unwrap(),
+            [AutoResumeEvent::Resumed {
+                printer: "Test Printer".to_string(),
+                attempt: 1
+            }]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_auto_resume_gives_up_on_persistent_pause() {
+        let paused = |error_state| {
+            Printer::new_with_state(
+                "Test Printer".to_string(),
+                PrinterStatus::StoppedPrinting,
+                Some(PrinterState::Paused),
+                error_state,
+                false,
+                false,
+            )
+        };
+        // Every resume reads the queue back still paused
+        let mut script = vec![vec![paused(ErrorState::Jammed)]];
+        script.extend((0..8).map(|_| vec![paused(ErrorState::NoError)]));
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(script)));
+
+        let events = Mutex::new(Vec::new());
+        let result = monitor
+            .monitor_with_auto_resume("Test Printer", 1, |event| {
+                events.lock().unwrap().push(event.clone());
+            })
+            .await;
+        assert!(result.is_err());
+
+        let events = events.into_inner().unwrap();
+        assert_eq!(events.len(), 4, "{:?}", events);
+        for (attempt, event) in (1..=3).zip(&events) {
+            assert!(
+                matches!(event, AutoResumeEvent::Failed { attempt: a, .. } if *a == attempt),
+                "{:?}",
+                event
+            );
         }
+        assert_eq!(
+            events[3],
+            AutoResumeEvent::GaveUp {
+                printer: "Test Printer".to_string(),
+                attempts: 3
+            }
+        );
+    }
 
-        Ok(())
+    #[tokio::test]
+    async fn test_pause_and_resume_verify_state() {
+        let mut paused = test_printer(PrinterStatus::StoppedPrinting);
+        paused.set_state(Some(PrinterState::Paused));
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            vec![paused.clone()],
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![paused],
+        ])));
+
+        assert!(monitor.pause_printer("Test Printer").await.is_ok());
+        assert!(monitor.resume_printer("Test Printer").await.is_ok());
+        // The queue stayed paused, so resuming did not take effect
+        assert!(matches!(
+            monitor.resume_printer("Test Printer").await,
+            Err(PrinterError::Other(_))
+        ));
     }
-}
 
-/// Summary information about a printer's current state.
-///
-/// This struct provides a snapshot of a printer's essential status information
-/// in a convenient format for reporting and monitoring applications.
-#[derive(Debug, Clone)]
-pub struct PrinterSummary {
-    /// Current operational status of the printer
-    pub status: crate::PrinterStatus,
-    /// Current error state of the printer
-    pub error_state: crate::ErrorState,
-    /// Whether the printer is currently offline
-    pub is_offline: bool,
-    /// Whether this is the system's default printer
-    pub is_default: bool,
-    /// Whether the printer currently has any error conditions
-    pub has_error: bool,
-}
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("HP_*", "hp_laserjet"));
+        assert!(glob_match("*jet", "HP_LaserJet"));
+        assert!(glob_match("Floor?-*", "Floor3-Copier"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("HP_*", "Canon_HP_MF"));
+        assert!(!glob_match("Floor?-*", "Floor10-Copier"));
+        assert!(!glob_match("HP", "HP_LaserJet"));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[tokio::test]
+    async fn test_refresh_reports_changes_since_last_look() {
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![test_printer(PrinterStatus::Printing)],
+            vec![test_printer(PrinterStatus::Printing)],
+            vec![],
+        ])));
+
+        let printer = monitor.find_printer("Test Printer").await.unwrap().unwrap();
+        let (printer, changes) = monitor.refresh(&printer).await.unwrap();
+        assert_eq!(printer.status(), &PrinterStatus::Printing);
+        assert_eq!(changes.printer_name, "Test Printer");
+        assert!(matches!(
+            changes.changes.as_slice(),
+            [PropertyChange::Status {
+                old: PrinterStatus::Idle,
+                new: PrinterStatus::Printing,
+            }]
+        ));
+
+        let (printer, changes) = monitor.refresh(&printer).await.unwrap();
+        assert!(!changes.has_changes());
+
+        assert!(matches!(
+            monitor.refresh(&printer).await,
+            Err(PrinterError::PrinterNotFound(name)) if name == "Test Printer"
+        ));
+    }
 
     #[tokio::test]
-    #[cfg(windows)]
-    async fn test_monitor_creation() {
-        let result = PrinterMonitor::new().await;
-        // This might fail in CI/test environments without proper WMI access
-        // but it should at least compile and attempt the connection
-        match result {
-            Ok(_) => println!("Monitor created successfully"),
-            Err(e) => println!("Expected error in test environment: {}", e),
+    async fn test_wmi_status_normalization() {
+        let with_status = |status: Option<&str>| {
+            Printer::from_raw_codes(
+                "Test Printer".to_string(),
+                PrinterStatus::Printing,
+                None,
+                ErrorState::NoError,
+                false,
+                false,
+                crate::RawStatusCodes {
+                    wmi_status: status.map(str::to_string),
+                    ..crate::RawStatusCodes::default()
+                },
+            )
+        };
+        let snapshots = || {
+            vec![
+                vec![with_status(Some(" "))],
+                vec![with_status(None)],
+                vec![with_status(Some("OK"))],
+            ]
+        };
+
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(snapshots())))
+                .with_wmi_status_normalization(true);
+        let blank = monitor.find_printer("Test Printer").await.unwrap().unwrap();
+        let missing = monitor.find_printer("Test Printer").await.unwrap().unwrap();
+        let ok = monitor.find_printer("Test Printer").await.unwrap().unwrap();
+        assert_eq!(blank.wmi_status(), None);
+        assert!(!blank.compare_with(&missing).has_changes());
+        assert_eq!(ok.wmi_status(), Some("OK"));
+        assert!(missing.compare_with(&ok).has_changes());
+
+        // Without the option the blank status is kept and registers as a change
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(snapshots())));
+        let blank = monitor.find_printer("Test Printer").await.unwrap().unwrap();
+        let missing = monitor.find_printer("Test Printer").await.unwrap().unwrap();
+        assert_eq!(blank.wmi_status(), Some(" "));
+        assert!(blank.compare_with(&missing).has_changes());
+    }
+
+    #[tokio::test]
+    async fn test_find_printers_lists_once() {
+        let printers = vec![
+            named_printer("HP_LaserJet"),
+            named_printer("Canon_MF"),
+            named_printer("Brother"),
+        ];
+        // A single scripted snapshot: a second listing would fail
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![printers])));
+
+        let found = monitor
+            .find_printers(&["hp_laserjet", "Brother", "Retired"])
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 3);
+        assert_eq!(
+            found["hp_laserjet"].as_ref().map(|p| p.name()),
+            Some("HP_LaserJet")
+        );
+        assert_eq!(found["Brother"].as_ref().map(|p| p.name()), Some("Brother"));
+        assert!(found["Retired"].is_none());
+
+        // Listing failures are reported instead of every name missing
+        assert!(monitor.find_printers(&["Brother"]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_find_printers_glob() {
+        let printers = vec![
+            named_printer("HP_LaserJet"),
+            named_printer("Canon_MF"),
+            named_printer("HP_OfficeJet"),
+            named_printer("Old-HP_Plotter"),
+        ];
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![printers])));
+
+        let found = monitor.find_printers_glob("HP_*").await.unwrap();
+        let names: Vec<&str> = found.iter().map(|printer| printer.name()).collect();
+        assert_eq!(names, ["HP_LaserJet", "HP_OfficeJet"]);
+    }
+
+    #[cfg(feature = "regex")]
+    #[tokio::test]
+    async fn test_find_printers_regex() {
+        let printers = vec![
+            named_printer("HP_LaserJet"),
+            named_printer("Canon_MF"),
+            named_printer("Old-HP_Plotter"),
+        ];
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![printers])));
+
+        let re = regex::Regex::new("HP_.*Jet$").unwrap();
+        let found = monitor.find_printers_regex(&re).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name(), "HP_LaserJet");
+    }
+
+    #[tokio::test]
+    async fn test_system_monitor_updates_persisted_history() {
+        use crate::JsonFileStore;
+
+        let path = std::env::temp_dir().join(format!(
+            "printer_event_handler_monitor_history_{}.json",
+            std::process::id()
+        ));
+        let store = Arc::new(JsonFileStore::new(&path));
+
+        // History from a previous run is reloaded and kept
+        let earlier = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut prior = HashMap::new();
+        prior.insert(
+            "Old Printer".to_string(),
+            PrinterHistory {
+                last_seen_online: Some(earlier),
+                last_seen_offline: None,
+            },
+        );
+        store.save(&prior).await.unwrap();
+
+        let start = Utc.with_ymd_and_hms(2025, 1, 2, 9, 0, 0).unwrap();
+        let clock = Arc::new(FixedClock::new(start));
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![offline_printer("Test Printer")],
+        ])))
+        .with_clock(clock.clone())
+        .with_history_store(store.clone());
+
+        let result = monitor
+            .monitor_system_batched(1, |_, _| clock.advance(chrono::Duration::minutes(1)))
+            .await;
+        assert!(result.is_err());
+
+        let history = store.load().await.unwrap();
+        assert_eq!(history["Old Printer"], prior["Old Printer"]);
+        assert_eq!(
+            history["Test Printer"],
+            PrinterHistory {
+                last_seen_online: Some(start),
+                last_seen_offline: Some(start + chrono::Duration::minutes(1)),
+            }
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_find_printer_with_name_normalization() {
+        let fleet = || vec![named_printer("HP_LaserJet"), named_printer("Canon Office")];
+
+        let strict = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            fleet(),
+            fleet(),
+        ])));
+        assert!(strict.find_printer("HP LaserJet").await.unwrap().is_none());
+        assert!(strict.find_printer("hp_laserjet").await.unwrap().is_some());
+
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![fleet(); 6])))
+                .with_name_normalization(true);
+        let found = monitor.find_printer("HP LaserJet").await.unwrap().unwrap();
+        assert_eq!(found.name(), "HP_LaserJet");
+        let found = monitor.find_printer("canon_office").await.unwrap().unwrap();
+        assert_eq!(found.name(), "Canon Office");
+        assert!(monitor.find_printer("HP Color").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_max_printers_truncates_listing() {
+        let fleet: Vec<Printer> = (0..10)
+            .map(|i| named_printer(&format!("Printer {}", i)))
+            .collect();
+        let limits = Arc::new(Mutex::new(Vec::new()));
+        let backend =
+            MockBackend::with_snapshots(vec![fleet.clone(), fleet.clone(), fleet[..2].to_vec()])
+                .recording_limits(limits.clone());
+        let monitor = PrinterMonitor::with_backend(Box::new(backend)).with_max_printers(3);
+
+        let printers = monitor.list_printers().await.unwrap();
+        assert_eq!(printers, fleet[..3].to_vec());
+        // The backend was asked for one printer past the cap
+        assert_eq!(*limits.lock().unwrap(), vec![4]);
+
+        let (printers, errors) = monitor.list_printers_lenient().await.unwrap();
+        assert_eq!(printers.len(), 3);
+        assert!(errors.is_empty());
+
+        // Below the cap nothing is dropped
+        assert_eq!(monitor.list_printers().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_max_printers_with_fax_excluded_lists_everything() {
+        let limits = Arc::new(Mutex::new(Vec::new()));
+        let fleet = vec![
+            named_printer("Front Desk")
+                .with_driver(Some("Microsoft Shared Fax Driver".to_string())),
+            named_printer("HP LaserJet"),
+        ];
+        let backend = MockBackend::with_snapshots(vec![fleet]).recording_limits(limits.clone());
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_max_printers(1)
+            .with_fax_excluded(true);
+
+        // Capping before the fax device is dropped would leave nothing
+        let printers = monitor.list_printers().await.unwrap();
+        assert_eq!(printers, vec![named_printer("HP LaserJet")]);
+        assert!(limits.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lookups_ignore_listing_filters() {
+        let fleet = || {
+            vec![
+                named_printer("HP LaserJet"),
+                named_printer("Front Desk")
+                    .with_driver(Some("Microsoft Shared Fax Driver".to_string())),
+                offline_printer("Lab"),
+            ]
+        };
+        let backend =
+            MockBackend::with_snapshots(vec![fleet(), fleet(), fleet()]).with_classes(vec![
+                PrinterClass {
+                    name: "office".to_string(),
+                    members: vec!["Lab".to_string(), "Front Desk".to_string()],
+                },
+            ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_max_printers(1)
+            .with_fax_excluded(true);
+
+        let found = monitor.find_printers(&["Front Desk", "Lab"]).await.unwrap();
+        assert!(found.values().all(Option::is_some));
+
+        let members = monitor.find_printer_or_class("office").await.unwrap();
+        let names: Vec<&str> = members.iter().map(|printer| printer.name()).collect();
+        assert_eq!(names, ["Lab", "Front Desk"]);
+
+        // The offline printer beyond the cap still turns the fleet red
+        assert!(!monitor.all_healthy().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_zero_interval_is_rejected() {
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            vec![named_printer("Office")],
+        ])));
+        let invalid = |result: Result<()>| matches!(result, Err(PrinterError::InvalidConfig(_)));
+
+        assert!(invalid(
+            monitor.monitor_printer("Office", 0, |_, _| {}).await
+        ));
+        assert!(invalid(
+            monitor.monitor_printer_changes("Office", 0, |_| {}).await
+        ));
+        assert!(invalid(monitor.monitor_system(0, |_| {}).await));
+        assert!(invalid(
+            monitor
+                .monitor_multiple_printers(vec!["Office".to_string()], 0, |_| {})
+                .await
+        ));
+        assert!(matches!(
+            monitor
+                .wait_online("Office", 0, Duration::from_millis(10))
+                .await,
+            Err(PrinterError::InvalidConfig(_))
+        ));
+
+        // Nothing was polled, so the scripted snapshot is still available
+        assert_eq!(monitor.list_printers().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_changed_since_token() {
+        let busy = Printer::new(
+            "Office".to_string(),
+            PrinterStatus::Printing,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+        let backend = MockBackend::with_snapshots(vec![
+            vec![named_printer("Office"), named_printer("Lab")],
+            vec![busy, named_printer("Reception")],
+            vec![named_printer("Office"), named_printer("Reception")],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let (printers, first) = monitor.list_printers_with_token().await.unwrap();
+        assert_eq!(printers.len(), 2);
+
+        let (changes, second) = monitor.changed_since(&first).await.unwrap();
+        assert_ne!(first, second);
+        assert_eq!(changes.len(), 3);
+        assert!(
+            matches!(&changes[0], SystemChange::PrinterChanged { current, .. }
+            if current.status() == &PrinterStatus::Printing)
+        );
+        assert!(matches!(&changes[1], SystemChange::PrinterAdded(p) if p.name() == "Reception"));
+        assert!(matches!(&changes[2], SystemChange::PrinterRemoved(p) if p.name() == "Lab"));
+
+        // The first token stays valid, so its diff now spans both polls
+        let (changes, _) = monitor.changed_since(&first).await.unwrap();
+        assert_eq!(changes.len(), 2);
+        assert!(
+            changes
+                .iter()
+                .all(|c| !matches!(c, SystemChange::PrinterChanged { .. }))
+        );
+
+        let unknown = SnapshotToken(u64::MAX);
+        assert!(matches!(
+            monitor.changed_since(&unknown).await,
+            Err(PrinterError::Other(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_tokens_expire() {
+        let backend = MockBackend::with_snapshots(vec![vec![]; MAX_SNAPSHOTS + 1]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let (_, oldest) = monitor.list_printers_with_token().await.unwrap();
+        let mut latest = oldest;
+        for _ in 0..MAX_SNAPSHOTS - 1 {
+            latest = monitor.list_printers_with_token().await.unwrap().1;
         }
+        assert_eq!(monitor.snapshots.lock().unwrap().len(), MAX_SNAPSHOTS);
+
+        let (changes, _) = monitor.changed_since(&latest).await.unwrap();
+        assert!(changes.is_empty());
+        assert!(monitor.changed_since(&oldest).await.is_err());
     }
 
     #[tokio::test]
-    #[cfg(unix)]
-    async fn test_monitor_unix_creation() {
-        let result = PrinterMonitor::new().await;
-        // On Unix/Linux, the monitor should be created successfully
+    async fn test_problems_report_status_conflicts() {
+        let conflicting = Printer::from_raw_codes(
+            "Office".to_string(),
+            PrinterStatus::Idle,
+            None,
+            ErrorState::NoError,
+            false,
+            false,
+            crate::RawStatusCodes {
+                wmi_status: Some("Degraded".to_string()),
+                ..crate::RawStatusCodes::default()
+            },
+        );
+        let monitor = PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![
+            vec![named_printer("Lab"), conflicting],
+        ])));
+
+        let problems = monitor.problems().await.unwrap();
+        assert_eq!(
+            problems,
+            vec![PrinterProblem {
+                name: "Office".to_string(),
+                severity: Severity::Warning,
+                reasons: vec![
+                    "Conflicting Status: Idle with no error, but WMI Status is 'Degraded'; \
+                     check the printer driver"
+                        .to_string()
+                ],
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_problems_reports_only_printers_with_issues() {
+        let jammed = Printer::new(
+            "Jammed".to_string(),
+            PrinterStatus::Other,
+            ErrorState::Jammed,
+            false,
+            false,
+        );
+        let mut low_toner_and_open = Printer::new(
+            "Low Toner".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::LowToner,
+            false,
+            false,
+        );
+        low_toner_and_open.set_state(Some(PrinterState::DoorOpen));
+        let mut manual_feed = named_printer("Manual Feed");
+        manual_feed.set_state(Some(PrinterState::ManualFeed));
+        let mut out_of_memory = named_printer("Out Of Memory");
+        out_of_memory.set_state(Some(PrinterState::OutOfMemory));
+        let fleet = vec![
+            named_printer("Healthy"),
+            jammed,
+            offline_printer("Offline"),
+            low_toner_and_open,
+            manual_feed,
+            out_of_memory,
+        ];
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(vec![fleet])));
+
+        let problems = monitor.problems().await.unwrap();
+        assert_eq!(
+            problems,
+            vec![
+                PrinterProblem {
+                    name: "Jammed".to_string(),
+                    severity: Severity::Error,
+                    reasons: vec!["Jammed: Clear the paper jam".to_string()],
+                },
+                PrinterProblem {
+                    name: "Offline".to_string(),
+                    severity: Severity::Error,
+                    reasons: vec!["Offline".to_string()],
+                },
+                PrinterProblem {
+                    name: "Low Toner".to_string(),
+                    severity: Severity::Error,
+                    reasons: vec![
+                        "Door Open: Close all printer doors and covers".to_string(),
+                        "Low Toner: Order a replacement toner cartridge".to_string(),
+                    ],
+                },
+                PrinterProblem {
+                    name: "Manual Feed".to_string(),
+                    severity: Severity::Warning,
+                    reasons: vec![
+                        "Manual Feed Required: Load paper into the manual feed slot".to_string(),
+                    ],
+                },
+                PrinterProblem {
+                    name: "Out Of Memory".to_string(),
+                    severity: Severity::Error,
+                    reasons: vec![
+                        "Printer out of memory; reduce job complexity or add RAM.".to_string(),
+                    ],
+                },
+            ]
+        );
+    }
+
+    fn printer_with_error(error_state: ErrorState) -> Printer {
+        Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            error_state,
+            false,
+            false,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_error_transitions_ignore_repeated_errors() {
+        let backend = MockBackend::with_snapshots(vec![
+            vec![printer_with_error(ErrorState::NoError)],
+            vec![printer_with_error(ErrorState::Jammed)],
+            vec![printer_with_error(ErrorState::Jammed)],
+            vec![printer_with_error(ErrorState::NoError)],
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let mut transitions = Vec::new();
+        let _ = monitor
+            .monitor_error_transitions("Test Printer", 1, |transition| transitions.push(transition))
+            .await;
+
+        let summary: Vec<(bool, ErrorState, ErrorState)> = transitions
+            .into_iter()
+            .map(|t| (t.became_error, t.from, t.to))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (true, ErrorState::NoError, ErrorState::Jammed),
+                (false, ErrorState::Jammed, ErrorState::NoError),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_transitions_opt_in_error_changes() {
+        let snapshots = vec![
+            vec![printer_with_error(ErrorState::Jammed)],
+            vec![printer_with_error(ErrorState::NoPaper)],
+        ];
+
+        let mut default_count = 0;
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(snapshots.clone())));
+        let _ = monitor
+            .monitor_error_transitions("Test Printer", 1, |_| default_count += 1)
+            .await;
+        assert_eq!(default_count, 0);
+
+        let mut transitions = Vec::new();
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(snapshots)));
+        let _ = monitor
+            .monitor_error_transitions_with("Test Printer", 1, true, |transition| {
+                transitions.push(transition)
+            })
+            .await;
+        assert_eq!(transitions.len(), 1);
+        assert!(transitions[0].became_error);
+        assert_eq!(transitions[0].to, ErrorState::NoPaper);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_missing_job() {
+        let cancelling = |stderr: Option<&str>| {
+            let backend = MockBackend::with_snapshots(vec![]);
+            let backend = match stderr {
+                Some(stderr) => backend.with_cancel_stderr(stderr),
+                None => backend,
+            };
+            PrinterMonitor::with_backend(Box::new(backend))
+        };
+
+        // What CUPS prints for a job that is already gone
+        let monitor = cancelling(Some("cancel: cancel-job failed: Job #7 does not exist.\n"));
+        let result = monitor.cancel_job("Test Printer", 7).await;
+        assert!(matches!(
+            result,
+            Err(PrinterError::JobNotFound { ref printer, job_id: 7 }) if printer == "Test Printer"
+        ));
+
+        // ... and for an unknown destination
+        let monitor = cancelling(Some(
+            "cancel: cancel-job failed: The printer or class does not exist.\n",
+        ));
+        let result = monitor.cancel_all_jobs("Gone").await;
+        assert!(matches!(result, Err(PrinterError::PrinterNotFound(ref name)) if name == "Gone"));
+
+        assert!(
+            cancelling(None)
+                .cancel_all_jobs("Test Printer")
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default_change_triggers_both_monitors() {
+        let printer = |is_default| {
+            Printer::new(
+                "Test Printer".to_string(),
+                PrinterStatus::Idle,
+                ErrorState::NoError,
+                false,
+                is_default,
+            )
+        };
+        assert_ne!(printer(false), printer(true));
+        assert!(printer(false).compare_with(&printer(true)).has_changes());
+        let options = crate::CompareOptions {
+            ignore_default: true,
+            ..Default::default()
+        };
+        assert!(
+            !printer(false)
+                .compare_with_options(&printer(true), &options)
+                .has_changes()
+        );
+
+        let snapshots = || vec![vec![printer(false)], vec![printer(true)]];
+
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(snapshots())));
+        let mut seen = Vec::new();
+        let _ = monitor
+            .monitor_printer("Test Printer", 1, |current, _| {
+                seen.push(current.is_default());
+            })
+            .await;
+        assert_eq!(seen, vec![false, true]);
+
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(MockBackend::with_snapshots(snapshots())));
+        let mut changed = Vec::new();
+        let _ = monitor
+            .monitor_printer_changes("Test Printer", 1, |changes| {
+                if changes.has_changes() {
+                    changed.push(changes.has_property_change("IsDefault"));
+                }
+            })
+            .await;
+        assert_eq!(changed, vec![true]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnect_on_error_resumes_monitoring() {
+        let backend = MockBackend::with_snapshots(vec![
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![test_printer(PrinterStatus::Printing)],
+        ])
+        .failing_after(1);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend)).with_reconnect_on_error(true);
+
+        let mut seen = Vec::new();
+        let result = monitor
+            .monitor_printer("Test Printer", 1, |current, _| {
+                seen.push(current.status().clone());
+            })
+            .await;
+
+        // The dead connection was rebuilt; only the exhausted script ends monitoring
+        assert!(matches!(result, Err(PrinterError::Other(_))));
+        assert_eq!(seen, vec![PrinterStatus::Idle, PrinterStatus::Printing]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_multiple_printers_share_backend_and_settings() {
+        let backend = MockBackend::with_snapshots(vec![
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![test_printer(PrinterStatus::Printing)],
+        ])
+        .failing_after(1);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend)).with_reconnect_on_error(true);
+
+        let counts = Arc::new(Mutex::new(Vec::new()));
+        let recorded = counts.clone();
+        let result = monitor
+            .monitor_multiple_printers(vec!["Test Printer".to_string()], 1, move |changes| {
+                recorded.lock().unwrap().push(changes.change_count());
+            })
+            .await;
+
+        // The task polled the scripted backend and reconnected it after the failure
+        assert!(matches!(result, Err(PrinterError::Other(_))));
+        assert_eq!(*counts.lock().unwrap(), vec![0, 1]);
+        assert_eq!(monitor.query_timings().unwrap().samples, 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnect_backoff_grows_across_failed_polls() {
+        let unavailable = || {
+            Err(PrinterError::WmiError(
+                "The RPC server is unavailable. (0x800706BA)".to_string(),
+            ))
+        };
+        let backend = MockBackend::with_results(vec![
+            Ok(vec![test_printer(PrinterStatus::Idle)]),
+            unavailable(),
+            unavailable(),
+            unavailable(),
+            Ok(vec![test_printer(PrinterStatus::Printing)]),
+            unavailable(),
+        ]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend))
+            .with_reconnect_on_error(true)
+            .with_backoff_policy(BackoffPolicy::exponential(
+                Duration::from_secs(1),
+                Duration::from_secs(60),
+            ));
+
+        let started = tokio::time::Instant::now();
+        let mut polled = Vec::new();
+        let result = monitor
+            .monitor_printer("Test Printer", 1, |_, _| polled.push(started.elapsed()))
+            .await;
+        assert!(matches!(result, Err(PrinterError::Other(_))));
+
+        // Reconnects succeed but the backend keeps failing: 1s + 2s + 4s of backoff,
+        // then a successful poll resets it to 1s for the next failure
+        assert_eq!(polled.len(), 2);
+        assert!(polled[1] >= Duration::from_secs(7) && polled[1] < Duration::from_secs(8));
+        assert!(started.elapsed() >= Duration::from_secs(8));
+        assert!(started.elapsed() < Duration::from_secs(9));
+    }
+
+    #[tokio::test]
+    async fn test_retryable_error_aborts_without_reconnect() {
+        let backend = MockBackend::with_snapshots(vec![
+            vec![test_printer(PrinterStatus::Idle)],
+            vec![test_printer(PrinterStatus::Printing)],
+        ])
+        .failing_after(1);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let result = monitor.monitor_printer("Test Printer", 1, |_, _| {}).await;
+        assert!(matches!(result, Err(PrinterError::WmiError(_))));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_error_handler_continues_past_transient_errors() {
+        let blip = || Err(PrinterError::Other("spooler busy".to_string()));
+        let backend = MockBackend::with_results(vec![
+            Ok(vec![test_printer(PrinterStatus::Idle)]),
+            blip(),
+            Ok(vec![test_printer(PrinterStatus::Printing)]),
+            blip(),
+            Ok(vec![test_printer(PrinterStatus::Idle)]),
+        ]);
+        let failures = Arc::new(Mutex::new(Vec::new()));
+        let recorded = failures.clone();
+        let monitor =
+            PrinterMonitor::with_backend(Box::new(backend)).with_error_handler(move |_, count| {
+                recorded.lock().unwrap().push(count);
+                if count < 3 {
+                    ErrorAction::Continue
+                } else {
+                    ErrorAction::Abort
+                }
+            });
+
+        let mut seen = Vec::new();
+        let result = monitor
+            .monitor_printer("Test Printer", 1, |current, _| {
+                seen.push(current.status().clone());
+            })
+            .await;
+
+        // Blips are skipped; the exhausted script fails three polls in a row
+        assert!(matches!(result, Err(PrinterError::Other(_))));
+        assert_eq!(
+            seen,
+            vec![
+                PrinterStatus::Idle,
+                PrinterStatus::Printing,
+                PrinterStatus::Idle
+            ]
+        );
+        assert_eq!(*failures.lock().unwrap(), vec![1, 1, 1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_error_handler_abort_stops_monitoring() {
+        let backend = MockBackend::with_results(vec![
+            Ok(vec![named_printer("Office")]),
+            Err(PrinterError::Other("spooler busy".to_string())),
+            Ok(vec![named_printer("Office")]),
+        ]);
+        let monitor = PrinterMonitor::builder()
+            .backend(Box::new(backend))
+            .error_handler(|_, _| ErrorAction::Abort)
+            .build()
+            .await
+            .unwrap();
+
+        let mut polls = 0;
+        let result = monitor.monitor_system_batched(1, |_, _| polls += 1).await;
+        assert!(matches!(result, Err(PrinterError::Other(_))));
+        assert_eq!(polls, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_monitor_printer_until_shutdown() {
+        let backend =
+            MockBackend::with_snapshots(vec![vec![test_printer(PrinterStatus::Idle)]; 1000]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+
+        let mut seen = 0;
+        let run = monitor.monitor_printer_until("Test Printer", 100, |_, _| seen += 1, async {
+            let _ = stopped.await;
+        });
+        let interrupt = async {
+            sleep(Duration::from_millis(250)).await;
+            stop.send(()).unwrap();
+        };
+
+        let (result, ()) = tokio::join!(run, interrupt);
+        // Interrupted cleanly long before the script ran out
         assert!(result.is_ok());
+        assert_eq!(seen, 1);
+    }
+
+    #[tokio::test]
+    async fn test_monitor_printer_until_returns_errors() {
+        let backend = MockBackend::with_snapshots(vec![]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let result = monitor
+            .monitor_printer_until("Test Printer", 1, |_, _| {}, std::future::pending())
+            .await;
+        assert!(matches!(result, Err(PrinterError::Other(_))));
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_fires_at_configured_cadence() {
+        let backend = MockBackend::with_snapshots(vec![vec![test_printer(PrinterStatus::Idle)]; 7]);
+        let monitor = PrinterMonitor::with_backend(Box::new(backend));
+
+        let mut changes = 0;
+        let mut heartbeats = Vec::new();
+        let result = monitor
+            .monitor_printer_with_heartbeat(
+                "Test Printer",
+                1,
+                3,
+                |_, _| changes += 1,
+                |current| heartbeats.push(current.name().to_string()),
+            )
+            .await;
+
+        assert!(result.is_err());
+        // Only the initial state is a change; heartbeats fire on polls 3 and 6
+        assert_eq!(changes, 1);
+        assert_eq!(heartbeats, ["Test Printer", "Test Printer"]);
     }
 }
@@ -0,0 +1,394 @@
+use crate::{Printer, PrinterChanges, PrinterError, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Default number of printers whose last recorded change is kept for deduplication
+const DEFAULT_DEDUP_CAPACITY: usize = 64;
+
+/// Availability history of a single printer, kept across monitor restarts
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrinterHistory {
+    /// When the printer was last seen online
+    pub last_seen_online: Option<DateTime<Utc>>,
+    /// When the printer was last seen offline
+    pub last_seen_offline: Option<DateTime<Utc>>,
+}
+
+impl PrinterHistory {
+    /// Records an observation of the printer at the given time
+    pub fn record(&mut self, printer: &Printer, at: DateTime<Utc>) {
+        if printer.is_offline() {
+            self.last_seen_offline = Some(at);
+        } else {
+            self.last_seen_online = Some(at);
+        }
+    }
+}
+
+/// Durable storage for printer availability history.
+///
+/// The system monitor loads the history when it starts and saves it after every
+/// poll (see [`crate::PrinterMonitor::with_history_store`]).
+#[async_trait]
+pub trait PersistenceStore: Send + Sync {
+    /// Loads the stored history, keyed by printer name.
+    ///
+    /// Returns an empty map when nothing has been stored yet.
+    async fn load(&self) -> Result<HashMap<String, PrinterHistory>>;
+
+    /// Replaces the stored history
+    async fn save(&self, history: &HashMap<String, PrinterHistory>) -> Result<()>;
+}
+
+/// Stores printer history as a JSON file.
+///
+/// # Example
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use printer_event_handler::{JsonFileStore, PrinterMonitor};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let monitor = PrinterMonitor::new()
+///         .await
+///         .unwrap()
+///         .with_history_store(Arc::new(JsonFileStore::new("printer_history.json")));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonFileStore {
+    path: PathBuf,
+}
+
+impl JsonFileStore {
+    /// Creates a store backed by the file at `path` (created on first save)
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the backing file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl PersistenceStore for JsonFileStore {
+    async fn load(&self) -> Result<HashMap<String, PrinterHistory>> {
+        let contents = match tokio::fs::read(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        serde_json::from_slice(&contents).map_err(|e| {
+            PrinterError::Other(format!(
+                "invalid history file '{}': {}",
+                self.path.display(),
+                e
+            ))
+        })
+    }
+
+    async fn save(&self, history: &HashMap<String, PrinterHistory>) -> Result<()> {
+        let contents = serde_json::to_vec_pretty(history)
+            .map_err(|e| PrinterError::Other(format!("cannot serialize history: {}", e)))?;
+
+        // Write a sibling file first so a crash never leaves a truncated history
+        let mut temp = self.path.clone().into_os_string();
+        temp.push(".tmp");
+        tokio::fs::write(&temp, contents).await?;
+        tokio::fs::rename(&temp, &self.path).await?;
+        Ok(())
+    }
+}
+
+/// Writes change history as JSON Lines, one [`PrinterChanges`] per line.
+///
+/// With [`with_dedup`](Self::with_dedup), a change that is identical to the
+/// previous change recorded for the same printer is skipped while it falls within
+/// the window, so a flapping printer adds one line per window instead of one per
+/// poll. Changes are compared by printer name and property changes; timestamps are
+/// only used for the window, and a change stamped before the last recorded one is
+/// always written. The deduplication state holds the last recorded change
+/// of at most [`with_dedup_capacity`](Self::with_dedup_capacity) printers, evicting
+/// the least recently recorded one.
+///
+/// # Example
+/// ```rust,no_run
+/// use printer_event_handler::{JsonlRecorder, PrinterMonitor};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut recorder = JsonlRecorder::create("changes.jsonl")?
+///         .with_dedup(chrono::Duration::minutes(10));
+///     let monitor = PrinterMonitor::new().await?;
+///     monitor
+///         .monitor_printer_changes("HP LaserJet", 60000, |changes| {
+///             if let Err(e) = recorder.record(changes) {
+///                 eprintln!("Cannot record changes: {}", e);
+///             }
+///         })
+///         .await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct JsonlRecorder<W: Write> {
+    writer: W,
+    dedup_window: Option<Duration>,
+    dedup_capacity: usize,
+    last_recorded: VecDeque<PrinterChanges>,
+}
+
+impl JsonlRecorder<File> {
+    /// Opens the file at `path` for appending, creating it if needed
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::new(file))
+    }
+}
+
+impl<W: Write> JsonlRecorder<W> {
+    /// Creates a recorder writing to `writer`, without deduplication
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            dedup_window: None,
+            dedup_capacity: DEFAULT_DEDUP_CAPACITY,
+            last_recorded: VecDeque::new(),
+        }
+    }
+
+    /// Skips changes identical to the printer's previous recorded change within `window`
+    pub fn with_dedup(mut self, window: Duration) -> Self {
+        self.dedup_window = Some(window);
+        self
+    }
+
+    /// Sets how many printers are remembered for deduplication (at least one)
+    pub fn with_dedup_capacity(mut self, printers: usize) -> Self {
+        self.dedup_capacity = printers.max(1);
+        self.last_recorded.truncate(self.dedup_capacity);
+        self
+    }
+
+    /// Writes the changes as one line, unless deduplication suppresses them.
+    ///
+    /// Returns whether a line was written.
+    ///
+    /// # Errors
+    /// * `PrinterError::IoError` - If writing to the underlying writer fails
+    pub fn record(&mut self, changes: &PrinterChanges) -> Result<bool> {
+        let Some(window) = self.dedup_window else {
+            self.write_line(changes)?;
+            return Ok(true);
+        };
+
+        let previous = self
+            .last_recorded
+            .iter()
+            .position(|last| last.printer_name == changes.printer_name);
+        if let Some(index) = previous {
+            let last = &self.last_recorded[index];
+            // A change stamped before the last recorded one is out of order, not a repeat
+            let elapsed = changes.timestamp - last.timestamp;
+            if last.changes == changes.changes && Duration::zero() <= elapsed && elapsed <= window {
+                return Ok(false);
+            }
+            self.last_recorded.remove(index);
+        }
+
+        self.write_line(changes)?;
+        self.last_recorded.push_front(changes.clone());
+        self.last_recorded.truncate(self.dedup_capacity);
+        Ok(true)
+    }
+
+    /// Flushes the underlying writer
+    pub fn flush(&mut self) -> Result<()> {
+        Ok(self.writer.flush()?)
+    }
+
+    /// Returns the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_line(&mut self, changes: &PrinterChanges) -> Result<()> {
+        let line = serde_json::to_string(changes)
+            .map_err(|e| PrinterError::Other(format!("cannot serialize changes: {}", e)))?;
+        writeln!(self.writer, "{}", line)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ErrorState, PrinterStatus};
+    use chrono::TimeZone;
+
+    fn temp_store(name: &str) -> JsonFileStore {
+        let path = std::env::temp_dir().join(format!(
+            "printer_event_handler_{}_{}.json",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        JsonFileStore::new(path)
+    }
+
+    #[tokio::test]
+    async fn test_json_store_round_trip() {
+        let store = temp_store("round_trip");
+        assert!(store.load().await.unwrap().is_empty());
+
+        let at = Utc.with_ymd_and_hms(2025, 3, 1, 8, 0, 0).unwrap();
+        let mut history = HashMap::new();
+        history.insert(
+            "HP_LaserJet".to_string(),
+            PrinterHistory {
+                last_seen_online: Some(at),
+                last_seen_offline: None,
+            },
+        );
+
+        store.save(&history).await.unwrap();
+        assert_eq!(store.load().await.unwrap(), history);
+
+        std::fs::write(store.path(), "not json").unwrap();
+        assert!(store.load().await.is_err());
+        std::fs::remove_file(store.path()).unwrap();
+    }
+
+    #[test]
+    fn test_history_records_online_and_offline() {
+        let online = Printer::new(
+            "HP".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+        let offline = Printer::new(
+            "HP".to_string(),
+            PrinterStatus::Offline,
+            ErrorState::Other,
+            true,
+            false,
+        );
+        let first = Utc.with_ymd_and_hms(2025, 3, 1, 8, 0, 0).unwrap();
+        let later = first + chrono::Duration::minutes(10);
+
+        let mut history = PrinterHistory::default();
+        history.record(&online, first);
+        history.record(&offline, later);
+        assert_eq!(history.last_seen_online, Some(first));
+        assert_eq!(history.last_seen_offline, Some(later));
+    }
+
+    fn offline_change(name: &str, offline: bool, at: DateTime<Utc>) -> PrinterChanges {
+        PrinterChanges {
+            printer_name: name.to_string(),
+            changes: vec![crate::PropertyChange::IsOffline {
+                old: !offline,
+                new: offline,
+            }],
+            timestamp: at,
+        }
+    }
+
+    fn recorded_lines(recorder: JsonlRecorder<Vec<u8>>) -> Vec<(String, DateTime<Utc>)> {
+        String::from_utf8(recorder.into_inner())
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let changes: PrinterChanges = serde_json::from_str(line).unwrap();
+                (changes.printer_name, changes.timestamp)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_jsonl_recorder_dedups_flapping_printer() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 8, 0, 0).unwrap();
+        let at = |minutes| start + chrono::Duration::minutes(minutes);
+
+        // The printer keeps dropping offline; the recoveries are missed between polls
+        let flapping = [
+            offline_change("HP", true, at(0)),
+            offline_change("HP", true, at(2)),
+            offline_change("Canon", true, at(3)),
+            offline_change("HP", true, at(4)),
+            offline_change("HP", false, at(5)),
+            offline_change("HP", true, at(6)),
+            offline_change("HP", true, at(20)),
+        ];
+
+        let mut plain = JsonlRecorder::new(Vec::new());
+        for changes in &flapping {
+            assert!(plain.record(changes).unwrap());
+        }
+        assert_eq!(recorded_lines(plain).len(), flapping.len());
+
+        let mut dedup = JsonlRecorder::new(Vec::new()).with_dedup(chrono::Duration::minutes(10));
+        let written: Vec<bool> = flapping
+            .iter()
+            .map(|changes| dedup.record(changes).unwrap())
+            .collect();
+        assert_eq!(written, vec![true, false, true, false, true, true, true]);
+        assert_eq!(
+            recorded_lines(dedup),
+            vec![
+                ("HP".to_string(), at(0)),
+                ("Canon".to_string(), at(3)),
+                ("HP".to_string(), at(5)),
+                ("HP".to_string(), at(6)),
+                ("HP".to_string(), at(20)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jsonl_recorder_writes_out_of_order_changes() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 8, 0, 0).unwrap();
+        let mut recorder = JsonlRecorder::new(Vec::new()).with_dedup(chrono::Duration::minutes(10));
+
+        assert!(recorder.record(&offline_change("HP", true, start)).unwrap());
+        // Identical, but stamped an hour earlier, e.g. replayed from another source
+        let earlier = start - chrono::Duration::hours(1);
+        assert!(
+            recorder
+                .record(&offline_change("HP", true, earlier))
+                .unwrap()
+        );
+        assert_eq!(
+            recorded_lines(recorder),
+            vec![("HP".to_string(), start), ("HP".to_string(), earlier)]
+        );
+    }
+
+    #[test]
+    fn test_jsonl_recorder_dedup_state_is_bounded() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 8, 0, 0).unwrap();
+        let mut recorder = JsonlRecorder::new(Vec::new())
+            .with_dedup(chrono::Duration::hours(1))
+            .with_dedup_capacity(2);
+
+        assert!(recorder.record(&offline_change("A", true, start)).unwrap());
+        assert!(recorder.record(&offline_change("B", true, start)).unwrap());
+        assert!(recorder.record(&offline_change("C", true, start)).unwrap());
+        assert_eq!(recorder.last_recorded.len(), 2);
+
+        // "A" was evicted, so its repeat is written again; "C" is still remembered
+        assert!(recorder.record(&offline_change("A", true, start)).unwrap());
+        assert!(!recorder.record(&offline_change("C", true, start)).unwrap());
+        assert_eq!(recorded_lines(recorder).len(), 4);
+    }
+}
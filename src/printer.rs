@@ -1,11 +1,12 @@
-#[cfg(windows)]
-use serde::Deserialize;
+use crate::clock::{Clock, SystemClock};
+use serde::{Deserialize, Serialize};
 
 /// Represents a printer's status (Win32_Printer.PrinterStatus - Current/Recommended)
 ///
 /// This is the current WMI property for printer status information.
 /// Values 1-7 according to Microsoft documentation.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum PrinterStatus {
     Other,           // 1
     Unknown,         // 2
@@ -22,7 +23,8 @@ pub enum PrinterStatus {
 /// This enum represents the actual WMI PrinterState values which correspond to
 /// the .NET System.Printing.PrintQueueStatus enumeration flags.
 /// See: <https://learn.microsoft.com/en-us/dotnet/api/system.printing.printqueuestatus>
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum PrinterState {
     None,                     // 0 - No status
     Paused,                   // 1 - The print queue is paused
@@ -53,6 +55,35 @@ pub enum PrinterState {
     StatusUnknown,            // Fallback for unmapped values
 }
 
+/// PrintQueueStatus bit values, in bit order
+const STATE_FLAGS: [(u32, PrinterState); 25] = [
+    (1, PrinterState::Paused),
+    (2, PrinterState::Error),
+    (4, PrinterState::PendingDeletion),
+    (8, PrinterState::PaperJam),
+    (16, PrinterState::PaperOut),
+    (32, PrinterState::ManualFeed),
+    (64, PrinterState::PaperProblem),
+    (128, PrinterState::Offline),
+    (256, PrinterState::IOActive),
+    (512, PrinterState::Busy),
+    (1024, PrinterState::Printing),
+    (2048, PrinterState::OutputBinFull),
+    (4096, PrinterState::NotAvailable),
+    (8192, PrinterState::Waiting),
+    (16384, PrinterState::Processing),
+    (32768, PrinterState::Initializing),
+    (65536, PrinterState::WarmingUp),
+    (131072, PrinterState::TonerLow),
+    (262144, PrinterState::NoToner),
+    (524288, PrinterState::PagePunt),
+    (1048576, PrinterState::UserInterventionRequired),
+    (2097152, PrinterState::OutOfMemory),
+    (4194304, PrinterState::DoorOpen),
+    (8388608, PrinterState::ServerUnknown),
+    (16777216, PrinterState::PowerSave),
+];
+
 impl PrinterStatus {
     /// Creates a PrinterStatus from a WMI status code.
     ///
@@ -205,6 +236,76 @@ impl PrinterState {
         }
     }
 
+    /// Creates a PrinterState from a CUPS `printer-state` value and `printer-state-reasons`.
+    ///
+    /// Reasons take precedence over the base state, most severe first, mirroring the
+    /// priority used for WMI flags. Reason keywords are matched with or without their
+    /// `-report`/`-warning`/`-error` severity suffix.
+    ///
+    /// | Reason / state                                  | PrinterState |
+    /// |-------------------------------------------------|--------------|
+    /// | `door-open`, `cover-open`, `interlock-open`     | DoorOpen     |
+    /// | `media-jam`                                     | PaperJam     |
+    /// | `media-empty`, `media-needed`                   | PaperOut     |
+    /// | `toner-empty`, `marker-supply-empty`            | NoToner      |
+    /// | `offline`, `shutdown`                           | Offline      |
+    /// | `toner-low`, `marker-supply-low`                | TonerLow     |
+    /// | `paused`                                        | Paused       |
+    /// | `connecting-to-device`, `processing-to-stop-point` | Processing |
+    /// | state 3 (idle)                                  | None         |
+    /// | state 4 (processing)                            | Printing     |
+    /// | state 5 (stopped)                               | Paused       |
+    ///
+    /// # Arguments
+    /// * `printer_state` - CUPS/IPP printer-state (3 = idle, 4 = processing, 5 = stopped)
+    /// * `reasons` - printer-state-reasons keywords, e.g. `["media-empty-error"]`
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::PrinterState;
+    ///
+    /// assert_eq!(PrinterState::from_cups(5, &["media-empty-error"]), PrinterState::PaperOut);
+    /// assert_eq!(PrinterState::from_cups(4, &["none"]), PrinterState::Printing);
+    /// ```
+    pub fn from_cups<S: AsRef<str>>(printer_state: u32, reasons: &[S]) -> Self {
+        let has_reason = |keywords: &[&str]| {
+            reasons.iter().any(|reason| {
+                let reason = reason.as_ref().trim();
+                let reason = reason
+                    .strip_suffix("-error")
+                    .or_else(|| reason.strip_suffix("-warning"))
+                    .or_else(|| reason.strip_suffix("-report"))
+                    .unwrap_or(reason);
+                keywords.contains(&reason)
+            })
+        };
+
+        if has_reason(&["door-open", "cover-open", "interlock-open"]) {
+            PrinterState::DoorOpen
+        } else if has_reason(&["media-jam"]) {
+            PrinterState::PaperJam
+        } else if has_reason(&["media-empty", "media-needed"]) {
+            PrinterState::PaperOut
+        } else if has_reason(&["toner-empty", "marker-supply-empty"]) {
+            PrinterState::NoToner
+        } else if has_reason(&["offline", "shutdown"]) {
+            PrinterState::Offline
+        } else if has_reason(&["toner-low", "marker-supply-low"]) {
+            PrinterState::TonerLow
+        } else if has_reason(&["paused"]) {
+            PrinterState::Paused
+        } else if has_reason(&["connecting-to-device", "processing-to-stop-point"]) {
+            PrinterState::Processing
+        } else {
+            match printer_state {
+                3 => PrinterState::None,
+                4 => PrinterState::Printing,
+                5 => PrinterState::Paused,
+                _ => PrinterState::StatusUnknown,
+            }
+        }
+    }
+
     /// Returns a human-readable description of this printer state.
     ///
     /// # Returns
@@ -241,6 +342,37 @@ impl PrinterState {
         }
     }
 
+    /// Joins the descriptions of every flag set in a WMI PrinterState value with `" | "`.
+    ///
+    /// Unlike [`PrinterState::description`], which names only the most significant
+    /// flag, this lists all of them in bit order. Returns `"None"` for 0 and
+    /// `"Status Unknown"` when only unrecognized bits are set.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::PrinterState;
+    ///
+    /// assert_eq!(PrinterState::flags_display(1024 | 131072), "Printing | Toner Low");
+    /// assert_eq!(PrinterState::flags_display(0), "None");
+    /// ```
+    pub fn flags_display(state_code: u32) -> String {
+        if state_code == 0 {
+            return PrinterState::None.description().to_string();
+        }
+
+        let flags: Vec<&str> = STATE_FLAGS
+            .iter()
+            .filter(|(bit, _)| state_code & bit != 0)
+            .map(|(_, state)| state.description())
+            .collect();
+
+        if flags.is_empty() {
+            PrinterState::StatusUnknown.description().to_string()
+        } else {
+            flags.join(" | ")
+        }
+    }
+
     /// Converts PrinterState to equivalent PrinterStatus when possible
     ///
     /// # Returns
@@ -275,6 +407,46 @@ impl PrinterState {
         )
     }
 
+    /// Converts this state to the equivalent [`ErrorState`] when one exists.
+    ///
+    /// | PrinterState               | ErrorState         |
+    /// |----------------------------|--------------------|
+    /// | `None`                     | `NoError`          |
+    /// | `Error`                    | `Other`            |
+    /// | `PaperJam`                 | `Jammed`           |
+    /// | `PaperOut`                 | `NoPaper`          |
+    /// | `DoorOpen`                 | `DoorOpen`         |
+    /// | `TonerLow`                 | `LowToner`         |
+    /// | `NoToner`                  | `NoToner`          |
+    /// | `OutputBinFull`            | `OutputBinFull`    |
+    /// | `UserInterventionRequired` | `ServiceRequested` |
+    ///
+    /// Every other state (activity such as `Printing`, connectivity such as
+    /// `Offline`, and problems without an error-state counterpart such as
+    /// `PaperProblem` or `OutOfMemory`) returns `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, PrinterState};
+    ///
+    /// assert_eq!(PrinterState::PaperJam.to_error_state(), Some(ErrorState::Jammed));
+    /// assert_eq!(PrinterState::Printing.to_error_state(), None);
+    /// ```
+    pub fn to_error_state(&self) -> Option<ErrorState> {
+        match self {
+            PrinterState::None => Some(ErrorState::NoError),
+            PrinterState::Error => Some(ErrorState::Other),
+            PrinterState::PaperJam => Some(ErrorState::Jammed),
+            PrinterState::PaperOut => Some(ErrorState::NoPaper),
+            PrinterState::DoorOpen => Some(ErrorState::DoorOpen),
+            PrinterState::TonerLow => Some(ErrorState::LowToner),
+            PrinterState::NoToner => Some(ErrorState::NoToner),
+            PrinterState::OutputBinFull => Some(ErrorState::OutputBinFull),
+            PrinterState::UserInterventionRequired => Some(ErrorState::ServiceRequested),
+            _ => None,
+        }
+    }
+
     /// Checks if this status represents an offline condition
     pub fn is_offline(&self) -> bool {
         matches!(
@@ -284,6 +456,70 @@ impl PrinterState {
     }
 }
 
+/// Translations of status descriptions, keyed by enum variant name.
+///
+/// Used with `describe_with` on [`PrinterStatus`], [`PrinterState`] and
+/// [`ErrorState`]. Keys are the variant names as written in Rust (e.g.
+/// `"PaperJam"`, `"NoError"`); a name shared by several enums, such as
+/// `"Offline"`, is translated the same way for all of them. Variants without a
+/// translation fall back to the English `description()`.
+///
+/// # Example
+/// ```
+/// use printer_event_handler::{ErrorState, Locale, PrinterState};
+/// use std::collections::HashMap;
+///
+/// let german = Locale::new(HashMap::from([("PaperJam", "Papierstau".to_string())]));
+/// assert_eq!(PrinterState::PaperJam.describe_with(&german), "Papierstau");
+/// assert_eq!(ErrorState::NoPaper.describe_with(&german), "No Paper");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Locale {
+    translations: std::collections::HashMap<&'static str, String>,
+}
+
+impl Locale {
+    /// Creates a locale from a translation table keyed by variant name
+    pub fn new(translations: std::collections::HashMap<&'static str, String>) -> Self {
+        Self { translations }
+    }
+
+    /// Returns this locale with a translation for `variant` added or replaced
+    pub fn with_translation(mut self, variant: &'static str, text: impl Into<String>) -> Self {
+        self.translations.insert(variant, text.into());
+        self
+    }
+
+    /// Returns the translation for `variant`, if the locale has one
+    pub fn translate(&self, variant: &str) -> Option<&str> {
+        self.translations.get(variant).map(String::as_str)
+    }
+
+    /// Looks up the variant name of `value`, falling back to `english`
+    fn describe<'a>(&'a self, value: &impl std::fmt::Debug, english: &'static str) -> &'a str {
+        if self.translations.is_empty() {
+            return english;
+        }
+        self.translate(&format!("{:?}", value)).unwrap_or(english)
+    }
+}
+
+impl PrinterStatus {
+    /// Returns the description of this status in `locale`, or the English
+    /// [`Self::description`] when it has no translation
+    pub fn describe_with<'a>(&self, locale: &'a Locale) -> &'a str {
+        locale.describe(self, self.description())
+    }
+}
+
+impl PrinterState {
+    /// Returns the description of this state in `locale`, or the English
+    /// [`Self::description`] when it has no translation
+    pub fn describe_with<'a>(&self, locale: &'a Locale) -> &'a str {
+        locale.describe(self, self.description())
+    }
+}
+
 impl std::fmt::Display for PrinterStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.description())
@@ -297,7 +533,8 @@ impl std::fmt::Display for PrinterState {
 }
 
 /// Represents a printer's error state
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ErrorState {
     NoError,
     Other,
@@ -317,23 +554,26 @@ impl ErrorState {
     ///
     /// # Arguments
     /// * `error` - Optional WMI detected error state code
+    /// * `treat_zero_as_no_error` - Map the documented "Unknown" value 0 to `NoError`
     ///
     /// # Returns
     /// Corresponding ErrorState enum variant
-    #[cfg(windows)]
-    pub(crate) fn from_u32(error: Option<u32>) -> Self {
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub(crate) fn from_u32(error: Option<u32>, treat_zero_as_no_error: bool) -> Self {
         match error {
             // Note: In practice, many printers report 0 when working normally,
-            // despite documentation saying 0=Unknown. We map 0 to NoError for better UX.
-            Some(0) => ErrorState::NoError, // Unknown (but often means no error in practice)
-            Some(1) => ErrorState::Other,   // Other
-            Some(2) => ErrorState::NoError, // No Error
-            Some(3) => ErrorState::LowPaper, // Low Paper
-            Some(4) => ErrorState::NoPaper, // No Paper
-            Some(5) => ErrorState::LowToner, // Low Toner
-            Some(6) => ErrorState::NoToner, // No Toner
-            Some(7) => ErrorState::DoorOpen, // Door Open
-            Some(8) => ErrorState::Jammed,  // Jammed
+            // despite documentation saying 0=Unknown. We map 0 to NoError for better UX
+            // unless the strict interpretation is requested.
+            Some(0) if treat_zero_as_no_error => ErrorState::NoError,
+            Some(0) => ErrorState::UnknownError,      // Unknown
+            Some(1) => ErrorState::Other,             // Other
+            Some(2) => ErrorState::NoError,           // No Error
+            Some(3) => ErrorState::LowPaper,          // Low Paper
+            Some(4) => ErrorState::NoPaper,           // No Paper
+            Some(5) => ErrorState::LowToner,          // Low Toner
+            Some(6) => ErrorState::NoToner,           // No Toner
+            Some(7) => ErrorState::DoorOpen,          // Door Open
+            Some(8) => ErrorState::Jammed,            // Jammed
             Some(9) => ErrorState::Other, // Offline (map to Other since we have separate offline status)
             Some(10) => ErrorState::ServiceRequested, // Service Requested
             Some(11) => ErrorState::OutputBinFull, // Output Bin Full
@@ -369,6 +609,12 @@ impl ErrorState {
         }
     }
 
+    /// Returns the description of this error state in `locale`, or the English
+    /// [`Self::description`] when it has no translation
+    pub fn describe_with<'a>(&self, locale: &'a Locale) -> &'a str {
+        locale.describe(self, self.description())
+    }
+
     /// Determines whether this error state represents an actual error condition.
     ///
     /// # Returns
@@ -384,6 +630,113 @@ impl ErrorState {
     pub fn is_error(&self) -> bool {
         !matches!(self, ErrorState::NoError)
     }
+
+    /// Returns how urgently this error state needs attention.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Severity};
+    ///
+    /// assert_eq!(ErrorState::LowToner.severity(), Severity::Warning);
+    /// assert!(ErrorState::Jammed.severity() > ErrorState::LowPaper.severity());
+    /// ```
+    pub fn severity(&self) -> Severity {
+        match self {
+            ErrorState::NoError => Severity::Info,
+            ErrorState::LowPaper
+            | ErrorState::LowToner
+            | ErrorState::Other
+            | ErrorState::UnknownError => Severity::Warning,
+            ErrorState::NoPaper
+            | ErrorState::NoToner
+            | ErrorState::DoorOpen
+            | ErrorState::Jammed
+            | ErrorState::OutputBinFull => Severity::Error,
+            ErrorState::ServiceRequested => Severity::Critical,
+        }
+    }
+
+    /// Converts this error state to the equivalent [`PrinterState`] when one exists.
+    ///
+    /// The inverse of [`PrinterState::to_error_state`]. `LowPaper` and
+    /// `UnknownError` have no PrinterState flag and return `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, PrinterState};
+    ///
+    /// assert_eq!(ErrorState::NoPaper.to_printer_state(), Some(PrinterState::PaperOut));
+    /// assert_eq!(ErrorState::LowPaper.to_printer_state(), None);
+    /// ```
+    pub fn to_printer_state(&self) -> Option<PrinterState> {
+        match self {
+            ErrorState::NoError => Some(PrinterState::None),
+            ErrorState::Other => Some(PrinterState::Error),
+            ErrorState::Jammed => Some(PrinterState::PaperJam),
+            ErrorState::NoPaper => Some(PrinterState::PaperOut),
+            ErrorState::DoorOpen => Some(PrinterState::DoorOpen),
+            ErrorState::LowToner => Some(PrinterState::TonerLow),
+            ErrorState::NoToner => Some(PrinterState::NoToner),
+            ErrorState::OutputBinFull => Some(PrinterState::OutputBinFull),
+            ErrorState::ServiceRequested => Some(PrinterState::UserInterventionRequired),
+            ErrorState::LowPaper | ErrorState::UnknownError => None,
+        }
+    }
+
+    /// Returns end-user guidance for resolving this error state.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::ErrorState;
+    ///
+    /// assert_eq!(ErrorState::NoPaper.recommended_action(), "Load paper into the tray");
+    /// ```
+    pub fn recommended_action(&self) -> &'static str {
+        match self {
+            ErrorState::NoError => "No action needed",
+            ErrorState::Other => "Check the printer's display for details",
+            ErrorState::LowPaper => "Add paper to the tray soon",
+            ErrorState::NoPaper => "Load paper into the tray",
+            ErrorState::LowToner => "Order a replacement toner cartridge",
+            ErrorState::NoToner => "Replace the toner cartridge",
+            ErrorState::DoorOpen => "Close all printer doors and covers",
+            ErrorState::Jammed => "Clear the paper jam",
+            ErrorState::ServiceRequested => "Contact a service technician",
+            ErrorState::OutputBinFull => "Empty the output tray",
+            ErrorState::UnknownError => "Restart the printer and check its connection",
+        }
+    }
+}
+
+/// How urgently an [`ErrorState`] needs attention, from least to most urgent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    /// Normal operation
+    Info,
+    /// Printing still works but attention is needed soon
+    Warning,
+    /// Printing is blocked until a user fixes the problem
+    Error,
+    /// Printing is blocked and needs a technician
+    Critical,
+}
+
+impl Severity {
+    /// Returns a human-readable description of the severity
+    pub fn description(&self) -> &'static str {
+        match self {
+            Severity::Info => "Info",
+            Severity::Warning => "Warning",
+            Severity::Error => "Error",
+            Severity::Critical => "Critical",
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description())
+    }
 }
 
 impl std::fmt::Display for ErrorState {
@@ -392,8 +745,380 @@ impl std::fmt::Display for ErrorState {
     }
 }
 
+/// The color mode currently selected in a printer's settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ColorMode {
+    /// Prints in color
+    Color,
+    /// Prints in grayscale or black and white
+    Monochrome,
+    /// The device picks color or monochrome per document
+    Auto,
+}
+
+impl ColorMode {
+    /// Parses a CUPS/IPP `print-color-mode` (or legacy PPD `ColorModel`) value.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::ColorMode;
+    ///
+    /// assert_eq!(ColorMode::from_cups("monochrome"), Some(ColorMode::Monochrome));
+    /// assert_eq!(ColorMode::from_cups("RGB"), Some(ColorMode::Color));
+    /// assert_eq!(ColorMode::from_cups("sepia"), None);
+    /// ```
+    pub fn from_cups(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "color" | "rgb" | "cmyk" | "cmy" => Some(ColorMode::Color),
+            "monochrome" | "process-monochrome" | "auto-monochrome" | "bi-level"
+            | "process-bi-level" | "gray" | "grayscale" | "kgray" | "black" => {
+                Some(ColorMode::Monochrome)
+            }
+            "auto" => Some(ColorMode::Auto),
+            _ => None,
+        }
+    }
+
+    /// Converts the WMI `Win32_PrinterConfiguration.Color` value (1 = monochrome, 2 = color)
+    pub fn from_wmi(color: u32) -> Option<Self> {
+        match color {
+            1 => Some(ColorMode::Monochrome),
+            2 => Some(ColorMode::Color),
+            _ => None,
+        }
+    }
+
+    /// Returns a human-readable description of the color mode
+    pub fn description(&self) -> &'static str {
+        match self {
+            ColorMode::Color => "Color",
+            ColorMode::Monochrome => "Monochrome",
+            ColorMode::Auto => "Auto",
+        }
+    }
+}
+
+impl std::fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+/// The duplex (two-sided printing) setting currently selected for a printer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DuplexMode {
+    /// Prints on one side of the sheet
+    Simplex,
+    /// Prints on both sides, flipping along the long edge (portrait binding)
+    DuplexLongEdge,
+    /// Prints on both sides, flipping along the short edge (landscape binding)
+    DuplexShortEdge,
+}
+
+impl DuplexMode {
+    /// Parses a CUPS/IPP `sides` value (or legacy PPD `Duplex` choice).
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::DuplexMode;
+    ///
+    /// assert_eq!(DuplexMode::from_cups("two-sided-long-edge"), Some(DuplexMode::DuplexLongEdge));
+    /// assert_eq!(DuplexMode::from_cups("DuplexTumble"), Some(DuplexMode::DuplexShortEdge));
+    /// assert_eq!(DuplexMode::from_cups("booklet"), None);
+    /// ```
+    pub fn from_cups(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "one-sided" | "none" | "simplex" => Some(DuplexMode::Simplex),
+            "two-sided-long-edge" | "duplexnotumble" => Some(DuplexMode::DuplexLongEdge),
+            "two-sided-short-edge" | "duplextumble" => Some(DuplexMode::DuplexShortEdge),
+            _ => None,
+        }
+    }
+
+    /// Converts the WMI `Win32_PrinterConfiguration.Duplex` flag.
+    ///
+    /// WMI only reports whether duplex is on, not the binding edge, so an enabled
+    /// flag is reported as the default long-edge binding.
+    pub fn from_wmi(duplex: bool) -> Self {
+        if duplex {
+            DuplexMode::DuplexLongEdge
+        } else {
+            DuplexMode::Simplex
+        }
+    }
+
+    /// Checks whether both sides of the sheet are printed
+    pub fn is_duplex(&self) -> bool {
+        !matches!(self, DuplexMode::Simplex)
+    }
+
+    /// Returns a human-readable description of the duplex mode
+    pub fn description(&self) -> &'static str {
+        match self {
+            DuplexMode::Simplex => "Simplex",
+            DuplexMode::DuplexLongEdge => "Duplex (long edge)",
+            DuplexMode::DuplexShortEdge => "Duplex (short edge)",
+        }
+    }
+}
+
+impl std::fmt::Display for DuplexMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+/// Why a print queue is paused, as reported by [`Printer::pause_reason`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PauseReason {
+    /// Paused on purpose while the printer reports no problem; resuming is enough
+    Administrative,
+    /// Held because of a blocking error such as a jam, no paper or an open door
+    ErrorHold,
+    /// The printer reports an unspecified or unknown problem, so the cause is unclear
+    Unknown,
+}
+
+impl PauseReason {
+    /// Returns a human-readable description of the pause reason
+    pub fn description(&self) -> &'static str {
+        match self {
+            PauseReason::Administrative => "Paused by an administrator",
+            PauseReason::ErrorHold => "Held because of a printer error",
+            PauseReason::Unknown => "Paused for an unknown reason",
+        }
+    }
+}
+
+impl std::fmt::Display for PauseReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+/// The CIM `Status` property reported by WMI for a printer
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WmiOperationalStatus {
+    /// `OK` - Operating normally
+    Ok,
+    /// `Error` - Failed
+    Error,
+    /// `Degraded` - Operating with reduced functionality
+    Degraded,
+    /// `Pred Fail` - Predicted to fail soon
+    PredFail,
+    /// `Starting` - Coming online
+    Starting,
+    /// `Stopping` - Going offline
+    Stopping,
+    /// `Service` - Undergoing maintenance
+    Service,
+    /// `Stressed` - Overloaded
+    Stressed,
+    /// `NonRecover` - Failed and cannot recover
+    NonRecover,
+    /// `No Contact` - The system cannot reach the device
+    NoContact,
+    /// `Lost Comm` - Communication with the device was lost
+    LostComm,
+    /// `Unknown`, or any other value not defined by CIM, as reported
+    Unknown(String),
+}
+
+impl WmiOperationalStatus {
+    /// Returns the CIM string for this status
+    pub fn as_str(&self) -> &str {
+        match self {
+            WmiOperationalStatus::Ok => "OK",
+            WmiOperationalStatus::Error => "Error",
+            WmiOperationalStatus::Degraded => "Degraded",
+            WmiOperationalStatus::PredFail => "Pred Fail",
+            WmiOperationalStatus::Starting => "Starting",
+            WmiOperationalStatus::Stopping => "Stopping",
+            WmiOperationalStatus::Service => "Service",
+            WmiOperationalStatus::Stressed => "Stressed",
+            WmiOperationalStatus::NonRecover => "NonRecover",
+            WmiOperationalStatus::NoContact => "No Contact",
+            WmiOperationalStatus::LostComm => "Lost Comm",
+            WmiOperationalStatus::Unknown(value) => value,
+        }
+    }
+
+    /// Whether the status indicates the printer is failing or unreachable
+    pub fn is_problem(&self) -> bool {
+        matches!(
+            self,
+            WmiOperationalStatus::Degraded
+                | WmiOperationalStatus::Error
+                | WmiOperationalStatus::NoContact
+                | WmiOperationalStatus::LostComm
+                | WmiOperationalStatus::NonRecover
+        )
+    }
+}
+
+impl std::str::FromStr for WmiOperationalStatus {
+    type Err = std::convert::Infallible;
+
+    /// Parses a CIM `Status` string; `Unknown` and values not defined by CIM become
+    /// [`WmiOperationalStatus::Unknown`].
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::WmiOperationalStatus;
+    ///
+    /// assert_eq!("Lost Comm".parse(), Ok(WmiOperationalStatus::LostComm));
+    /// assert_eq!(
+    ///     "Busy".parse(),
+    ///     Ok(WmiOperationalStatus::Unknown("Busy".to_string()))
+    /// );
+    /// ```
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match value.trim() {
+            "OK" => WmiOperationalStatus::Ok,
+            "Error" => WmiOperationalStatus::Error,
+            "Degraded" => WmiOperationalStatus::Degraded,
+            "Pred Fail" => WmiOperationalStatus::PredFail,
+            "Starting" => WmiOperationalStatus::Starting,
+            "Stopping" => WmiOperationalStatus::Stopping,
+            "Service" => WmiOperationalStatus::Service,
+            "Stressed" => WmiOperationalStatus::Stressed,
+            "NonRecover" => WmiOperationalStatus::NonRecover,
+            "No Contact" => WmiOperationalStatus::NoContact,
+            "Lost Comm" => WmiOperationalStatus::LostComm,
+            other => WmiOperationalStatus::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl std::fmt::Display for WmiOperationalStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Decoded `Win32_Printer.Attributes` bitfield (`PRINTER_ATTRIBUTE_*` flags).
+///
+/// Every flag is `false` when the platform does not report attributes (Linux).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PrinterAttributes {
+    /// The raw bitfield
+    pub raw: u32,
+    /// `0x1` - Jobs are fully spooled before printing starts
+    pub queued: bool,
+    /// `0x2` - Jobs go directly to the printer without spooling
+    pub direct: bool,
+    /// `0x4` - The printer is the default printer
+    pub default: bool,
+    /// `0x8` - The printer is shared on the network
+    pub shared: bool,
+    /// `0x10` - The printer is a network printer connection
+    pub network: bool,
+    /// `0x20` - The printer is hidden from users
+    pub hidden: bool,
+    /// `0x40` - The printer is attached locally
+    pub local: bool,
+    /// `0x80` - Mismatched jobs are held in the queue instead of printed
+    pub enable_devq: bool,
+    /// `0x100` - Jobs are kept in the queue after printing
+    pub keep_printed_jobs: bool,
+    /// `0x200` - Fully spooled jobs print before partially spooled ones
+    pub do_complete_first: bool,
+    /// `0x400` - The printer is set to work offline
+    pub work_offline: bool,
+    /// `0x800` - Bidirectional communication is enabled
+    pub enable_bidi: bool,
+    /// `0x1000` - Only raw data type jobs can be spooled
+    pub raw_only: bool,
+    /// `0x2000` - The printer is published in Active Directory
+    pub published: bool,
+    /// `0x4000` - The printer is a fax printer
+    pub fax: bool,
+    /// `0x8000` - The printer is redirected from a Terminal Services/RDS client session
+    pub terminal_server: bool,
+}
+
+impl PrinterAttributes {
+    /// Decodes a raw `Attributes` value
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::PrinterAttributes;
+    ///
+    /// let attributes = PrinterAttributes::from_bits(0x0248);
+    /// assert!(attributes.shared && attributes.local && attributes.do_complete_first);
+    /// assert!(!attributes.network);
+    /// ```
+    pub fn from_bits(raw: u32) -> Self {
+        let flag = |bit: u32| raw & bit != 0;
+        Self {
+            raw,
+            queued: flag(0x1),
+            direct: flag(0x2),
+            default: flag(0x4),
+            shared: flag(0x8),
+            network: flag(0x10),
+            hidden: flag(0x20),
+            local: flag(0x40),
+            enable_devq: flag(0x80),
+            keep_printed_jobs: flag(0x100),
+            do_complete_first: flag(0x200),
+            work_offline: flag(0x400),
+            enable_bidi: flag(0x800),
+            raw_only: flag(0x1000),
+            published: flag(0x2000),
+            fax: flag(0x4000),
+            terminal_server: flag(0x8000),
+        }
+    }
+}
+
+/// Paper sizes a printer supports
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaInfo {
+    /// Names of all supported paper sizes as reported by the platform (e.g. `A4`, `Letter`)
+    pub supported: Vec<String>,
+    /// The paper size selected by default, if known
+    pub default: Option<String>,
+}
+
+/// A printer driver installed on the system, as reported by
+/// [`crate::PrinterMonitor::list_drivers`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrinterDriver {
+    /// The driver (or printer model) name, e.g. `HP Universal Printing PCL 6`
+    pub name: String,
+    /// The driver version, if reported: the driver model version (e.g. `3`) on
+    /// Windows, the version in the model description (e.g. `5.3.3`) on Linux
+    pub version: Option<String>,
+    /// Where the driver runs or comes from: the platform (e.g. `Windows x64`) on
+    /// Windows, the CUPS driver URI (e.g. `drv:///sample.drv/generic.ppd`) on Linux
+    pub environment: Option<String>,
+}
+
+/// A CUPS printer class: a named group of printers that share jobs, as
+/// reported by [`crate::PrinterMonitor::list_classes`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrinterClass {
+    /// The class name, usable as a print destination like a printer name
+    pub name: String,
+    /// Names of the member printers, in the order CUPS lists them
+    pub members: Vec<String>,
+}
+
+/// Names of well-known virtual printers recognized by [`Printer::is_virtual`]
+pub const VIRTUAL_PRINTER_NAMES: &[&str] = &[
+    "Microsoft Print to PDF",
+    "Microsoft XPS Document Writer",
+    "OneNote",
+    "CUPS-PDF",
+];
+
 /// Represents a change in a specific printer property
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum PropertyChange {
     Name {
         old: String,
@@ -419,6 +1144,10 @@ pub enum PropertyChange {
         old: bool,
         new: bool,
     },
+    IsPublished {
+        old: bool,
+        new: bool,
+    },
     PrinterStatusCode {
         old: Option<u32>,
         new: Option<u32>,
@@ -443,6 +1172,22 @@ pub enum PropertyChange {
         old: Option<String>,
         new: Option<String>,
     },
+    Location {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    Comment {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    Priority {
+        old: Option<u32>,
+        new: Option<u32>,
+    },
+    JobsSinceReset {
+        old: Option<u32>,
+        new: Option<u32>,
+    },
 }
 
 impl PropertyChange {
@@ -455,6 +1200,7 @@ impl PropertyChange {
             PropertyChange::ErrorState { .. } => "ErrorState",
             PropertyChange::IsOffline { .. } => "IsOffline",
             PropertyChange::IsDefault { .. } => "IsDefault",
+            PropertyChange::IsPublished { .. } => "IsPublished",
             PropertyChange::PrinterStatusCode { .. } => "PrinterStatusCode",
             PropertyChange::PrinterStateCode { .. } => "PrinterStateCode",
             PropertyChange::DetectedErrorStateCode { .. } => "DetectedErrorStateCode",
@@ -463,48 +1209,360 @@ impl PropertyChange {
             }
             PropertyChange::ExtendedPrinterStatusCode { .. } => "ExtendedPrinterStatusCode",
             PropertyChange::WmiStatus { .. } => "WmiStatus",
+            PropertyChange::Location { .. } => "Location",
+            PropertyChange::Comment { .. } => "Comment",
+            PropertyChange::Priority { .. } => "Priority",
+            PropertyChange::JobsSinceReset { .. } => "JobsSinceReset",
         }
     }
 
     /// Returns a human-readable description of the change
     pub fn description(&self) -> String {
+        let (old, new) = self.display_values();
+        format!("{}: {} → {}", self.property_name(), old, new)
+    }
+
+    /// Returns [`Self::description`] with the old value in red and the new one in green.
+    ///
+    /// Meant for terminal output. Follows the `NO_COLOR` convention: when the
+    /// environment variable is set to a non-empty value, the plain description is
+    /// returned instead.
+    #[cfg(feature = "color")]
+    pub fn colored_description(&self) -> String {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        self.colored_description_with(!no_color)
+    }
+
+    /// Formats the description with or without ANSI colors
+    #[cfg(feature = "color")]
+    fn colored_description_with(&self, color: bool) -> String {
+        use owo_colors::OwoColorize;
+
+        if !color {
+            return self.description();
+        }
+        let (old, new) = self.display_values();
+        format!("{}: {} → {}", self.property_name(), old.red(), new.green())
+    }
+
+    /// Returns the old and new values formatted as in [`Self::description`]
+    pub fn display_values(&self) -> (String, String) {
         match self {
-            PropertyChange::Name { old, new } => format!("Name: '{}' → '{}'", old, new),
+            PropertyChange::Name { old, new } => (format!("'{}'", old), format!("'{}'", new)),
             PropertyChange::Status { old, new } => {
-                format!("Status: {} → {}", old.description(), new.description())
+                (old.description().to_string(), new.description().to_string())
             }
             PropertyChange::State { old, new } => {
                 let old_desc = old.as_ref().map(|s| s.description()).unwrap_or("None");
                 let new_desc = new.as_ref().map(|s| s.description()).unwrap_or("None");
-                format!("State: {} → {}", old_desc, new_desc)
+                (old_desc.to_string(), new_desc.to_string())
             }
             PropertyChange::ErrorState { old, new } => {
-                format!("ErrorState: {} → {}", old.description(), new.description())
+                (old.description().to_string(), new.description().to_string())
             }
-            PropertyChange::IsOffline { old, new } => format!("IsOffline: {} → {}", old, new),
-            PropertyChange::IsDefault { old, new } => format!("IsDefault: {} → {}", old, new),
-            PropertyChange::PrinterStatusCode { old, new } => {
-                format!("PrinterStatusCode: {:?} → {:?}", old, new)
+            PropertyChange::IsOffline { old, new }
+            | PropertyChange::IsDefault { old, new }
+            | PropertyChange::IsPublished { old, new } => (old.to_string(), new.to_string()),
+            PropertyChange::PrinterStatusCode { old, new }
+            | PropertyChange::PrinterStateCode { old, new }
+            | PropertyChange::DetectedErrorStateCode { old, new }
+            | PropertyChange::ExtendedDetectedErrorStateCode { old, new }
+            | PropertyChange::ExtendedPrinterStatusCode { old, new }
+            | PropertyChange::Priority { old, new }
+            | PropertyChange::JobsSinceReset { old, new } => {
+                (format!("{:?}", old), format!("{:?}", new))
             }
-            PropertyChange::PrinterStateCode { old, new } => {
-                format!("PrinterStateCode: {:?} → {:?}", old, new)
+            PropertyChange::WmiStatus { old, new }
+            | PropertyChange::Location { old, new }
+            | PropertyChange::Comment { old, new } => (format!("{:?}", old), format!("{:?}", new)),
+        }
+    }
+
+    /// Combines this change with a later change of the same property into one net change.
+    ///
+    /// Keeps this change's `old` value and the later change's `new` value. Returns
+    /// `None` if `later` is for a different property.
+    pub fn combine(&self, later: &PropertyChange) -> Option<PropertyChange> {
+        let combined = match (self, later) {
+            (PropertyChange::Name { old, .. }, PropertyChange::Name { new, .. }) => {
+                PropertyChange::Name {
+                    old: old.clone(),
+                    new: new.clone(),
+                }
+            }
+            (PropertyChange::Status { old, .. }, PropertyChange::Status { new, .. }) => {
+                PropertyChange::Status {
+                    old: old.clone(),
+                    new: new.clone(),
+                }
+            }
+            (PropertyChange::State { old, .. }, PropertyChange::State { new, .. }) => {
+                PropertyChange::State {
+                    old: old.clone(),
+                    new: new.clone(),
+                }
+            }
+            (PropertyChange::ErrorState { old, .. }, PropertyChange::ErrorState { new, .. }) => {
+                PropertyChange::ErrorState {
+                    old: old.clone(),
+                    new: new.clone(),
+                }
+            }
+            (PropertyChange::IsOffline { old, .. }, PropertyChange::IsOffline { new, .. }) => {
+                PropertyChange::IsOffline {
+                    old: *old,
+                    new: *new,
+                }
+            }
+            (PropertyChange::IsDefault { old, .. }, PropertyChange::IsDefault { new, .. }) => {
+                PropertyChange::IsDefault {
+                    old: *old,
+                    new: *new,
+                }
+            }
+            (PropertyChange::IsPublished { old, .. }, PropertyChange::IsPublished { new, .. }) => {
+                PropertyChange::IsPublished {
+                    old: *old,
+                    new: *new,
+                }
+            }
+            (
+                PropertyChange::PrinterStatusCode { old, .. },
+                PropertyChange::PrinterStatusCode { new, .. },
+            ) => PropertyChange::PrinterStatusCode {
+                old: *old,
+                new: *new,
+            },
+            (
+                PropertyChange::PrinterStateCode { old, .. },
+                PropertyChange::PrinterStateCode { new, .. },
+            ) => PropertyChange::PrinterStateCode {
+                old: *old,
+                new: *new,
+            },
+            (
+                PropertyChange::DetectedErrorStateCode { old, .. },
+                PropertyChange::DetectedErrorStateCode { new, .. },
+            ) => PropertyChange::DetectedErrorStateCode {
+                old: *old,
+                new: *new,
+            },
+            (
+                PropertyChange::ExtendedDetectedErrorStateCode { old, .. },
+                PropertyChange::ExtendedDetectedErrorStateCode { new, .. },
+            ) => PropertyChange::ExtendedDetectedErrorStateCode {
+                old: *old,
+                new: *new,
+            },
+            (
+                PropertyChange::ExtendedPrinterStatusCode { old, .. },
+                PropertyChange::ExtendedPrinterStatusCode { new, .. },
+            ) => PropertyChange::ExtendedPrinterStatusCode {
+                old: *old,
+                new: *new,
+            },
+            (PropertyChange::WmiStatus { old, .. }, PropertyChange::WmiStatus { new, .. }) => {
+                PropertyChange::WmiStatus {
+                    old: old.clone(),
+                    new: new.clone(),
+                }
+            }
+            (PropertyChange::Location { old, .. }, PropertyChange::Location { new, .. }) => {
+                PropertyChange::Location {
+                    old: old.clone(),
+                    new: new.clone(),
+                }
+            }
+            (PropertyChange::Comment { old, .. }, PropertyChange::Comment { new, .. }) => {
+                PropertyChange::Comment {
+                    old: old.clone(),
+                    new: new.clone(),
+                }
+            }
+            (PropertyChange::Priority { old, .. }, PropertyChange::Priority { new, .. }) => {
+                PropertyChange::Priority {
+                    old: *old,
+                    new: *new,
+                }
             }
-            PropertyChange::DetectedErrorStateCode { old, new } => {
-                format!("DetectedErrorStateCode: {:?} → {:?}", old, new)
+            (
+                PropertyChange::JobsSinceReset { old, .. },
+                PropertyChange::JobsSinceReset { new, .. },
+            ) => PropertyChange::JobsSinceReset {
+                old: *old,
+                new: *new,
+            },
+            _ => return None,
+        };
+        Some(combined)
+    }
+
+    /// Checks whether the old and new values are equal, i.e. there is no net change
+    pub fn is_noop(&self) -> bool {
+        match self {
+            PropertyChange::Name { old, new } => old == new,
+            PropertyChange::Status { old, new } => old == new,
+            PropertyChange::State { old, new } => old == new,
+            PropertyChange::ErrorState { old, new } => old == new,
+            PropertyChange::IsOffline { old, new } => old == new,
+            PropertyChange::IsDefault { old, new } => old == new,
+            PropertyChange::IsPublished { old, new } => old == new,
+            PropertyChange::PrinterStatusCode { old, new } => old == new,
+            PropertyChange::PrinterStateCode { old, new } => old == new,
+            PropertyChange::DetectedErrorStateCode { old, new } => old == new,
+            PropertyChange::ExtendedDetectedErrorStateCode { old, new } => old == new,
+            PropertyChange::ExtendedPrinterStatusCode { old, new } => old == new,
+            PropertyChange::WmiStatus { old, new } => old == new,
+            PropertyChange::Location { old, new } => old == new,
+            PropertyChange::Comment { old, new } => old == new,
+            PropertyChange::Priority { old, new } => old == new,
+            PropertyChange::JobsSinceReset { old, new } => old == new,
+        }
+    }
+
+    /// Returns the old and new values as strings, `None` where the value is absent.
+    ///
+    /// Enum values use their variant names (matching their serde representation).
+    pub fn values_as_strings(&self) -> (Option<String>, Option<String>) {
+        fn text<T: std::fmt::Display>(value: &Option<T>) -> Option<String> {
+            value.as_ref().map(|v| v.to_string())
+        }
+
+        match self {
+            PropertyChange::Name { old, new } => (Some(old.clone()), Some(new.clone())),
+            PropertyChange::Status { old, new } => {
+                (Some(format!("{:?}", old)), Some(format!("{:?}", new)))
             }
-            PropertyChange::ExtendedDetectedErrorStateCode { old, new } => {
-                format!("ExtendedDetectedErrorStateCode: {:?} → {:?}", old, new)
+            PropertyChange::State { old, new } => (
+                old.as_ref().map(|s| format!("{:?}", s)),
+                new.as_ref().map(|s| format!("{:?}", s)),
+            ),
+            PropertyChange::ErrorState { old, new } => {
+                (Some(format!("{:?}", old)), Some(format!("{:?}", new)))
             }
-            PropertyChange::ExtendedPrinterStatusCode { old, new } => {
-                format!("ExtendedPrinterStatusCode: {:?} → {:?}", old, new)
+            PropertyChange::IsOffline { old, new }
+            | PropertyChange::IsDefault { old, new }
+            | PropertyChange::IsPublished { old, new } => {
+                (Some(old.to_string()), Some(new.to_string()))
             }
-            PropertyChange::WmiStatus { old, new } => format!("WmiStatus: {:?} → {:?}", old, new),
+            PropertyChange::PrinterStatusCode { old, new }
+            | PropertyChange::PrinterStateCode { old, new }
+            | PropertyChange::DetectedErrorStateCode { old, new }
+            | PropertyChange::ExtendedDetectedErrorStateCode { old, new }
+            | PropertyChange::ExtendedPrinterStatusCode { old, new }
+            | PropertyChange::Priority { old, new }
+            | PropertyChange::JobsSinceReset { old, new } => (text(old), text(new)),
+            PropertyChange::WmiStatus { old, new }
+            | PropertyChange::Location { old, new }
+            | PropertyChange::Comment { old, new } => (old.clone(), new.clone()),
         }
     }
 }
 
+/// One property change as table cells, produced by [`PrinterChanges::rows`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChangeRow {
+    /// Name of the changed property (see [`PropertyChange::property_name`])
+    pub property: String,
+    /// Previous value, formatted as in [`PropertyChange::description`]
+    pub old: String,
+    /// New value, formatted as in [`PropertyChange::description`]
+    pub new: String,
+}
+
+/// A flat, serializable delta of printer property changes.
+///
+/// Maps each changed property name (see [`PropertyChange::property_name`]) to its
+/// `(old, new)` values as strings, with `null` for absent values. Created by
+/// [`PrinterChanges::to_delta`].
+///
+/// # Example
+/// ```
+/// use printer_event_handler::{PrinterChanges, PrinterStatus, PropertyChange};
+///
+/// let mut changes = PrinterChanges::new("HP".to_string());
+/// changes.changes.push(PropertyChange::Status {
+///     old: PrinterStatus::Idle,
+///     new: PrinterStatus::Printing,
+/// });
+///
+/// let delta = changes.to_delta();
+/// assert_eq!(
+///     delta.get("Status"),
+///     Some(&(Some("Idle".to_string()), Some("Printing".to_string())))
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PrinterDelta(std::collections::BTreeMap<String, (Option<String>, Option<String>)>);
+
+impl PrinterDelta {
+    /// Returns the `(old, new)` values of a property, if it changed
+    pub fn get(&self, property_name: &str) -> Option<&(Option<String>, Option<String>)> {
+        self.0.get(property_name)
+    }
+
+    /// Returns the number of changed properties
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks whether no property changed
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the changed properties in name order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &(Option<String>, Option<String>))> {
+        self.0.iter().map(|(name, values)| (name.as_str(), values))
+    }
+}
+
+/// Selects which properties [`Printer::compare_with_options`] ignores.
+///
+/// Every field defaults to `false`, so `CompareOptions::default()` tracks the
+/// same properties as [`Printer::compare_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompareOptions {
+    /// Ignore changes to the printer name
+    pub ignore_name: bool,
+    /// Ignore changes to the interpreted status
+    pub ignore_status: bool,
+    /// Ignore changes to the interpreted state
+    pub ignore_state: bool,
+    /// Ignore changes to the error state
+    pub ignore_error_state: bool,
+    /// Ignore changes to the offline flag
+    pub ignore_offline: bool,
+    /// Ignore changes to the default printer flag
+    pub ignore_default: bool,
+    /// Ignore changes to the directory-published flag
+    pub ignore_published: bool,
+    /// Ignore changes to the raw PrinterStatus code
+    pub ignore_status_code: bool,
+    /// Ignore changes to the raw PrinterState code
+    pub ignore_state_code: bool,
+    /// Ignore changes to the raw DetectedErrorState code
+    pub ignore_detected_error_code: bool,
+    /// Ignore changes to the raw ExtendedDetectedErrorState code
+    pub ignore_extended_detected_error_code: bool,
+    /// Ignore changes to the raw ExtendedPrinterStatus code
+    pub ignore_extended_status_code: bool,
+    /// Ignore changes to the WMI Status string (e.g. "OK" ↔ "Unknown" flaps)
+    pub ignore_wmi_status: bool,
+    /// Ignore changes to the location text
+    pub ignore_location: bool,
+    /// Ignore changes to the comment/description text
+    pub ignore_comment: bool,
+    /// Ignore changes to the queue priority
+    pub ignore_priority: bool,
+    /// Ignore changes to the jobs-since-reset counter
+    pub ignore_jobs_since_reset: bool,
+}
+
 /// Contains all property changes detected between two printer states
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PrinterChanges {
     /// The printer name these changes apply to
     pub printer_name: String,
@@ -517,10 +1575,15 @@ pub struct PrinterChanges {
 impl PrinterChanges {
     /// Creates a new empty PrinterChanges instance
     pub fn new(printer_name: String) -> Self {
+        Self::new_with_clock(printer_name, &SystemClock)
+    }
+
+    /// Creates a new empty PrinterChanges instance timestamped by the given clock
+    pub fn new_with_clock(printer_name: String, clock: &dyn Clock) -> Self {
         Self {
             printer_name,
             changes: Vec::new(),
-            timestamp: chrono::Utc::now(),
+            timestamp: clock.now(),
         }
     }
 
@@ -549,6 +1612,252 @@ impl PrinterChanges {
             .collect()
     }
 
+    /// Folds a later set of changes for the same printer into this one.
+    ///
+    /// Successive changes to the same property collapse into a single net change
+    /// that keeps the earliest `old` and the latest `new` value; a property that
+    /// returned to its original value drops out entirely. The timestamp moves to
+    /// `other.timestamp` if that is later.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{PrinterChanges, PrinterStatus, PropertyChange};
+    ///
+    /// let mut window = PrinterChanges::new("HP".to_string());
+    /// window.changes.push(PropertyChange::Status {
+    ///     old: PrinterStatus::Idle,
+    ///     new: PrinterStatus::Printing,
+    /// });
+    ///
+    /// let mut later = PrinterChanges::new("HP".to_string());
+    /// later.changes.push(PropertyChange::Status {
+    ///     old: PrinterStatus::Printing,
+    ///     new: PrinterStatus::Offline,
+    /// });
+    ///
+    /// window.merge(&later);
+    /// assert_eq!(
+    ///     window.changes,
+    ///     vec![PropertyChange::Status {
+    ///         old: PrinterStatus::Idle,
+    ///         new: PrinterStatus::Offline,
+    ///     }]
+    /// );
+    /// ```
+    pub fn merge(&mut self, other: &PrinterChanges) {
+        for change in &other.changes {
+            let existing = self
+                .changes
+                .iter()
+                .position(|c| c.property_name() == change.property_name());
+
+            match existing.and_then(|index| Some((index, self.changes[index].combine(change)?))) {
+                Some((index, combined)) if combined.is_noop() => {
+                    self.changes.remove(index);
+                }
+                Some((index, combined)) => self.changes[index] = combined,
+                None => self.changes.push(change.clone()),
+            }
+        }
+
+        if other.timestamp > self.timestamp {
+            self.timestamp = other.timestamp;
+        }
+    }
+
+    /// Converts the changes into a flat [`PrinterDelta`] with stringified values.
+    ///
+    /// If a property appears more than once, the changes are combined into one net change.
+    pub fn to_delta(&self) -> PrinterDelta {
+        let mut delta = std::collections::BTreeMap::new();
+        for change in &self.changes {
+            let (old, new) = change.values_as_strings();
+            delta
+                .entry(change.property_name().to_string())
+                .and_modify(|values: &mut (Option<String>, Option<String>)| values.1 = new.clone())
+                .or_insert((old, new));
+        }
+        PrinterDelta(delta)
+    }
+
+    /// Encodes the changes in the compact binary format (see [`Printer::to_bytes`]).
+    ///
+    /// Requires the `binary` feature.
+    #[cfg(feature = "binary")]
+    pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+        encode_binary(self)
+    }
+
+    /// Decodes changes written by [`Self::to_bytes`].
+    ///
+    /// Requires the `binary` feature.
+    #[cfg(feature = "binary")]
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        decode_binary(bytes)
+    }
+
+    /// Returns how many jobs were printed between the two states, if the
+    /// jobs-since-reset counter changed and both values are known.
+    ///
+    /// A counter that went down was reset in between; the jobs counted since the
+    /// reset are returned then, as earlier ones cannot be recovered.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let printer = Printer::new("HP".to_string(), PrinterStatus::Idle, ErrorState::NoError, false, false);
+    /// let before = printer.clone().with_jobs_since_reset(Some(120));
+    /// let after = printer.with_jobs_since_reset(Some(127));
+    ///
+    /// assert_eq!(before.compare_with(&after).jobs_printed(), Some(7));
+    /// ```
+    pub fn jobs_printed(&self) -> Option<u32> {
+        self.changes.iter().find_map(|change| match change {
+            PropertyChange::JobsSinceReset {
+                old: Some(old),
+                new: Some(new),
+            } => Some(new.checked_sub(*old).unwrap_or(*new)),
+            _ => None,
+        })
+    }
+
+    /// Returns one table row per property change, in detection order.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{PrinterChanges, PrinterStatus, PropertyChange};
+    ///
+    /// let mut changes = PrinterChanges::new("HP".to_string());
+    /// changes.changes.push(PropertyChange::Status {
+    ///     old: PrinterStatus::Idle,
+    ///     new: PrinterStatus::Printing,
+    /// });
+    ///
+    /// for row in changes.rows() {
+    ///     println!("{:<12} {:<12} {}", row.property, row.old, row.new);
+    /// }
+    /// ```
+    pub fn rows(&self) -> Vec<ChangeRow> {
+        self.changes
+            .iter()
+            .map(|change| {
+                let (old, new) = change.display_values();
+                ChangeRow {
+                    property: change.property_name().to_string(),
+                    old,
+                    new,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the detection time converted to the given time zone.
+    ///
+    /// The stored `timestamp` stays UTC; this
+    /// only changes how it is presented, e.g. to correlate with local logs.
+    pub fn timestamp_in<Tz: chrono::TimeZone>(&self, tz: &Tz) -> chrono::DateTime<Tz> {
+        self.timestamp.with_timezone(tz)
+    }
+
+    /// Formats the detection time in the given time zone with a `chrono` format string.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use printer_event_handler::PrinterChanges;
+    ///
+    /// let mut changes = PrinterChanges::new("HP".to_string());
+    /// changes.timestamp = Utc.with_ymd_and_hms(2025, 3, 4, 14, 3, 0).unwrap();
+    ///
+    /// let berlin_winter = FixedOffset::east_opt(3600).unwrap();
+    /// assert_eq!(
+    ///     changes.format_timestamp(&berlin_winter, "%Y-%m-%d %H:%M %:z"),
+    ///     "2025-03-04 15:03 +01:00"
+    /// );
+    /// ```
+    pub fn format_timestamp<Tz>(&self, tz: &Tz, format: &str) -> String
+    where
+        Tz: chrono::TimeZone,
+        Tz::Offset: std::fmt::Display,
+    {
+        self.timestamp_in(tz).format(format).to_string()
+    }
+
+    /// Describes the changes in one sentence, ready to send as an alert.
+    ///
+    /// Mentions the offline flag, the error state and the status, in that order,
+    /// and leaves out raw codes and other noisy properties. The status is skipped
+    /// when the printer went offline or came back online, which already says it.
+    /// Change sets without any of these properties only name what changed.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let before = Printer::new("HP".to_string(), PrinterStatus::Idle, ErrorState::NoError, false, false);
+    /// let after = Printer::new("HP".to_string(), PrinterStatus::Offline, ErrorState::Jammed, true, false);
+    ///
+    /// let message = before.compare_with(&after).alert_message();
+    /// assert!(message.starts_with("Printer 'HP' went OFFLINE and reported Jammed at "));
+    /// assert!(message.ends_with(" UTC"));
+    /// ```
+    pub fn alert_message(&self) -> String {
+        let went_offline_or_online = self
+            .changes
+            .iter()
+            .any(|change| matches!(change, PropertyChange::IsOffline { old, new } if old != new));
+
+        let mut events = Vec::new();
+        for change in &self.changes {
+            if let PropertyChange::IsOffline { new, .. } = change {
+                events.push(
+                    if *new {
+                        "went OFFLINE"
+                    } else {
+                        "came back ONLINE"
+                    }
+                    .to_string(),
+                );
+            }
+        }
+        for change in &self.changes {
+            if let PropertyChange::ErrorState { old, new } = change {
+                events.push(match new {
+                    ErrorState::NoError => format!("recovered from {}", old.description()),
+                    _ => format!("reported {}", new.description()),
+                });
+            }
+        }
+        if !went_offline_or_online {
+            for change in &self.changes {
+                if let PropertyChange::Status { new, .. } = change {
+                    events.push(format!("is now {}", new.description()));
+                }
+            }
+        }
+
+        let what = if !events.is_empty() {
+            join_with_and(&events)
+        } else if !self.changes.is_empty() {
+            let properties: Vec<String> = self
+                .changes
+                .iter()
+                .map(|change| change.property_name().to_string())
+                .collect();
+            format!("changed {}", join_with_and(&properties))
+        } else {
+            "reported no changes".to_string()
+        };
+
+        format!(
+            "Printer '{}' {} at {}",
+            self.printer_name,
+            what,
+            self.timestamp.format("%H:%M UTC")
+        )
+    }
+
     /// Returns a summary string of all changes
     pub fn summary(&self) -> String {
         if self.changes.is_empty() {
@@ -567,10 +1876,12 @@ impl PrinterChanges {
     }
 }
 
-/// WMI status codes for creating Printer instances
-#[cfg(windows)]
-#[derive(Debug)]
-pub struct WmiStatusCodes {
+/// Raw status codes for creating Printer instances.
+///
+/// The fields mirror the WMI `Win32_Printer` properties but the type is available on
+/// every platform, so mocks and tests can build printers with realistic codes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RawStatusCodes {
     pub printer_status_code: Option<u32>,
     pub printer_state_code: Option<u32>,
     pub detected_error_state_code: Option<u32>,
@@ -579,32 +1890,208 @@ pub struct WmiStatusCodes {
     pub wmi_status: Option<String>,
 }
 
+/// WMI status codes for creating Printer instances
+#[cfg(windows)]
+pub type WmiStatusCodes = RawStatusCodes;
+
 /// Internal WMI printer representation
 #[cfg(windows)]
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Default)]
 pub(crate) struct Win32Printer {
     #[serde(rename = "Name")]
     pub name: Option<String>,
-    #[serde(rename = "PrinterStatus")]
+    #[serde(rename = "PrinterStatus", default, deserialize_with = "lenient_u32")]
     pub printer_status: Option<u32>,
-    #[serde(rename = "DetectedErrorState")]
+    #[serde(
+        rename = "DetectedErrorState",
+        default,
+        deserialize_with = "lenient_u32"
+    )]
     pub detected_error_state: Option<u32>,
     #[serde(rename = "WorkOffline")]
     pub work_offline: Option<bool>,
-    #[serde(rename = "PrinterState")]
+    #[serde(rename = "PrinterState", default, deserialize_with = "lenient_u32")]
     pub printer_state: Option<u32>,
     #[serde(rename = "Default")]
     pub default: Option<bool>,
-    #[serde(rename = "ExtendedPrinterStatus")]
+    #[serde(
+        rename = "ExtendedPrinterStatus",
+        default,
+        deserialize_with = "lenient_u32"
+    )]
     pub extended_printer_status: Option<u32>,
-    #[serde(rename = "ExtendedDetectedErrorState")]
+    #[serde(
+        rename = "ExtendedDetectedErrorState",
+        default,
+        deserialize_with = "lenient_u32"
+    )]
     pub extended_detected_error_state: Option<u32>,
     #[serde(rename = "Status")]
     pub status: Option<String>,
+    #[serde(rename = "Attributes")]
+    pub attributes: Option<u32>,
+    #[serde(rename = "Location")]
+    pub location: Option<String>,
+    #[serde(rename = "Comment")]
+    pub comment: Option<String>,
+    #[serde(rename = "Priority")]
+    pub priority: Option<u32>,
+    #[serde(rename = "PortName")]
+    pub port_name: Option<String>,
+    #[serde(rename = "JobCountSinceLastReset")]
+    pub job_count_since_last_reset: Option<u32>,
+    #[serde(rename = "DriverName")]
+    pub driver_name: Option<String>,
+    #[serde(rename = "Published")]
+    pub published: Option<bool>,
+    #[serde(rename = "ServerName")]
+    pub server_name: Option<String>,
+}
+
+/// Internal representation of an `MSFT_Printer` row (PrintManagement provider)
+#[cfg(windows)]
+#[derive(Deserialize, Debug, Default)]
+pub(crate) struct MsftPrinter {
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    /// Enumerated status: 0 is normal, `n` is the PrinterState flag `1 << (n - 1)`
+    #[serde(rename = "PrinterStatus", default, deserialize_with = "lenient_u32")]
+    pub printer_status: Option<u32>,
+    #[serde(rename = "Location")]
+    pub location: Option<String>,
+    #[serde(rename = "Comment")]
+    pub comment: Option<String>,
+    #[serde(rename = "Priority")]
+    pub priority: Option<u32>,
+    #[serde(rename = "PortName")]
+    pub port_name: Option<String>,
+    #[serde(rename = "DriverName")]
+    pub driver_name: Option<String>,
+    #[serde(rename = "Published")]
+    pub published: Option<bool>,
+    #[serde(rename = "ComputerName")]
+    pub computer_name: Option<String>,
+}
+
+#[cfg(windows)]
+impl From<MsftPrinter> for Win32Printer {
+    /// Maps the `MSFT_Printer` properties onto their `Win32_Printer` counterparts.
+    ///
+    /// The enumerated status becomes the matching PrinterState flag, from which
+    /// the status, state and offline flag are derived as usual.
+    fn from(row: MsftPrinter) -> Self {
+        let printer_state = row.printer_status.and_then(|status| match status {
+            0 => Some(0),
+            1..=25 => Some(1 << (status - 1)),
+            _ => None,
+        });
+        Self {
+            name: row.name,
+            printer_state,
+            location: row.location,
+            comment: row.comment,
+            priority: row.priority,
+            port_name: row.port_name,
+            driver_name: row.driver_name,
+            published: row.published,
+            server_name: row.computer_name,
+            ..Self::default()
+        }
+    }
+}
+
+/// Deserializes a status code that WMI providers may report as an unsigned or
+/// signed integer, or as a numeric string.
+///
+/// Signed values are reinterpreted as the `uint32` they encode (providers that
+/// return `VT_I4` for `uint32` properties). Null and unparseable values become
+/// `None` rather than failing the whole row.
+#[cfg_attr(not(windows), allow(dead_code))]
+fn lenient_u32<'de, D>(deserializer: D) -> std::result::Result<Option<u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct LenientU32;
+
+    impl<'de> serde::de::Visitor<'de> for LenientU32 {
+        type Value = Option<u32>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("an integer status code or a numeric string")
+        }
+
+        fn visit_u64<E: serde::de::Error>(self, v: u64) -> std::result::Result<Self::Value, E> {
+            Ok(u32::try_from(v).ok())
+        }
+
+        fn visit_i64<E: serde::de::Error>(self, v: i64) -> std::result::Result<Self::Value, E> {
+            Ok(match i32::try_from(v) {
+                Ok(signed) => Some(signed as u32),
+                Err(_) => u32::try_from(v).ok(),
+            })
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> std::result::Result<Self::Value, E> {
+            let v = v.trim();
+            match v.parse::<u32>() {
+                Ok(code) => Ok(Some(code)),
+                Err(_) => match v.parse::<i64>() {
+                    Ok(signed) => self.visit_i64(signed),
+                    Err(_) => Ok(None),
+                },
+            }
+        }
+
+        fn visit_none<E: serde::de::Error>(self) -> std::result::Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: serde::de::Error>(self) -> std::result::Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: serde::Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> std::result::Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    deserializer.deserialize_any(LenientU32)
+}
+
+/// Internal WMI printer configuration representation (current DEVMODE settings)
+#[cfg(windows)]
+#[derive(Deserialize, Debug)]
+pub(crate) struct Win32PrinterConfiguration {
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "Color")]
+    pub color: Option<u32>,
+    #[serde(rename = "Duplex")]
+    pub duplex: Option<bool>,
+}
+
+/// Returns the JSON Schema of a serialized [`Printer`], pretty-printed.
+///
+/// Describes the wire format of [`Printer`] (and the status enums it contains)
+/// for validating JSON output in other languages. Requires the `schema` feature.
+///
+/// # Example
+/// ```
+/// let schema = printer_event_handler::printer_json_schema();
+/// assert!(schema.contains("printer_status_code"));
+/// ```
+#[cfg(feature = "schema")]
+pub fn printer_json_schema() -> String {
+    let schema = schemars::schema_for!(Printer);
+    serde_json::to_string_pretty(&schema).expect("JSON Schema serializes to JSON")
 }
 
 /// Represents a printer and its current state
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Printer {
     name: String,
     status: PrinterStatus,
@@ -620,6 +2107,35 @@ pub struct Printer {
     extended_detected_error_state_code: Option<u32>, // ExtendedDetectedErrorState
     extended_printer_status_code: Option<u32>, // ExtendedPrinterStatus
     wmi_status: Option<String>,             // Status property (OK, Degraded, etc.)
+    attributes: Option<u32>,                // Attributes bitfield (PRINTER_ATTRIBUTE_*)
+    #[serde(default)]
+    color_mode: Option<ColorMode>, // Currently selected color mode, if reported
+    #[serde(default)]
+    duplex_mode: Option<DuplexMode>, // Currently selected duplex setting, if reported
+    #[serde(default)]
+    location: Option<String>, // Administrator-assigned location text
+    #[serde(default)]
+    comment: Option<String>, // Comment (Windows) or description (CUPS)
+    #[serde(default)]
+    priority: Option<u32>, // Spooler queue priority (1-99, Windows only)
+    #[serde(default)]
+    port: Option<String>, // Port name (Windows) or device URI (CUPS)
+    #[serde(default)]
+    jobs_since_reset: Option<u32>, // Cumulative job counter (Windows only)
+    #[serde(default)]
+    jobs_queued: Option<u32>, // Jobs currently waiting in or printing from the queue
+    #[serde(default)]
+    driver: Option<String>, // Driver name (Windows only)
+    #[serde(default)]
+    is_published: bool, // Published in Active Directory (Windows) or shared (CUPS)
+    #[serde(default)]
+    server: Option<String>, // Print server of a network printer connection (Windows only)
+    #[serde(default)]
+    queue_stopped: bool, // Queue stopped/disabled by CUPS, whatever its state reasons (Linux only)
+
+    // User-assigned grouping tags (not part of the reported printer state)
+    #[serde(default)]
+    tags: Vec<String>,
 }
 
 impl Printer {
@@ -667,6 +2183,20 @@ impl Printer {
             extended_detected_error_state_code: None,
             extended_printer_status_code: None,
             wmi_status: None,
+            attributes: None,
+            color_mode: None,
+            duplex_mode: None,
+            location: None,
+            comment: None,
+            priority: None,
+            port: None,
+            jobs_since_reset: None,
+            jobs_queued: None,
+            driver: None,
+            is_published: false,
+            server: None,
+            queue_stopped: false,
+            tags: Vec::new(),
         }
     }
 
@@ -692,6 +2222,20 @@ impl Printer {
             extended_detected_error_state_code: None,
             extended_printer_status_code: None,
             wmi_status: None,
+            attributes: None,
+            color_mode: None,
+            duplex_mode: None,
+            location: None,
+            comment: None,
+            priority: None,
+            port: None,
+            jobs_since_reset: None,
+            jobs_queued: None,
+            driver: None,
+            is_published: false,
+            server: None,
+            queue_stopped: false,
+            tags: Vec::new(),
         }
     }
 
@@ -705,6 +2249,50 @@ impl Printer {
         is_offline: bool,
         is_default: bool,
         wmi_codes: WmiStatusCodes,
+    ) -> Self {
+        Self::from_raw_codes(
+            name,
+            status,
+            state,
+            error_state,
+            is_offline,
+            is_default,
+            wmi_codes,
+        )
+    }
+
+    /// Creates a new Printer instance from raw status codes on any platform.
+    ///
+    /// Useful for mock backends and tests that need printers carrying the same
+    /// codes a Windows backend would report.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus, RawStatusCodes};
+    ///
+    /// let printer = Printer::from_raw_codes(
+    ///     "HP LaserJet".to_string(),
+    ///     PrinterStatus::Idle,
+    ///     None,
+    ///     ErrorState::NoError,
+    ///     false,
+    ///     true,
+    ///     RawStatusCodes {
+    ///         printer_status_code: Some(3),
+    ///         wmi_status: Some("OK".to_string()),
+    ///         ..RawStatusCodes::default()
+    ///     },
+    /// );
+    /// assert_eq!(printer.printer_status_code(), Some(3));
+    /// ```
+    pub fn from_raw_codes(
+        name: String,
+        status: PrinterStatus,
+        state: Option<PrinterState>,
+        error_state: ErrorState,
+        is_offline: bool,
+        is_default: bool,
+        codes: RawStatusCodes,
     ) -> Self {
         Self {
             name,
@@ -713,12 +2301,26 @@ impl Printer {
             error_state,
             is_offline,
             is_default,
-            printer_status_code: wmi_codes.printer_status_code,
-            printer_state_code: wmi_codes.printer_state_code,
-            detected_error_state_code: wmi_codes.detected_error_state_code,
-            extended_detected_error_state_code: wmi_codes.extended_detected_error_state_code,
-            extended_printer_status_code: wmi_codes.extended_printer_status_code,
-            wmi_status: wmi_codes.wmi_status,
+            printer_status_code: codes.printer_status_code,
+            printer_state_code: codes.printer_state_code,
+            detected_error_state_code: codes.detected_error_state_code,
+            extended_detected_error_state_code: codes.extended_detected_error_state_code,
+            extended_printer_status_code: codes.extended_printer_status_code,
+            wmi_status: codes.wmi_status,
+            attributes: None,
+            color_mode: None,
+            duplex_mode: None,
+            location: None,
+            comment: None,
+            priority: None,
+            port: None,
+            jobs_since_reset: None,
+            jobs_queued: None,
+            driver: None,
+            is_published: false,
+            server: None,
+            queue_stopped: false,
+            tags: Vec::new(),
         }
     }
 
@@ -767,38 +2369,717 @@ impl Printer {
         self.error_state.is_error()
     }
 
-    // Raw WMI Status Code Getters
+    /// Checks whether the printer is out of paper.
+    ///
+    /// The condition is reported consistently across every field that can carry it:
+    /// `ErrorState`/DetectedErrorState (4), the PrinterState PaperOut flag (16) and
+    /// ExtendedDetectedErrorState (4).
+    pub fn is_out_of_paper(&self) -> bool {
+        self.signals_condition(ErrorState::NoPaper, PrinterState::PaperOut, 4, 16, 4)
+    }
 
-    /// Returns the raw PrinterStatus code (1-7, current/recommended property)
-    pub fn printer_status_code(&self) -> Option<u32> {
-        self.printer_status_code
+    /// Checks whether the printer has a paper jam.
+    ///
+    /// Checks `ErrorState`/DetectedErrorState (8), the PrinterState PaperJam flag (8)
+    /// and ExtendedDetectedErrorState (8).
+    pub fn is_jammed(&self) -> bool {
+        self.signals_condition(ErrorState::Jammed, PrinterState::PaperJam, 8, 8, 8)
     }
 
-    /// Returns the raw PrinterState code (0-25, obsolete property)
-    pub fn printer_state_code(&self) -> Option<u32> {
-        self.printer_state_code
+    /// Checks whether a printer door or cover is open.
+    ///
+    /// Checks `ErrorState`/DetectedErrorState (7), the PrinterState DoorOpen flag
+    /// (4194304) and ExtendedDetectedErrorState (7).
+    pub fn is_door_open(&self) -> bool {
+        self.signals_condition(ErrorState::DoorOpen, PrinterState::DoorOpen, 7, 4194304, 7)
     }
 
-    /// Returns the raw DetectedErrorState code (0-11)
-    pub fn detected_error_state_code(&self) -> Option<u32> {
-        self.detected_error_state_code
+    /// Checks whether the printer is low on toner.
+    ///
+    /// Checks `ErrorState`/DetectedErrorState (5), the PrinterState TonerLow flag
+    /// (131072) and ExtendedDetectedErrorState (5).
+    pub fn is_low_toner(&self) -> bool {
+        self.signals_condition(ErrorState::LowToner, PrinterState::TonerLow, 5, 131072, 5)
     }
 
-    /// Returns the raw ExtendedDetectedErrorState code
-    pub fn extended_detected_error_state_code(&self) -> Option<u32> {
-        self.extended_detected_error_state_code
+    /// Checks whether the printer has run out of memory.
+    ///
+    /// Large or complex jobs fail under this condition. Checks the decoded
+    /// `PrinterState`, the raw PrinterState OutOfMemory flag (2097152) and
+    /// ExtendedDetectedErrorState (14).
+    pub fn is_out_of_memory(&self) -> bool {
+        self.state.as_ref() == Some(&PrinterState::OutOfMemory)
+            || self
+                .printer_state_code
+                .is_some_and(|code| code & 2097152 != 0)
+            || self.extended_detected_error_state_code == Some(14)
     }
 
-    /// Returns the raw ExtendedPrinterStatus code
-    pub fn extended_printer_status_code(&self) -> Option<u32> {
-        self.extended_printer_status_code
+    /// Checks whether the printer's status signals contradict each other.
+    ///
+    /// Compares the WMI Status string against `PrinterStatus` and `ErrorState`:
+    /// the signals conflict when Status reports a problem (see
+    /// [`WmiOperationalStatus::is_problem`], e.g. `Error` or `Degraded`) while
+    /// `PrinterStatus` reports a working printer (Idle, Printing or Warmup) and
+    /// `ErrorState` reports no error. This usually means a driver misreports one
+    /// of them. The offline flag is not compared, since on Windows it is partly
+    /// derived from the Status string. Printers without a Status never conflict.
+    pub fn has_status_conflict(&self) -> bool {
+        let status_problem = self
+            .wmi_operational_status()
+            .is_some_and(|status| status.is_problem());
+        let working = matches!(
+            self.status,
+            PrinterStatus::Idle | PrinterStatus::Printing | PrinterStatus::Warmup
+        );
+        status_problem && working && !self.error_state.is_error()
     }
 
-    /// Returns the WMI Status property string (OK, Degraded, Error, etc.)
-    pub fn wmi_status(&self) -> Option<&str> {
+    /// Checks whether the print queue is paused.
+    ///
+    /// Checks the decoded `PrinterState`, the raw PrinterState Paused flag (1),
+    /// which may be set alongside higher-priority flags, and on Linux whether CUPS
+    /// reports the queue as stopped. The decoded state of a stopped CUPS queue
+    /// shows its most severe alert instead, e.g. a paper jam.
+    pub fn is_paused(&self) -> bool {
+        self.queue_stopped
+            || self.state.as_ref() == Some(&PrinterState::Paused)
+            || self.printer_state_code.is_some_and(|code| code & 1 != 0)
+    }
+
+    /// Returns a cheap fingerprint of the monitored properties.
+    ///
+    /// Covers exactly the properties [`Self::compare_with`] reports, so a poll loop
+    /// over a large fleet can keep one `u64` per printer and only run the full diff
+    /// when the hash changes. Equal printers always hash equally; different ones
+    /// collide only with negligible probability. The value is only meaningful within
+    /// one process, so do not persist it.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let idle = Printer::new("HP".to_string(), PrinterStatus::Idle, ErrorState::NoError, false, false);
+    /// let printing = Printer::new("HP".to_string(), PrinterStatus::Printing, ErrorState::NoError, false, false);
+    ///
+    /// assert_eq!(idle.state_hash(), idle.clone().state_hash());
+    /// assert_ne!(idle.state_hash(), printing.state_hash());
+    /// ```
+    pub fn state_hash(&self) -> u64 {
+        use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher};
+
+        BuildHasherDefault::<DefaultHasher>::default().hash_one(self)
+    }
+
+    /// Tells an intentional pause apart from an error hold, or `None` if the
+    /// queue is not paused (see [`Self::is_paused`]).
+    ///
+    /// A paused queue with a blocking error (an [`ErrorState`] of
+    /// [`Severity::Error`] or worse, a jam, no paper, an open door or the raw
+    /// PrinterState Error flag (2)) is a [`PauseReason::ErrorHold`]. A generic or
+    /// unknown error state, or an unknown `PrinterStatus`, gives
+    /// [`PauseReason::Unknown`]. Otherwise the pause is
+    /// [`PauseReason::Administrative`]; warnings such as low toner do not hold
+    /// the queue.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, PauseReason, Printer, PrinterState, PrinterStatus};
+    ///
+    /// let paused = |error_state| Printer::new_with_state(
+    ///     "HP".to_string(), PrinterStatus::StoppedPrinting, Some(PrinterState::Paused),
+    ///     error_state, false, false,
+    /// );
+    /// assert_eq!(paused(ErrorState::NoError).pause_reason(), Some(PauseReason::Administrative));
+    /// assert_eq!(paused(ErrorState::Jammed).pause_reason(), Some(PauseReason::ErrorHold));
+    /// ```
+    pub fn pause_reason(&self) -> Option<PauseReason> {
+        if !self.is_paused() {
+            return None;
+        }
+
+        let blocking_error = self.error_state.severity() >= Severity::Error
+            || self.is_jammed()
+            || self.is_out_of_paper()
+            || self.is_door_open()
+            || self.printer_state_code.is_some_and(|code| code & 2 != 0);
+        if blocking_error {
+            return Some(PauseReason::ErrorHold);
+        }
+
+        let unclear = matches!(
+            self.error_state,
+            ErrorState::Other | ErrorState::UnknownError
+        ) || matches!(
+            self.status,
+            PrinterStatus::Unknown | PrinterStatus::StatusUnknown
+        );
+        if unclear {
+            Some(PauseReason::Unknown)
+        } else {
+            Some(PauseReason::Administrative)
+        }
+    }
+
+    /// Checks whether the printer is waiting for paper in the manual feed slot.
+    ///
+    /// Printing is blocked until someone loads the paper, so this is a "job stuck"
+    /// signal even though the printer reports no error. Checks the decoded
+    /// `PrinterState` and the raw PrinterState ManualFeed flag (32), which
+    /// `PrinterState` hides whenever a higher-priority flag such as Printing is set.
+    pub fn needs_manual_feed(&self) -> bool {
+        self.state.as_ref() == Some(&PrinterState::ManualFeed)
+            || self.printer_state_code.is_some_and(|code| code & 32 != 0)
+    }
+
+    /// Checks a condition across the overlapping error/state fields
+    fn signals_condition(
+        &self,
+        error_state: ErrorState,
+        state: PrinterState,
+        detected_error_code: u32,
+        state_flag: u32,
+        extended_error_code: u32,
+    ) -> bool {
+        self.error_state == error_state
+            || self.state.as_ref() == Some(&state)
+            || self.detected_error_state_code == Some(detected_error_code)
+            || self
+                .printer_state_code
+                .is_some_and(|code| code & state_flag != 0)
+            || self.extended_detected_error_state_code == Some(extended_error_code)
+    }
+
+    /// Updates the decoded printer state
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub(crate) fn set_state(&mut self, state: Option<PrinterState>) {
+        self.state = state;
+    }
+
+    /// Updates the default printer designation
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub(crate) fn set_default(&mut self, is_default: bool) {
+        self.is_default = is_default;
+    }
+
+    /// Returns the decoded Win32_Printer Attributes bitfield.
+    ///
+    /// All flags are `false` when attributes are not reported (e.g. on Linux);
+    /// use [`Self::attributes_code`] to tell that apart from a zero bitfield.
+    pub fn attributes(&self) -> PrinterAttributes {
+        PrinterAttributes::from_bits(self.attributes.unwrap_or(0))
+    }
+
+    /// Returns the raw Win32_Printer Attributes bitfield, if reported
+    pub fn attributes_code(&self) -> Option<u32> {
+        self.attributes
+    }
+
+    /// Returns this printer with the given raw Attributes bitfield.
+    pub fn with_attributes(mut self, attributes: Option<u32>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    /// Returns the currently selected color mode, if the driver reports one.
+    ///
+    /// Read from `Win32_PrinterConfiguration.Color` on Windows and from the
+    /// `print-color-mode` option reported by `lpoptions` on Linux.
+    pub fn color_mode(&self) -> Option<ColorMode> {
+        self.color_mode
+    }
+
+    /// Returns this printer with the given current color mode.
+    pub fn with_color_mode(mut self, color_mode: Option<ColorMode>) -> Self {
+        self.color_mode = color_mode;
+        self
+    }
+
+    /// Updates the current color mode
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub(crate) fn set_color_mode(&mut self, color_mode: Option<ColorMode>) {
+        self.color_mode = color_mode;
+    }
+
+    /// Returns the currently selected duplex setting, if the driver reports one.
+    ///
+    /// Read from `Win32_PrinterConfiguration.Duplex` on Windows, which only tells
+    /// whether duplex is on (see [`DuplexMode::from_wmi`]), and from the `sides`
+    /// option reported by `lpoptions` on Linux.
+    pub fn duplex_mode(&self) -> Option<DuplexMode> {
+        self.duplex_mode
+    }
+
+    /// Returns this printer with the given current duplex setting.
+    pub fn with_duplex_mode(mut self, duplex_mode: Option<DuplexMode>) -> Self {
+        self.duplex_mode = duplex_mode;
+        self
+    }
+
+    /// Updates the current duplex setting
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub(crate) fn set_duplex_mode(&mut self, duplex_mode: Option<DuplexMode>) {
+        self.duplex_mode = duplex_mode;
+    }
+
+    /// Encodes the printer in a compact binary format for high-frequency logging.
+    ///
+    /// The encoding is MessagePack with field names, several times smaller than
+    /// JSON yet self-describing: fields added in later versions default when
+    /// older data is decoded, so existing logs stay readable.
+    ///
+    /// Requires the `binary` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let printer = Printer::new("HP".to_string(), PrinterStatus::Idle, ErrorState::NoError, false, false);
+    /// let bytes = printer.to_bytes().unwrap();
+    /// assert_eq!(Printer::from_bytes(&bytes).unwrap(), printer);
+    /// ```
+    #[cfg(feature = "binary")]
+    pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+        encode_binary(self)
+    }
+
+    /// Decodes a printer written by [`Self::to_bytes`].
+    ///
+    /// Requires the `binary` feature.
+    #[cfg(feature = "binary")]
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        decode_binary(bytes)
+    }
+
+    /// Returns the port the printer is connected through: the `PortName` on Windows
+    /// (e.g. `IP_192.168.1.20`, `USB001`) or the device URI on Linux
+    /// (e.g. `socket://192.168.1.20:9100`).
+    pub fn port(&self) -> Option<&str> {
+        self.port.as_deref()
+    }
+
+    /// Returns this printer with the given port name or device URI; blank text is stored as `None`.
+    pub fn with_port(mut self, port: Option<String>) -> Self {
+        self.set_port(port);
+        self
+    }
+
+    /// Updates the port name or device URI
+    pub(crate) fn set_port(&mut self, port: Option<String>) {
+        self.port = port.filter(|text| !text.trim().is_empty());
+    }
+
+    /// Returns the print server a network printer connection goes through.
+    ///
+    /// Read from `Win32_Printer.ServerName` when reported, otherwise parsed from a
+    /// `\\server\share` printer name. `None` for local printers.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let printer = Printer::new(
+    ///     r"\\PRINTSRV01\HP-Floor3".to_string(),
+    ///     PrinterStatus::Idle,
+    ///     ErrorState::NoError,
+    ///     false,
+    ///     false,
+    /// );
+    /// assert_eq!(printer.server_name().as_deref(), Some("PRINTSRV01"));
+    /// assert_eq!(printer.share_name().as_deref(), Some("HP-Floor3"));
+    /// ```
+    pub fn server_name(&self) -> Option<String> {
+        self.server
+            .as_deref()
+            .map(|server| server.trim_start_matches('\\'))
+            .filter(|server| !server.trim().is_empty())
+            .or_else(|| split_unc_name(&self.name).map(|(server, _)| server))
+            .map(str::to_string)
+    }
+
+    /// Returns the share name of a `\\server\share` network printer connection,
+    /// or `None` for local printers.
+    pub fn share_name(&self) -> Option<String> {
+        split_unc_name(&self.name).map(|(_, share)| share.to_string())
+    }
+
+    /// Returns this printer with the given print server name.
+    pub fn with_server_name(mut self, server: Option<String>) -> Self {
+        self.server = server;
+        self
+    }
+
+    /// Returns the name of the printer driver, read from `Win32_Printer.DriverName` on Windows.
+    pub fn driver(&self) -> Option<&str> {
+        self.driver.as_deref()
+    }
+
+    /// Returns this printer with the given driver name.
+    pub fn with_driver(mut self, driver: Option<String>) -> Self {
+        self.driver = driver;
+        self
+    }
+
+    /// Checks whether this is a fax device rather than a printer.
+    ///
+    /// Detected from the Windows fax attribute (see [`PrinterAttributes::fax`]), a
+    /// driver name containing "Fax", the Windows fax service port (`SHRFAX:`) or a
+    /// CUPS fax backend URI (`fax://`, `hpfax:`, `faxmodem:`).
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let printer = Printer::new("Front Desk".to_string(), PrinterStatus::Idle, ErrorState::NoError, false, false)
+    ///     .with_driver(Some("Microsoft Shared Fax Driver".to_string()));
+    /// assert!(printer.is_fax());
+    /// ```
+    pub fn is_fax(&self) -> bool {
+        const FAX_PORT_PREFIXES: &[&str] = &["shrfax:", "fax:", "hpfax:", "faxmodem:"];
+
+        let fax_driver = self
+            .driver
+            .as_deref()
+            .is_some_and(|driver| driver.to_lowercase().contains("fax"));
+        let fax_port = self.port.as_deref().is_some_and(|port| {
+            let port = port.to_lowercase();
+            FAX_PORT_PREFIXES
+                .iter()
+                .any(|prefix| port.starts_with(prefix))
+        });
+        self.attributes().fax || fax_driver || fax_port
+    }
+
+    /// Returns the network host (IP address or host name) the printer points at.
+    ///
+    /// Understands Windows standard TCP/IP port names (`IP_192.168.1.20`,
+    /// `192.168.1.20_1`, `printer01.corp.local`), UNC paths of shared printers
+    /// (`\\server\printer`) and device URIs such as `socket://host:9100`,
+    /// `ipp://host/ipp/print`, `lpd://host/queue` or `smb://server/printer`.
+    /// Returns `None` for local, USB, file and WSD ports and for DNS-SD URIs.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let printer = Printer::new("HP".to_string(), PrinterStatus::Idle, ErrorState::NoError, false, false)
+    ///     .with_port(Some("socket://192.168.1.20:9100".to_string()));
+    /// assert_eq!(printer.host().as_deref(), Some("192.168.1.20"));
+    /// ```
+    pub fn host(&self) -> Option<String> {
+        let port = self.port.as_deref()?.trim();
+
+        if port.contains("://") {
+            return host_from_uri(port);
+        }
+        if let Some(unc) = port.strip_prefix("\\\\") {
+            return unc
+                .split('\\')
+                .next()
+                .filter(|server| !server.is_empty())
+                .map(str::to_string);
+        }
+
+        let candidate = port.strip_prefix("IP_").unwrap_or(port);
+        // Duplicate standard TCP/IP ports get a numeric suffix, e.g. "192.168.1.20_1"
+        let candidate = match candidate.rsplit_once('_') {
+            Some((host, suffix)) if suffix.chars().all(|c| c.is_ascii_digit()) => host,
+            _ => candidate,
+        };
+
+        let is_ip = candidate.parse::<std::net::Ipv4Addr>().is_ok();
+        let is_host_name = candidate.contains('.')
+            && candidate
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+        (is_ip || is_host_name).then(|| candidate.to_string())
+    }
+
+    /// Returns the spooler priority of the print queue (1-99, higher prints first).
+    ///
+    /// Read from `Win32_Printer.Priority` on Windows; `None` where the platform has
+    /// no per-queue priority (CUPS only prioritizes individual jobs).
+    pub fn priority(&self) -> Option<u32> {
+        self.priority
+    }
+
+    /// Returns this printer with the given queue priority.
+    pub fn with_priority(mut self, priority: Option<u32>) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the number of jobs printed since the counter was last reset.
+    ///
+    /// Read from `Win32_Printer.JobCountSinceLastReset` on Windows; `None` on CUPS,
+    /// which keeps no such counter. See [`PrinterChanges::jobs_printed`] for the
+    /// number of jobs between two polls.
+    pub fn jobs_since_reset(&self) -> Option<u32> {
+        self.jobs_since_reset
+    }
+
+    /// Returns this printer with the given jobs-since-reset counter.
+    pub fn with_jobs_since_reset(mut self, jobs_since_reset: Option<u32>) -> Self {
+        self.jobs_since_reset = jobs_since_reset;
+        self
+    }
+
+    /// Returns the number of jobs currently in the printer's queue.
+    ///
+    /// Read from the spooler's `Win32_PerfRawData_Spooler_PrintQueue.Jobs` counter
+    /// on Windows and counted from `lpstat -o` on CUPS. `None` when the queue
+    /// could not be read. Like the color mode, the queue length is detail rather
+    /// than printer state, so it is not compared by [`PartialEq`].
+    pub fn jobs_queued(&self) -> Option<u32> {
+        self.jobs_queued
+    }
+
+    /// Returns this printer with the given number of queued jobs.
+    pub fn with_jobs_queued(mut self, jobs_queued: Option<u32>) -> Self {
+        self.jobs_queued = jobs_queued;
+        self
+    }
+
+    /// Updates the number of queued jobs
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub(crate) fn set_jobs_queued(&mut self, jobs_queued: Option<u32>) {
+        self.jobs_queued = jobs_queued;
+    }
+
+    /// Checks whether the printer has work in hand: it is printing or processing a
+    /// job, or jobs are waiting in its queue.
+    ///
+    /// Printing and processing are read from the status, the decoded state and the
+    /// raw PrinterState Printing (1024) and Processing (16384) flags, since a
+    /// higher-priority flag can hide them from the decoded state. A printer whose
+    /// queue length is unknown only counts as busy through those signals.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let idle = Printer::new(
+    ///     "HP LaserJet".to_string(),
+    ///     PrinterStatus::Idle,
+    ///     ErrorState::NoError,
+    ///     false,
+    ///     false,
+    /// );
+    /// assert!(!idle.has_active_work());
+    /// assert!(idle.clone().with_jobs_queued(Some(2)).has_active_work());
+    /// assert!(!idle.with_jobs_queued(Some(0)).has_active_work());
+    /// ```
+    pub fn has_active_work(&self) -> bool {
+        let working = self.status == PrinterStatus::Printing
+            || matches!(
+                self.state,
+                Some(PrinterState::Printing | PrinterState::Processing)
+            )
+            || self
+                .printer_state_code
+                .is_some_and(|code| code & (1024 | 16384) != 0);
+        working || self.jobs_queued.is_some_and(|jobs| jobs > 0)
+    }
+
+    /// Checks whether the printer is published for discovery by other computers.
+    ///
+    /// Read from `Win32_Printer.Published` (listed in Active Directory) on Windows
+    /// and from the `printer-is-shared` option on CUPS. Compared by
+    /// [`Printer::compare_with`], so a printer that is unexpectedly unpublished
+    /// shows up as an `IsPublished` change.
+    pub fn is_published(&self) -> bool {
+        self.is_published
+    }
+
+    /// Returns this printer with the given published flag.
+    pub fn with_published(mut self, is_published: bool) -> Self {
+        self.is_published = is_published;
+        self
+    }
+
+    /// Updates the published flag
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub(crate) fn set_published(&mut self, is_published: bool) {
+        self.is_published = is_published;
+    }
+
+    /// Records whether CUPS reports the queue as stopped
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub(crate) fn set_queue_stopped(&mut self, queue_stopped: bool) {
+        self.queue_stopped = queue_stopped;
+    }
+
+    /// Returns the administrator-assigned location of the printer, if set.
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// Returns the printer comment (Windows) or description (CUPS), if set.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Returns this printer with the given location; blank text is stored as `None`.
+    pub fn with_location(mut self, location: Option<String>) -> Self {
+        self.set_location(location);
+        self
+    }
+
+    /// Returns this printer with the given comment; blank text is stored as `None`.
+    pub fn with_comment(mut self, comment: Option<String>) -> Self {
+        self.set_comment(comment);
+        self
+    }
+
+    /// Updates the location text
+    pub(crate) fn set_location(&mut self, location: Option<String>) {
+        self.location = location.filter(|text| !text.trim().is_empty());
+    }
+
+    /// Updates the comment text
+    pub(crate) fn set_comment(&mut self, comment: Option<String>) {
+        self.comment = comment.filter(|text| !text.trim().is_empty());
+    }
+
+    /// Checks whether this is a per-session redirected printer (RDS/Citrix).
+    ///
+    /// Redirected printers are created for a client session and disappear when the
+    /// session ends, so server-side monitoring usually wants to ignore them. A
+    /// printer is considered redirected when either:
+    /// - the `PRINTER_ATTRIBUTE_TS` bit (0x8000) is set in Attributes, which the
+    ///   spooler sets on terminal-server redirected queues, or
+    /// - its name follows the RDS pattern `"Printer (redirected 3)"` or the Citrix
+    ///   pattern `"Printer (from CLIENT) in session 3"`.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let printer = Printer::new(
+    ///     "HP LaserJet (redirected 2)".to_string(),
+    ///     PrinterStatus::Idle,
+    ///     ErrorState::NoError,
+    ///     false,
+    ///     false,
+    /// );
+    /// assert!(printer.is_redirected());
+    /// ```
+    pub fn is_redirected(&self) -> bool {
+        if self.attributes().terminal_server {
+            return true;
+        }
+
+        let name = self.name.to_lowercase();
+        name.contains("(redirected ") || (name.contains("(from ") && name.contains(" in session "))
+    }
+
+    /// Checks whether this is a known virtual (software-only) printer.
+    ///
+    /// Matches the names in [`VIRTUAL_PRINTER_NAMES`] case-insensitively, including
+    /// variants such as "Send To OneNote 2016". Use [`Self::is_virtual_with`] to
+    /// recognize additional virtual printers.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{ErrorState, Printer, PrinterStatus};
+    ///
+    /// let printer = Printer::new(
+    ///     "Microsoft Print to PDF".to_string(),
+    ///     PrinterStatus::Idle,
+    ///     ErrorState::NoError,
+    ///     false,
+    ///     false,
+    /// );
+    /// assert!(printer.is_virtual());
+    /// ```
+    pub fn is_virtual(&self) -> bool {
+        self.is_virtual_with::<&str>(&[])
+    }
+
+    /// Checks whether this is a virtual printer, also matching the given extra names.
+    ///
+    /// # Arguments
+    /// * `extra_names` - Additional virtual printer names, matched like the built-in ones
+    pub fn is_virtual_with<S: AsRef<str>>(&self, extra_names: &[S]) -> bool {
+        let name = self.name.to_lowercase();
+        VIRTUAL_PRINTER_NAMES
+            .iter()
+            .copied()
+            .chain(extra_names.iter().map(AsRef::as_ref))
+            .any(|virtual_name| name.contains(&virtual_name.to_lowercase()))
+    }
+
+    /// Returns the user-assigned tags for this printer (e.g. floor or team).
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Checks whether the printer carries the given tag (case-insensitive).
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = tag.to_lowercase();
+        self.tags.iter().any(|t| t.to_lowercase() == tag)
+    }
+
+    /// Returns this printer with the given tags attached.
+    ///
+    /// Tags are grouping metadata only; they are ignored by equality checks
+    /// and `compare_with`.
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    // Raw WMI Status Code Getters
+
+    /// Returns the raw PrinterStatus code (1-7, current/recommended property)
+    pub fn printer_status_code(&self) -> Option<u32> {
+        self.printer_status_code
+    }
+
+    /// Returns the raw PrinterState code (0-25, obsolete property)
+    pub fn printer_state_code(&self) -> Option<u32> {
+        self.printer_state_code
+    }
+
+    /// Returns the raw DetectedErrorState code (0-11)
+    pub fn detected_error_state_code(&self) -> Option<u32> {
+        self.detected_error_state_code
+    }
+
+    /// Returns the raw ExtendedDetectedErrorState code
+    pub fn extended_detected_error_state_code(&self) -> Option<u32> {
+        self.extended_detected_error_state_code
+    }
+
+    /// Returns the raw ExtendedPrinterStatus code
+    pub fn extended_printer_status_code(&self) -> Option<u32> {
+        self.extended_printer_status_code
+    }
+
+    /// Returns the WMI Status property string (OK, Degraded, Error, etc.)
+    pub fn wmi_status(&self) -> Option<&str> {
         self.wmi_status.as_deref()
     }
 
+    /// Clears a WMI Status that is empty or only whitespace
+    pub(crate) fn clear_blank_wmi_status(&mut self) {
+        if self
+            .wmi_status
+            .as_deref()
+            .is_some_and(|status| status.trim().is_empty())
+        {
+            self.wmi_status = None;
+        }
+    }
+
+    /// Returns the WMI Status property parsed into a [`WmiOperationalStatus`]
+    pub fn wmi_operational_status(&self) -> Option<WmiOperationalStatus> {
+        // Parsing is infallible; unknown strings become `Other`
+        self.wmi_status
+            .as_deref()
+            .and_then(|status| status.parse().ok())
+    }
+
     // WMI Status Description Getters
 
     /// Returns human-readable description of PrinterStatus code
@@ -924,66 +3205,108 @@ impl Printer {
 
     /// Compares this printer with another and returns detailed changes
     pub fn compare_with(&self, other: &Printer) -> PrinterChanges {
-        let mut changes = PrinterChanges::new(self.name.clone());
+        self.compare_with_clock(other, &SystemClock)
+    }
 
-        // Check each property for changes
-        if self.name != other.name {
-            changes.changes.push(PropertyChange::Name {
-                old: self.name.clone(),
-                new: other.name.clone(),
+    /// Compares this printer with another, timestamping the changes with the given clock
+    pub fn compare_with_clock(&self, other: &Printer, clock: &dyn Clock) -> PrinterChanges {
+        self.diff(other, &CompareOptions::default(), clock)
+    }
+
+    /// Compares this printer with another, skipping the fields ignored by `options`.
+    ///
+    /// # Example
+    /// ```
+    /// use printer_event_handler::{CompareOptions, ErrorState, Printer, PrinterStatus};
+    ///
+    /// let before = Printer::new("HP".to_string(), PrinterStatus::Idle, ErrorState::NoError, false, false);
+    /// let after = Printer::new("HP".to_string(), PrinterStatus::Idle, ErrorState::NoError, true, false);
+    ///
+    /// let options = CompareOptions {
+    ///     ignore_offline: true,
+    ///     ..CompareOptions::default()
+    /// };
+    /// assert!(!before.compare_with_options(&after, &options).has_changes());
+    /// assert!(before.compare_with(&after).has_changes());
+    /// ```
+    pub fn compare_with_options(
+        &self,
+        other: &Printer,
+        options: &CompareOptions,
+    ) -> PrinterChanges {
+        self.diff(other, options, &SystemClock)
+    }
+
+    fn diff(&self, other: &Printer, options: &CompareOptions, clock: &dyn Clock) -> PrinterChanges {
+        let mut changes = PrinterChanges::new_with_clock(self.name.clone(), clock);
+
+        // Check each property for changes
+        if !options.ignore_name && self.name != other.name {
+            changes.changes.push(PropertyChange::Name {
+                old: self.name.clone(),
+                new: other.name.clone(),
             });
         }
 
-        if self.status != other.status {
+        if !options.ignore_status && self.status != other.status {
             changes.changes.push(PropertyChange::Status {
                 old: self.status.clone(),
                 new: other.status.clone(),
             });
         }
 
-        if self.state != other.state {
+        if !options.ignore_state && self.state != other.state {
             changes.changes.push(PropertyChange::State {
                 old: self.state.clone(),
                 new: other.state.clone(),
             });
         }
 
-        if self.error_state != other.error_state {
+        if !options.ignore_error_state && self.error_state != other.error_state {
             changes.changes.push(PropertyChange::ErrorState {
                 old: self.error_state.clone(),
                 new: other.error_state.clone(),
             });
         }
 
-        if self.is_offline != other.is_offline {
+        if !options.ignore_offline && self.is_offline != other.is_offline {
             changes.changes.push(PropertyChange::IsOffline {
                 old: self.is_offline,
                 new: other.is_offline,
             });
         }
 
-        if self.is_default != other.is_default {
+        if !options.ignore_default && self.is_default != other.is_default {
             changes.changes.push(PropertyChange::IsDefault {
                 old: self.is_default,
                 new: other.is_default,
             });
         }
 
-        if self.printer_status_code != other.printer_status_code {
+        if !options.ignore_published && self.is_published != other.is_published {
+            changes.changes.push(PropertyChange::IsPublished {
+                old: self.is_published,
+                new: other.is_published,
+            });
+        }
+
+        if !options.ignore_status_code && self.printer_status_code != other.printer_status_code {
             changes.changes.push(PropertyChange::PrinterStatusCode {
                 old: self.printer_status_code,
                 new: other.printer_status_code,
             });
         }
 
-        if self.printer_state_code != other.printer_state_code {
+        if !options.ignore_state_code && self.printer_state_code != other.printer_state_code {
             changes.changes.push(PropertyChange::PrinterStateCode {
                 old: self.printer_state_code,
                 new: other.printer_state_code,
             });
         }
 
-        if self.detected_error_state_code != other.detected_error_state_code {
+        if !options.ignore_detected_error_code
+            && self.detected_error_state_code != other.detected_error_state_code
+        {
             changes
                 .changes
                 .push(PropertyChange::DetectedErrorStateCode {
@@ -992,7 +3315,9 @@ impl Printer {
                 });
         }
 
-        if self.extended_detected_error_state_code != other.extended_detected_error_state_code {
+        if !options.ignore_extended_detected_error_code
+            && self.extended_detected_error_state_code != other.extended_detected_error_state_code
+        {
             changes
                 .changes
                 .push(PropertyChange::ExtendedDetectedErrorStateCode {
@@ -1001,7 +3326,9 @@ impl Printer {
                 });
         }
 
-        if self.extended_printer_status_code != other.extended_printer_status_code {
+        if !options.ignore_extended_status_code
+            && self.extended_printer_status_code != other.extended_printer_status_code
+        {
             changes
                 .changes
                 .push(PropertyChange::ExtendedPrinterStatusCode {
@@ -1010,24 +3337,118 @@ impl Printer {
                 });
         }
 
-        if self.wmi_status != other.wmi_status {
+        if !options.ignore_wmi_status && self.wmi_status != other.wmi_status {
             changes.changes.push(PropertyChange::WmiStatus {
                 old: self.wmi_status.clone(),
                 new: other.wmi_status.clone(),
             });
         }
 
+        if !options.ignore_location && self.location != other.location {
+            changes.changes.push(PropertyChange::Location {
+                old: self.location.clone(),
+                new: other.location.clone(),
+            });
+        }
+
+        if !options.ignore_comment && self.comment != other.comment {
+            changes.changes.push(PropertyChange::Comment {
+                old: self.comment.clone(),
+                new: other.comment.clone(),
+            });
+        }
+
+        if !options.ignore_priority && self.priority != other.priority {
+            changes.changes.push(PropertyChange::Priority {
+                old: self.priority,
+                new: other.priority,
+            });
+        }
+
+        if !options.ignore_jobs_since_reset && self.jobs_since_reset != other.jobs_since_reset {
+            changes.changes.push(PropertyChange::JobsSinceReset {
+                old: self.jobs_since_reset,
+                new: other.jobs_since_reset,
+            });
+        }
+
         changes
     }
 }
 
+/// Splits a `\\server\share` printer connection name into server and share
+fn split_unc_name(name: &str) -> Option<(&str, &str)> {
+    let (server, share) = name.strip_prefix(r"\\")?.split_once('\\')?;
+    if server.is_empty() || share.is_empty() {
+        return None;
+    }
+    Some((server, share))
+}
+
+/// Joins phrases as "a", "a and b" or "a, b and c"
+fn join_with_and(parts: &[String]) -> String {
+    match parts {
+        [] => String::new(),
+        [only] => only.clone(),
+        [init @ .., last] => format!("{} and {}", init.join(", "), last),
+    }
+}
+
+/// Encodes a value as MessagePack, keeping field names for forward compatibility
+#[cfg(feature = "binary")]
+fn encode_binary<T: Serialize>(value: &T) -> crate::Result<Vec<u8>> {
+    rmp_serde::to_vec_named(value)
+        .map_err(|e| crate::PrinterError::Other(format!("cannot encode binary snapshot: {}", e)))
+}
+
+/// Decodes a value written by [`encode_binary`]
+#[cfg(feature = "binary")]
+fn decode_binary<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> crate::Result<T> {
+    rmp_serde::from_slice(bytes)
+        .map_err(|e| crate::PrinterError::Other(format!("invalid binary snapshot: {}", e)))
+}
+
+/// Extracts the host from a device URI such as `ipp://user@host:631/printers/x`
+fn host_from_uri(uri: &str) -> Option<String> {
+    let (scheme, rest) = uri.split_once("://")?;
+    // Local connections and service names rather than hosts
+    if matches!(
+        scheme.to_ascii_lowercase().as_str(),
+        "usb" | "file" | "parallel" | "serial" | "dnssd" | "hp" | "hpfax" | "cups-pdf"
+    ) {
+        return None;
+    }
+
+    let authority = rest.split(['/', '?']).next()?;
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let host = match host_port.strip_prefix('[') {
+        // IPv6 literal, e.g. "[fe80::1]:631"
+        Some(v6) => v6.split(']').next()?,
+        None => host_port.split(':').next()?,
+    };
+
+    (!host.is_empty()).then(|| host.to_string())
+}
+
 #[cfg(windows)]
 impl From<Win32Printer> for Printer {
+    /// Converts a WMI Win32_Printer object into a Printer instance, treating
+    /// `DetectedErrorState = 0` as no error.
+    fn from(wmi_printer: Win32Printer) -> Self {
+        Self::from_wmi(wmi_printer, true)
+    }
+}
+
+#[cfg(windows)]
+impl Printer {
     /// Converts a WMI Win32_Printer object into a Printer instance.
     ///
     /// This implementation prioritizes PrinterStatus (current) over PrinterState (obsolete)
-    /// according to Microsoft recommendations.
-    fn from(wmi_printer: Win32Printer) -> Self {
+    /// according to Microsoft recommendations. `treat_zero_error_as_no_error` selects
+    /// how the documented "Unknown" `DetectedErrorState` value 0 is interpreted.
+    pub(crate) fn from_wmi(wmi_printer: Win32Printer, treat_zero_error_as_no_error: bool) -> Self {
         // First, try to get status from PrinterStatus (current/recommended property)
         let status = PrinterStatus::from_u32(wmi_printer.printer_status);
 
@@ -1052,9 +3473,10 @@ impl From<Win32Printer> for Printer {
             // ExtendedPrinterStatus 7 = Offline
             || wmi_printer.extended_printer_status == Some(7)
             // Status property indicating problematic states
-            || wmi_printer.status.as_ref().is_some_and(|s| matches!(s.as_str(),
-                "Degraded" | "Error" | "No Contact" | "Lost Comm" | "NonRecover"
-            ));
+            || wmi_printer.status.as_deref().is_some_and(|s| {
+                s.parse::<WmiOperationalStatus>()
+                    .is_ok_and(|status| status.is_problem())
+            });
 
         let wmi_codes = WmiStatusCodes {
             printer_status_code: wmi_printer.printer_status,
@@ -1071,28 +3493,121 @@ impl From<Win32Printer> for Printer {
                 .unwrap_or_else(|| "Unknown Printer".to_string()),
             final_status,
             state,
-            ErrorState::from_u32(wmi_printer.detected_error_state),
+            ErrorState::from_u32(
+                wmi_printer.detected_error_state,
+                treat_zero_error_as_no_error,
+            ),
             is_offline,
             wmi_printer.default.unwrap_or(false),
             wmi_codes,
         )
+        .with_attributes(wmi_printer.attributes)
+        .with_location(wmi_printer.location)
+        .with_comment(wmi_printer.comment)
+        .with_priority(wmi_printer.priority)
+        .with_jobs_since_reset(wmi_printer.job_count_since_last_reset)
+        .with_driver(wmi_printer.driver_name)
+        .with_port(wmi_printer.port_name)
+        .with_published(wmi_printer.published.unwrap_or(false))
+        .with_server_name(wmi_printer.server_name)
     }
 }
 
 impl PartialEq for Printer {
     /// Compares two Printer instances for equality.
+    ///
+    /// Covers the same properties [`Printer::compare_with`] reports, so two printers
+    /// are unequal exactly when `compare_with` finds changes. To ignore some of them
+    /// (e.g. the default printer flag), use [`Printer::compare_with_options`].
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
             && self.status == other.status
             && self.state == other.state
             && self.error_state == other.error_state
             && self.is_offline == other.is_offline
+            && self.is_default == other.is_default
+            && self.is_published == other.is_published
             && self.printer_status_code == other.printer_status_code
             && self.printer_state_code == other.printer_state_code
             && self.detected_error_state_code == other.detected_error_state_code
             && self.extended_detected_error_state_code == other.extended_detected_error_state_code
             && self.extended_printer_status_code == other.extended_printer_status_code
             && self.wmi_status == other.wmi_status
+            && self.location == other.location
+            && self.comment == other.comment
+            && self.priority == other.priority
+            && self.jobs_since_reset == other.jobs_since_reset
+    }
+}
+
+impl Eq for Printer {}
+
+impl Ord for Printer {
+    /// Orders printers by name, so they can key a `BTreeMap` or fill a `BTreeSet`
+    /// for ordered display.
+    ///
+    /// Printers with the same name are ordered by the remaining properties
+    /// [`PartialEq`] compares, in field order, which keeps the ordering consistent
+    /// with equality. The port is not one of them: equality ignores it, so ordering
+    /// by it would make equal printers compare unequal.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.name
+            .cmp(&other.name)
+            .then_with(|| self.status.cmp(&other.status))
+            .then_with(|| self.state.cmp(&other.state))
+            .then_with(|| self.error_state.cmp(&other.error_state))
+            .then_with(|| self.is_offline.cmp(&other.is_offline))
+            .then_with(|| self.is_default.cmp(&other.is_default))
+            .then_with(|| self.is_published.cmp(&other.is_published))
+            .then_with(|| self.printer_status_code.cmp(&other.printer_status_code))
+            .then_with(|| self.printer_state_code.cmp(&other.printer_state_code))
+            .then_with(|| {
+                self.detected_error_state_code
+                    .cmp(&other.detected_error_state_code)
+            })
+            .then_with(|| {
+                self.extended_detected_error_state_code
+                    .cmp(&other.extended_detected_error_state_code)
+            })
+            .then_with(|| {
+                self.extended_printer_status_code
+                    .cmp(&other.extended_printer_status_code)
+            })
+            .then_with(|| self.wmi_status.cmp(&other.wmi_status))
+            .then_with(|| self.location.cmp(&other.location))
+            .then_with(|| self.comment.cmp(&other.comment))
+            .then_with(|| self.priority.cmp(&other.priority))
+            .then_with(|| self.jobs_since_reset.cmp(&other.jobs_since_reset))
+    }
+}
+
+impl PartialOrd for Printer {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::hash::Hash for Printer {
+    /// Hashes the same properties [`PartialEq`] compares, so equal printers hash
+    /// equally (see [`Printer::state_hash`])
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.status.hash(state);
+        self.state.hash(state);
+        self.error_state.hash(state);
+        self.is_offline.hash(state);
+        self.is_default.hash(state);
+        self.is_published.hash(state);
+        self.printer_status_code.hash(state);
+        self.printer_state_code.hash(state);
+        self.detected_error_state_code.hash(state);
+        self.extended_detected_error_state_code.hash(state);
+        self.extended_printer_status_code.hash(state);
+        self.wmi_status.hash(state);
+        self.location.hash(state);
+        self.comment.hash(state);
+        self.priority.hash(state);
+        self.jobs_since_reset.hash(state);
     }
 }
 
@@ -1112,6 +3627,50 @@ mod tests {
         assert_eq!(PrinterState::TonerLow.to_string(), "Toner Low");
     }
 
+    #[test]
+    fn test_printer_state_flags_display() {
+        assert_eq!(PrinterState::flags_display(0), "None");
+        assert_eq!(PrinterState::flags_display(1024), "Printing");
+        assert_eq!(
+            PrinterState::flags_display(1024 | 131072),
+            "Printing | Toner Low"
+        );
+        assert_eq!(
+            PrinterState::flags_display(4194304 | 16 | 2),
+            "Error | Paper Out | Door Open"
+        );
+        assert_eq!(PrinterState::flags_display(1 << 30), "Status Unknown");
+        assert_eq!(PrinterState::flags_display((1 << 30) | 1), "Paused");
+    }
+
+    #[test]
+    fn test_state_and_error_state_conversions() {
+        let pairs = [
+            (PrinterState::None, ErrorState::NoError),
+            (PrinterState::Error, ErrorState::Other),
+            (PrinterState::PaperJam, ErrorState::Jammed),
+            (PrinterState::PaperOut, ErrorState::NoPaper),
+            (PrinterState::DoorOpen, ErrorState::DoorOpen),
+            (PrinterState::TonerLow, ErrorState::LowToner),
+            (PrinterState::NoToner, ErrorState::NoToner),
+            (PrinterState::OutputBinFull, ErrorState::OutputBinFull),
+            (
+                PrinterState::UserInterventionRequired,
+                ErrorState::ServiceRequested,
+            ),
+        ];
+        for (state, error) in pairs {
+            assert_eq!(state.to_error_state(), Some(error.clone()));
+            assert_eq!(error.to_printer_state(), Some(state));
+        }
+
+        assert_eq!(PrinterState::Printing.to_error_state(), None);
+        assert_eq!(PrinterState::Offline.to_error_state(), None);
+        assert_eq!(PrinterState::PaperProblem.to_error_state(), None);
+        assert_eq!(ErrorState::LowPaper.to_printer_state(), None);
+        assert_eq!(ErrorState::UnknownError.to_printer_state(), None);
+    }
+
     #[test]
     fn test_error_state_is_error() {
         assert!(!ErrorState::NoError.is_error());
@@ -1119,6 +3678,27 @@ mod tests {
         assert!(ErrorState::NoPaper.is_error());
     }
 
+    #[test]
+    fn test_error_state_severity_and_action() {
+        assert_eq!(ErrorState::NoError.severity(), Severity::Info);
+        assert_eq!(ErrorState::LowPaper.severity(), Severity::Warning);
+        assert_eq!(ErrorState::Jammed.severity(), Severity::Error);
+        assert_eq!(ErrorState::ServiceRequested.severity(), Severity::Critical);
+
+        assert_eq!(
+            ErrorState::NoPaper.recommended_action(),
+            "Load paper into the tray"
+        );
+        assert_eq!(
+            ErrorState::Jammed.recommended_action(),
+            "Clear the paper jam"
+        );
+        assert_eq!(
+            ErrorState::DoorOpen.recommended_action(),
+            "Close all printer doors and covers"
+        );
+    }
+
     #[test]
     fn test_printer_creation() {
         let printer = Printer::new(
@@ -1148,4 +3728,1375 @@ mod tests {
             PrinterStatus::Other
         );
     }
+
+    #[test]
+    fn test_compare_with_clock_timestamp() {
+        use crate::clock::FixedClock;
+        use chrono::TimeZone;
+
+        let at = chrono::Utc
+            .with_ymd_and_hms(2025, 3, 14, 9, 26, 53)
+            .unwrap();
+        let clock = FixedClock::new(at);
+
+        let idle = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+        let printing = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Printing,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+
+        let changes = idle.compare_with_clock(&printing, &clock);
+        assert_eq!(changes.timestamp, at);
+        assert_eq!(changes.change_count(), 1);
+
+        let empty = PrinterChanges::new_with_clock("Test Printer".to_string(), &clock);
+        assert_eq!(empty.timestamp, at);
+    }
+
+    #[test]
+    fn test_tags_serialized_but_ignored_by_equality() {
+        let printer = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+        let tagged = printer.clone().with_tags(vec!["floor-2".to_string()]);
+
+        assert_eq!(printer, tagged);
+        assert!(!printer.compare_with(&tagged).has_changes());
+
+        let json = serde_json::to_value(&tagged).unwrap();
+        assert_eq!(json["tags"], serde_json::json!(["floor-2"]));
+    }
+
+    #[test]
+    fn test_state_hash_tracks_monitored_fields() {
+        let printer = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        )
+        .with_location(Some("Floor 2".to_string()));
+
+        // Equal printers hash equally, including fields compare_with ignores
+        let mut tagged = printer.clone();
+        tagged.tags = vec!["finance".to_string()];
+        tagged.port = Some("USB001".to_string());
+        assert_eq!(tagged, printer);
+        assert_eq!(tagged.state_hash(), printer.state_hash());
+
+        let mut changes: Vec<Printer> = Vec::new();
+        let mut changed = printer.clone();
+        changed.status = PrinterStatus::Printing;
+        changes.push(changed);
+        let mut changed = printer.clone();
+        changed.error_state = ErrorState::Jammed;
+        changes.push(changed);
+        let mut changed = printer.clone();
+        changed.is_offline = true;
+        changes.push(changed);
+        let mut changed = printer.clone();
+        changed.printer_state_code = Some(1);
+        changes.push(changed);
+        changes.push(printer.clone().with_location(Some("Floor 3".to_string())));
+        changes.push(printer.clone().with_jobs_since_reset(Some(4)));
+
+        for changed in changes {
+            assert_ne!(changed, printer);
+            assert_ne!(changed.state_hash(), printer.state_hash(), "{:?}", changed);
+        }
+    }
+
+    #[test]
+    fn test_pause_reason() {
+        let paused = |status, error_state| {
+            Printer::new_with_state(
+                "Test Printer".to_string(),
+                status,
+                Some(PrinterState::Paused),
+                error_state,
+                false,
+                false,
+            )
+        };
+
+        // Not paused at all
+        let running = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::Jammed,
+            false,
+            false,
+        );
+        assert_eq!(running.pause_reason(), None);
+
+        // Paused with no error is an intentional pause, even with a warning
+        let admin = paused(PrinterStatus::StoppedPrinting, ErrorState::NoError);
+        assert_eq!(admin.pause_reason(), Some(PauseReason::Administrative));
+        let low_toner = paused(PrinterStatus::Idle, ErrorState::LowToner);
+        assert_eq!(low_toner.pause_reason(), Some(PauseReason::Administrative));
+
+        // Paused with a blocking error is an error hold
+        for error_state in [
+            ErrorState::Jammed,
+            ErrorState::NoPaper,
+            ErrorState::DoorOpen,
+            ErrorState::ServiceRequested,
+        ] {
+            let held = paused(PrinterStatus::StoppedPrinting, error_state);
+            assert_eq!(held.pause_reason(), Some(PauseReason::ErrorHold));
+        }
+
+        // Paused flag plus Error flag in the raw PrinterState code
+        let mut raw = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+        raw.printer_state_code = Some(1 | 2);
+        assert_eq!(raw.pause_reason(), Some(PauseReason::ErrorHold));
+        raw.printer_state_code = Some(1);
+        assert_eq!(raw.pause_reason(), Some(PauseReason::Administrative));
+
+        // Unspecified problems leave the cause open
+        let unclear = paused(PrinterStatus::StoppedPrinting, ErrorState::UnknownError);
+        assert_eq!(unclear.pause_reason(), Some(PauseReason::Unknown));
+        let unknown_status = paused(PrinterStatus::StatusUnknown, ErrorState::NoError);
+        assert_eq!(unknown_status.pause_reason(), Some(PauseReason::Unknown));
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn test_colored_description() {
+        let change = PropertyChange::Status {
+            old: PrinterStatus::Idle,
+            new: PrinterStatus::Offline,
+        };
+
+        let colored = change.colored_description_with(true);
+        assert_eq!(
+            colored,
+            "Status: \x1b[31mIdle\x1b[39m → \x1b[32mOffline\x1b[39m"
+        );
+
+        let plain = change.colored_description_with(false);
+        assert_eq!(plain, change.description());
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    #[cfg(feature = "schema")]
+    fn test_printer_json_schema() {
+        let schema: serde_json::Value = serde_json::from_str(&printer_json_schema()).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        for field in [
+            "name",
+            "status",
+            "error_state",
+            "is_offline",
+            "printer_status_code",
+        ] {
+            assert!(properties.contains_key(field), "schema lacks {}", field);
+        }
+        // The status enums are described by their variant names
+        let definitions = schema["$defs"].to_string();
+        assert!(definitions.contains("StoppedPrinting"));
+        assert!(definitions.contains("Jammed"));
+
+        let changes = schemars::schema_for!(PrinterChanges);
+        assert!(
+            serde_json::to_string(&changes)
+                .unwrap()
+                .contains("JobsSinceReset")
+        );
+    }
+
+    #[test]
+    fn test_describe_with_locale() {
+        use std::collections::HashMap;
+
+        let locale = Locale::new(HashMap::from([
+            ("PaperJam", "Papierstau".to_string()),
+            ("Offline", "Hors ligne".to_string()),
+        ]))
+        .with_translation("Jammed", "Papierstau");
+
+        assert_eq!(PrinterState::PaperJam.describe_with(&locale), "Papierstau");
+        assert_eq!(PrinterState::PaperJam.description(), "Paper Jam");
+        assert_eq!(ErrorState::Jammed.describe_with(&locale), "Papierstau");
+
+        // Shared variant names translate for every enum
+        assert_eq!(PrinterStatus::Offline.describe_with(&locale), "Hors ligne");
+        assert_eq!(PrinterState::Offline.describe_with(&locale), "Hors ligne");
+
+        // Untranslated variants keep the English description
+        assert_eq!(PrinterStatus::Idle.describe_with(&locale), "Idle");
+        assert_eq!(
+            ErrorState::UnknownError.describe_with(&Locale::default()),
+            "Unknown Error State"
+        );
+        assert_eq!(locale.translate("Printing"), None);
+    }
+
+    #[test]
+    fn test_printers_sort_by_name() {
+        let printer = |name: &str, status| {
+            Printer::new(name.to_string(), status, ErrorState::NoError, false, false)
+        };
+        let mut printers = vec![
+            printer("Zebra", PrinterStatus::Idle),
+            printer("Brother", PrinterStatus::Printing),
+            printer("HP", PrinterStatus::Idle),
+            printer("Brother", PrinterStatus::Idle),
+        ];
+        printers.sort();
+        let names: Vec<&str> = printers.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["Brother", "Brother", "HP", "Zebra"]);
+        assert_eq!(printers[0].status(), &PrinterStatus::Idle);
+
+        // Consistent with equality, including the properties equality ignores
+        let hp = printer("HP", PrinterStatus::Idle);
+        let moved = hp.clone().with_port(Some("USB001".to_string()));
+        assert_eq!(hp, moved);
+        assert_eq!(hp.cmp(&moved), std::cmp::Ordering::Equal);
+        assert_ne!(
+            hp.cmp(&printer("HP", PrinterStatus::Printing)),
+            std::cmp::Ordering::Equal
+        );
+
+        let ordered: std::collections::BTreeMap<Printer, usize> =
+            printers.into_iter().rev().zip(0..).collect();
+        assert_eq!(ordered.keys().next().map(|p| p.name()), Some("Brother"));
+    }
+
+    #[test]
+    fn test_has_active_work() {
+        let printer = |status| {
+            Printer::new(
+                "Test Printer".to_string(),
+                status,
+                ErrorState::NoError,
+                false,
+                false,
+            )
+        };
+
+        // Status alone, with an unknown queue length
+        assert!(printer(PrinterStatus::Printing).has_active_work());
+        assert!(!printer(PrinterStatus::Idle).has_active_work());
+        assert!(!printer(PrinterStatus::Offline).has_active_work());
+
+        // Status combined with the queue length
+        for (status, jobs, expected) in [
+            (PrinterStatus::Idle, Some(0), false),
+            (PrinterStatus::Idle, Some(3), true),
+            (PrinterStatus::Printing, Some(0), true),
+            (PrinterStatus::Printing, Some(1), true),
+            (PrinterStatus::StoppedPrinting, Some(4), true),
+            (PrinterStatus::StoppedPrinting, Some(0), false),
+        ] {
+            let candidate = printer(status.clone()).with_jobs_queued(jobs);
+            assert_eq!(
+                candidate.has_active_work(),
+                expected,
+                "{:?} with {:?} queued",
+                status,
+                jobs
+            );
+        }
+
+        // Processing is only visible in the state
+        let processing = Printer::new_with_state(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            Some(PrinterState::Processing),
+            ErrorState::NoError,
+            false,
+            false,
+        )
+        .with_jobs_queued(Some(0));
+        assert!(processing.has_active_work());
+
+        // Raw Printing/Processing flags hidden behind a higher-priority flag
+        let mut raw = printer(PrinterStatus::Idle).with_jobs_queued(Some(0));
+        raw.printer_state_code = Some(131072 | 16384);
+        assert!(raw.has_active_work());
+        raw.printer_state_code = Some(131072);
+        assert!(!raw.has_active_work());
+
+        // The queue length is detail, not compared state
+        assert_eq!(
+            printer(PrinterStatus::Idle).with_jobs_queued(Some(5)),
+            printer(PrinterStatus::Idle)
+        );
+    }
+
+    #[test]
+    fn test_status_conflict() {
+        let printer = |status: PrinterStatus, error_state: ErrorState, wmi_status: Option<&str>| {
+            Printer::from_raw_codes(
+                "Test Printer".to_string(),
+                status,
+                None,
+                error_state,
+                false,
+                false,
+                RawStatusCodes {
+                    wmi_status: wmi_status.map(str::to_string),
+                    ..RawStatusCodes::default()
+                },
+            )
+        };
+
+        // Idle and error-free, yet WMI reports a problem
+        assert!(
+            printer(PrinterStatus::Idle, ErrorState::NoError, Some("Degraded"))
+                .has_status_conflict()
+        );
+        assert!(
+            printer(PrinterStatus::Printing, ErrorState::NoError, Some("Error"))
+                .has_status_conflict()
+        );
+
+        // Consistent signals
+        assert!(
+            !printer(PrinterStatus::Idle, ErrorState::NoError, Some("OK")).has_status_conflict()
+        );
+        assert!(!printer(PrinterStatus::Idle, ErrorState::NoError, None).has_status_conflict());
+        assert!(
+            !printer(PrinterStatus::Idle, ErrorState::Jammed, Some("Error")).has_status_conflict()
+        );
+        assert!(
+            !printer(PrinterStatus::Offline, ErrorState::NoError, Some("Error"))
+                .has_status_conflict()
+        );
+    }
+
+    #[test]
+    fn test_condition_helpers_check_every_field() {
+        let base = || {
+            Printer::new(
+                "Test Printer".to_string(),
+                PrinterStatus::Idle,
+                ErrorState::NoError,
+                false,
+                false,
+            )
+        };
+        assert!(!base().is_out_of_paper());
+        assert!(!base().is_jammed());
+        assert!(!base().is_door_open());
+        assert!(!base().is_low_toner());
+        assert!(!base().is_out_of_memory());
+
+        // Out of paper via the mapped ErrorState
+        let mut printer = base();
+        printer.error_state = ErrorState::NoPaper;
+        assert!(printer.is_out_of_paper());
+
+        // Jammed via the raw PrinterState flags, combined with another flag
+        let mut printer = base();
+        printer.printer_state_code = Some(8 | 1024);
+        assert!(printer.is_jammed());
+        assert!(!printer.is_out_of_paper());
+
+        // Manual feed via the decoded PrinterState or a raw flag hidden by Printing
+        assert!(!base().needs_manual_feed());
+        let mut printer = base();
+        printer.state = Some(PrinterState::ManualFeed);
+        assert!(printer.needs_manual_feed());
+        let mut printer = base();
+        printer.printer_state_code = Some(32 | 1024 | 131072);
+        assert!(printer.needs_manual_feed());
+        printer.printer_state_code = Some(1024);
+        assert!(!printer.needs_manual_feed());
+
+        // Out of memory via the decoded PrinterState, a raw flag or an extended code
+        let mut printer = base();
+        printer.state = Some(PrinterState::OutOfMemory);
+        assert!(printer.is_out_of_memory());
+        let mut printer = base();
+        printer.printer_state_code = Some(2097152 | 1024);
+        assert!(printer.is_out_of_memory());
+        let mut printer = base();
+        printer.extended_detected_error_state_code = Some(14);
+        assert!(printer.is_out_of_memory());
+        printer.extended_detected_error_state_code = Some(15);
+        assert!(!printer.is_out_of_memory());
+
+        // Door open via the decoded PrinterState
+        let mut printer = base();
+        printer.state = Some(PrinterState::DoorOpen);
+        assert!(printer.is_door_open());
+
+        // Low toner via ExtendedDetectedErrorState only
+        let mut printer = base();
+        printer.extended_detected_error_state_code = Some(5);
+        assert!(printer.is_low_toner());
+        assert!(!printer.is_jammed());
+
+        // Out of paper via the raw DetectedErrorState code only
+        let mut printer = base();
+        printer.detected_error_state_code = Some(4);
+        assert!(printer.is_out_of_paper());
+    }
+
+    #[test]
+    fn test_redirected_detection() {
+        let named = |name: &str| {
+            Printer::new(
+                name.to_string(),
+                PrinterStatus::Idle,
+                ErrorState::NoError,
+                false,
+                false,
+            )
+        };
+
+        // Name heuristics
+        assert!(named("HP LaserJet (redirected 3)").is_redirected());
+        assert!(named("Canon MF (from LAPTOP-42) in session 7").is_redirected());
+        assert!(!named("HP LaserJet").is_redirected());
+        assert!(!named("Printer from accounting").is_redirected());
+
+        // Attribute-based: PRINTER_ATTRIBUTE_TS alongside Local (0x40)
+        let redirected = named("Client Printer").with_attributes(Some(0x8000 | 0x40));
+        assert!(redirected.is_redirected());
+        let local = named("Client Printer").with_attributes(Some(0x40));
+        assert!(!local.is_redirected());
+    }
+
+    #[test]
+    fn test_host_from_port_names_and_uris() {
+        let host = |port: &str| {
+            Printer::new(
+                "Test Printer".to_string(),
+                PrinterStatus::Idle,
+                ErrorState::NoError,
+                false,
+                false,
+            )
+            .with_port(Some(port.to_string()))
+            .host()
+        };
+
+        // Windows port names
+        assert_eq!(host("IP_192.168.1.20").as_deref(), Some("192.168.1.20"));
+        assert_eq!(host("192.168.1.21").as_deref(), Some("192.168.1.21"));
+        assert_eq!(host("192.168.1.22_1").as_deref(), Some("192.168.1.22"));
+        assert_eq!(
+            host("printer01.corp.local").as_deref(),
+            Some("printer01.corp.local")
+        );
+        assert_eq!(host("\\\\printsrv\\Finance").as_deref(), Some("printsrv"));
+        assert_eq!(host("WSD-4c7b2c1e-1f2a-4c5d-9e8f-0a1b2c3d4e5f"), None);
+        assert_eq!(host("USB001"), None);
+        assert_eq!(host("LPT1:"), None);
+        assert_eq!(host("PORTPROMPT:"), None);
+
+        // Device URIs
+        assert_eq!(host("socket://10.0.0.5:9100").as_deref(), Some("10.0.0.5"));
+        assert_eq!(
+            host("ipp://printer.local/ipp/print").as_deref(),
+            Some("printer.local")
+        );
+        assert_eq!(
+            host("ipps://admin@10.0.0.6:631/printers/hp").as_deref(),
+            Some("10.0.0.6")
+        );
+        assert_eq!(host("lpd://10.0.0.7/queue").as_deref(), Some("10.0.0.7"));
+        assert_eq!(host("ipp://[fe80::1]:631/ipp").as_deref(), Some("fe80::1"));
+        assert_eq!(host("smb://printsrv/Finance").as_deref(), Some("printsrv"));
+        assert_eq!(host("usb://HP/LaserJet?serial=123"), None);
+        assert_eq!(host("dnssd://HP%20LaserJet._ipp._tcp.local/"), None);
+        assert_eq!(host("file:///dev/null"), None);
+
+        let unset = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+        assert_eq!(unset.host(), None);
+    }
+
+    #[test]
+    fn test_printer_attributes_decoding() {
+        // Typical local shared printer: Shared | Local | DoComplete | EnableBidi
+        let local_shared = PrinterAttributes::from_bits(0x0A48);
+        assert_eq!(local_shared.raw, 0x0A48);
+        assert!(local_shared.shared);
+        assert!(local_shared.local);
+        assert!(local_shared.do_complete_first);
+        assert!(local_shared.enable_bidi);
+        assert!(!local_shared.network && !local_shared.hidden && !local_shared.default);
+
+        // Network connection that is the default and published
+        let network = PrinterAttributes::from_bits(0x2014);
+        assert!(network.network && network.default && network.published);
+        assert!(!network.local);
+
+        // Hidden direct raw-only fax queue
+        let fax = PrinterAttributes::from_bits(0x5022);
+        assert!(fax.hidden && fax.direct && fax.raw_only && fax.fax);
+
+        let printer = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+        assert_eq!(printer.attributes(), PrinterAttributes::default());
+        let printer = printer.with_attributes(Some(0x8000 | 0x40));
+        assert!(printer.attributes().terminal_server);
+        assert_eq!(printer.attributes_code(), Some(0x8040));
+    }
+
+    #[test]
+    fn test_printer_state_from_cups_reasons() {
+        assert_eq!(PrinterState::from_cups(3, &["none"]), PrinterState::None);
+        assert_eq!(
+            PrinterState::from_cups(4, &["none"]),
+            PrinterState::Printing
+        );
+        assert_eq!(PrinterState::from_cups(5, &["none"]), PrinterState::Paused);
+        assert_eq!(
+            PrinterState::from_cups(5, &["media-empty-error"]),
+            PrinterState::PaperOut
+        );
+        assert_eq!(
+            PrinterState::from_cups(3, &["toner-low-report"]),
+            PrinterState::TonerLow
+        );
+        assert_eq!(
+            PrinterState::from_cups(5, &["cover-open-error", "toner-low-warning"]),
+            PrinterState::DoorOpen
+        );
+        assert_eq!(
+            PrinterState::from_cups(5, &["paused"]),
+            PrinterState::Paused
+        );
+        assert_eq!(
+            PrinterState::from_cups(4, &["connecting-to-device"]),
+            PrinterState::Processing
+        );
+        assert_eq!(
+            PrinterState::from_cups(9, &[] as &[&str]),
+            PrinterState::StatusUnknown
+        );
+    }
+
+    #[test]
+    fn test_compare_with_options_ignores_selected_fields() {
+        let before = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+        let mut flapped = before.clone();
+        flapped.wmi_status = Some("Unknown".to_string());
+        flapped.printer_state_code = Some(0);
+
+        let options = CompareOptions {
+            ignore_wmi_status: true,
+            ignore_state_code: true,
+            ..CompareOptions::default()
+        };
+        assert!(
+            !before
+                .compare_with_options(&flapped, &options)
+                .has_changes()
+        );
+        assert_eq!(before.compare_with(&flapped).change_count(), 2);
+
+        let mut printing = flapped.clone();
+        printing.status = PrinterStatus::Printing;
+        let changes = before.compare_with_options(&printing, &options);
+        assert_eq!(changes.change_count(), 1);
+        assert_eq!(changes.changes[0].property_name(), "Status");
+    }
+
+    #[test]
+    fn test_unpublish_detected() {
+        let published = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        )
+        .with_published(true);
+        let unpublished = published.clone().with_published(false);
+        assert_ne!(published, unpublished);
+
+        let changes = published.compare_with(&unpublished);
+        assert_eq!(
+            changes.changes,
+            vec![PropertyChange::IsPublished {
+                old: true,
+                new: false
+            }]
+        );
+        assert_eq!(
+            changes.changes[0].description(),
+            "IsPublished: true → false"
+        );
+
+        let options = CompareOptions {
+            ignore_published: true,
+            ..CompareOptions::default()
+        };
+        assert!(
+            !published
+                .compare_with_options(&unpublished, &options)
+                .has_changes()
+        );
+    }
+
+    #[test]
+    fn test_priority_change_detected() {
+        let before = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        )
+        .with_priority(Some(1));
+        let after = before.clone().with_priority(Some(50));
+
+        let changes = before.compare_with(&after);
+        assert_eq!(changes.change_count(), 1);
+        assert_eq!(
+            changes.changes[0].description(),
+            "Priority: Some(1) → Some(50)"
+        );
+
+        let options = CompareOptions {
+            ignore_priority: true,
+            ..CompareOptions::default()
+        };
+        assert!(!before.compare_with_options(&after, &options).has_changes());
+    }
+
+    #[test]
+    fn test_zero_detected_error_state_mapping() {
+        assert_eq!(ErrorState::from_u32(Some(0), true), ErrorState::NoError);
+        assert_eq!(
+            ErrorState::from_u32(Some(0), false),
+            ErrorState::UnknownError
+        );
+        // Other codes are unaffected by the setting
+        for strict in [true, false] {
+            assert_eq!(ErrorState::from_u32(Some(2), strict), ErrorState::NoError);
+            assert_eq!(ErrorState::from_u32(Some(8), strict), ErrorState::Jammed);
+            assert_eq!(ErrorState::from_u32(None, strict), ErrorState::UnknownError);
+        }
+    }
+
+    #[test]
+    fn test_lenient_u32_coerces_status_codes() {
+        #[derive(Deserialize)]
+        struct Row {
+            #[serde(rename = "PrinterStatus", default, deserialize_with = "lenient_u32")]
+            printer_status: Option<u32>,
+        }
+        let status = |json: &str| serde_json::from_str::<Row>(json).unwrap().printer_status;
+
+        assert_eq!(status(r#"{"PrinterStatus": 3}"#), Some(3));
+        assert_eq!(status(r#"{"PrinterStatus": "3"}"#), Some(3));
+        assert_eq!(status(r#"{"PrinterStatus": " 4 "}"#), Some(4));
+        assert_eq!(status(r#"{"PrinterStatus": -1}"#), Some(u32::MAX));
+        assert_eq!(status(r#"{"PrinterStatus": "-2"}"#), Some(u32::MAX - 1));
+        assert_eq!(status(r#"{"PrinterStatus": "idle"}"#), None);
+        assert_eq!(status(r#"{"PrinterStatus": null}"#), None);
+        assert_eq!(status("{}"), None);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_win32_printer_status_as_string() {
+        let row: Win32Printer = serde_json::from_str(
+            r#"{"Name": "HP LaserJet", "PrinterStatus": "3", "DetectedErrorState": 2}"#,
+        )
+        .unwrap();
+        assert_eq!(row.printer_status, Some(3));
+
+        let printer = Printer::from(row);
+        assert_eq!(printer.status(), &PrinterStatus::Idle);
+        assert_eq!(printer.error_state(), &ErrorState::NoError);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_msft_printer_row() {
+        let row = |json: &str| {
+            Printer::from(Win32Printer::from(
+                serde_json::from_str::<MsftPrinter>(json).unwrap(),
+            ))
+        };
+
+        let idle = row(r#"{"Name": "HP LaserJet", "PrinterStatus": 0, "PortName": "IP_10.0.0.5"}"#);
+        assert_eq!(idle.status(), &PrinterStatus::Idle);
+        assert!(!idle.is_offline());
+        assert_eq!(idle.port(), Some("IP_10.0.0.5"));
+
+        let offline = row(r#"{"Name": "HP LaserJet", "PrinterStatus": 8}"#);
+        assert_eq!(offline.printer_state_code(), Some(128));
+        assert!(offline.is_offline());
+
+        let jammed = row(r#"{"Name": "HP LaserJet", "PrinterStatus": 4}"#);
+        assert!(jammed.is_jammed());
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_zero_detected_error_state_from_wmi() {
+        let row = || Win32Printer {
+            name: Some("HP LaserJet".to_string()),
+            printer_status: Some(3),
+            detected_error_state: Some(0),
+            ..Win32Printer::default()
+        };
+
+        assert_eq!(Printer::from(row()).error_state(), &ErrorState::NoError);
+        assert_eq!(
+            Printer::from_wmi(row(), true).error_state(),
+            &ErrorState::NoError
+        );
+        assert_eq!(
+            Printer::from_wmi(row(), false).error_state(),
+            &ErrorState::UnknownError
+        );
+    }
+
+    #[test]
+    fn test_jobs_printed_between_snapshots() {
+        let printer = |jobs| {
+            Printer::new(
+                "HP LaserJet".to_string(),
+                PrinterStatus::Idle,
+                ErrorState::NoError,
+                false,
+                false,
+            )
+            .with_jobs_since_reset(jobs)
+        };
+
+        let changes = printer(Some(120)).compare_with(&printer(Some(135)));
+        assert_eq!(changes.jobs_printed(), Some(15));
+        assert_eq!(
+            changes.changes[0].description(),
+            "JobsSinceReset: Some(120) → Some(135)"
+        );
+        assert_ne!(printer(Some(120)), printer(Some(135)));
+
+        // The counter was reset in between; only the jobs since then are known
+        assert_eq!(
+            printer(Some(500))
+                .compare_with(&printer(Some(4)))
+                .jobs_printed(),
+            Some(4)
+        );
+
+        assert_eq!(
+            printer(Some(7))
+                .compare_with(&printer(Some(7)))
+                .jobs_printed(),
+            None
+        );
+        assert_eq!(
+            printer(None).compare_with(&printer(Some(3))).jobs_printed(),
+            None
+        );
+
+        let options = CompareOptions {
+            ignore_jobs_since_reset: true,
+            ..CompareOptions::default()
+        };
+        assert!(
+            !printer(Some(1))
+                .compare_with_options(&printer(Some(2)), &options)
+                .has_changes()
+        );
+    }
+
+    #[test]
+    fn test_fax_detection() {
+        let named = |name: &str| {
+            Printer::new(
+                name.to_string(),
+                PrinterStatus::Idle,
+                ErrorState::NoError,
+                false,
+                false,
+            )
+        };
+
+        assert!(
+            named("Front Desk")
+                .with_driver(Some("Microsoft Shared Fax Driver".to_string()))
+                .is_fax()
+        );
+        assert!(
+            named("Sales")
+                .with_driver(Some("HP LaserJet FAX".to_string()))
+                .is_fax()
+        );
+        assert!(named("Fax").with_port(Some("SHRFAX:".to_string())).is_fax());
+        assert!(
+            named("Office_Fax")
+                .with_port(Some("hpfax:/usb/Officejet?serial=CN123".to_string()))
+                .is_fax()
+        );
+        assert!(named("Front Desk").with_attributes(Some(0x4000)).is_fax());
+
+        assert!(
+            !named("HP LaserJet")
+                .with_driver(Some("HP Universal Printing PCL 6".to_string()))
+                .with_port(Some("IP_192.168.1.20".to_string()))
+                .is_fax()
+        );
+        assert!(!named("Fax Room Printer").is_fax());
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_jobs_since_reset_from_wmi() {
+        let printer = Printer::from(Win32Printer {
+            name: Some("HP LaserJet".to_string()),
+            printer_status: Some(3),
+            job_count_since_last_reset: Some(1234),
+            ..Win32Printer::default()
+        });
+        assert_eq!(printer.jobs_since_reset(), Some(1234));
+    }
+
+    #[test]
+    fn test_server_name_from_unc_name() {
+        let printer = |name: &str| {
+            Printer::new(
+                name.to_string(),
+                PrinterStatus::Idle,
+                ErrorState::NoError,
+                false,
+                false,
+            )
+        };
+
+        let network = printer(r"\\PRINTSRV01\HP-Floor3");
+        assert_eq!(network.server_name().as_deref(), Some("PRINTSRV01"));
+        assert_eq!(network.share_name().as_deref(), Some("HP-Floor3"));
+
+        // Local printers and malformed names have no server
+        for name in ["HP LaserJet", r"\\PRINTSRV01", r"\\\HP", r"\\PRINTSRV01\"] {
+            assert_eq!(printer(name).server_name(), None, "{}", name);
+            assert_eq!(printer(name).share_name(), None, "{}", name);
+        }
+
+        // A reported server name wins, without its leading backslashes
+        let reported = printer("HP-Floor3").with_server_name(Some(r"\\PRINTSRV02".to_string()));
+        assert_eq!(reported.server_name().as_deref(), Some("PRINTSRV02"));
+        assert_eq!(reported.share_name(), None);
+        let blank = network.with_server_name(Some(String::new()));
+        assert_eq!(blank.server_name().as_deref(), Some("PRINTSRV01"));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_published_from_wmi() {
+        let published = Printer::from(Win32Printer {
+            name: Some("HP LaserJet".to_string()),
+            printer_status: Some(3),
+            published: Some(true),
+            ..Win32Printer::default()
+        });
+        assert!(published.is_published());
+
+        for flag in [Some(false), None] {
+            let printer = Printer::from(Win32Printer {
+                name: Some("HP LaserJet".to_string()),
+                published: flag,
+                ..Win32Printer::default()
+            });
+            assert!(!printer.is_published());
+        }
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_priority_from_wmi() {
+        let printer = Printer::from(Win32Printer {
+            name: Some("HP LaserJet".to_string()),
+            printer_status: Some(3),
+            priority: Some(42),
+            ..Win32Printer::default()
+        });
+        assert_eq!(printer.priority(), Some(42));
+
+        let printer = Printer::from(Win32Printer {
+            name: Some("HP LaserJet".to_string()),
+            ..Win32Printer::default()
+        });
+        assert_eq!(printer.priority(), None);
+    }
+
+    #[test]
+    fn test_wmi_operational_status_parsing() {
+        let known = [
+            ("OK", WmiOperationalStatus::Ok),
+            ("Error", WmiOperationalStatus::Error),
+            ("Degraded", WmiOperationalStatus::Degraded),
+            (
+                "Unknown",
+                WmiOperationalStatus::Unknown("Unknown".to_string()),
+            ),
+            ("Pred Fail", WmiOperationalStatus::PredFail),
+            ("Starting", WmiOperationalStatus::Starting),
+            ("Stopping", WmiOperationalStatus::Stopping),
+            ("Service", WmiOperationalStatus::Service),
+            ("Stressed", WmiOperationalStatus::Stressed),
+            ("NonRecover", WmiOperationalStatus::NonRecover),
+            ("No Contact", WmiOperationalStatus::NoContact),
+            ("Lost Comm", WmiOperationalStatus::LostComm),
+        ];
+        for (value, expected) in known {
+            let parsed: WmiOperationalStatus = value.parse().unwrap();
+            assert_eq!(parsed, expected);
+            assert_eq!(parsed.as_str(), value);
+        }
+
+        let other: WmiOperationalStatus = "Busy".parse().unwrap();
+        assert_eq!(other, WmiOperationalStatus::Unknown("Busy".to_string()));
+        assert!(!other.is_problem());
+        assert!(WmiOperationalStatus::LostComm.is_problem());
+
+        let mut printer = Printer::new(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+        assert_eq!(printer.wmi_operational_status(), None);
+        printer.wmi_status = Some("Degraded".to_string());
+        assert_eq!(
+            printer.wmi_operational_status(),
+            Some(WmiOperationalStatus::Degraded)
+        );
+    }
+
+    #[test]
+    fn test_from_raw_codes_diff() {
+        let codes = RawStatusCodes {
+            printer_status_code: Some(3),
+            printer_state_code: Some(0),
+            detected_error_state_code: Some(2),
+            extended_detected_error_state_code: Some(0),
+            extended_printer_status_code: Some(2),
+            wmi_status: Some("OK".to_string()),
+        };
+        let ready = Printer::from_raw_codes(
+            "Test Printer".to_string(),
+            PrinterStatus::Idle,
+            Some(PrinterState::None),
+            ErrorState::NoError,
+            false,
+            false,
+            codes.clone(),
+        );
+        assert_eq!(ready.printer_status_code(), Some(3));
+        assert_eq!(ready.wmi_status(), Some("OK"));
+
+        let jammed = Printer::from_raw_codes(
+            "Test Printer".to_string(),
+            PrinterStatus::Other,
+            Some(PrinterState::PaperJam),
+            ErrorState::Jammed,
+            false,
+            false,
+            RawStatusCodes {
+                printer_status_code: Some(1),
+                printer_state_code: Some(8),
+                detected_error_state_code: Some(8),
+                wmi_status: Some("Error".to_string()),
+                ..codes
+            },
+        );
+        assert!(jammed.is_jammed());
+
+        let changes = ready.compare_with(&jammed);
+        let changed: Vec<&str> = changes
+            .changes
+            .iter()
+            .map(PropertyChange::property_name)
+            .collect();
+        assert_eq!(
+            changed,
+            vec![
+                "Status",
+                "State",
+                "ErrorState",
+                "PrinterStatusCode",
+                "PrinterStateCode",
+                "DetectedErrorStateCode",
+                "WmiStatus",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_virtual_printer_detection() {
+        let named = |name: &str| {
+            Printer::new(
+                name.to_string(),
+                PrinterStatus::Idle,
+                ErrorState::NoError,
+                false,
+                false,
+            )
+        };
+
+        assert!(named("Microsoft Print to PDF").is_virtual());
+        assert!(named("Microsoft XPS Document Writer").is_virtual());
+        assert!(named("Send To OneNote 2016").is_virtual());
+        assert!(named("Cups-PDF").is_virtual());
+        assert!(!named("HP LaserJet Pro").is_virtual());
+
+        let fax = named("Fax");
+        assert!(!fax.is_virtual());
+        assert!(fax.is_virtual_with(&["fax"]));
+        assert!(!named("HP LaserJet Pro").is_virtual_with(&["fax"]));
+    }
+
+    #[test]
+    #[cfg(feature = "binary")]
+    fn test_binary_round_trip() {
+        let printer = Printer::new(
+            "HP LaserJet".to_string(),
+            PrinterStatus::Printing,
+            ErrorState::LowToner,
+            false,
+            true,
+        )
+        .with_location(Some("Floor 2".to_string()))
+        .with_tags(vec!["finance".to_string()]);
+        let bytes = printer.to_bytes().unwrap();
+        assert!(bytes.len() < serde_json::to_vec(&printer).unwrap().len());
+        let decoded = Printer::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, printer);
+        assert_eq!(decoded.tags(), printer.tags());
+
+        let mut changes = PrinterChanges::new("HP LaserJet".to_string());
+        changes.changes = vec![
+            PropertyChange::Status {
+                old: PrinterStatus::Idle,
+                new: PrinterStatus::Printing,
+            },
+            PropertyChange::State {
+                old: None,
+                new: Some(PrinterState::TonerLow),
+            },
+        ];
+        let decoded = PrinterChanges::from_bytes(&changes.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded.printer_name, changes.printer_name);
+        assert_eq!(decoded.changes, changes.changes);
+        assert_eq!(decoded.timestamp, changes.timestamp);
+
+        assert!(Printer::from_bytes(b"not msgpack").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "binary")]
+    fn test_binary_decodes_snapshots_without_newer_fields() {
+        let printer = Printer::new(
+            "HP LaserJet".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        );
+
+        // A snapshot written before the optional fields existed
+        let mut older = serde_json::to_value(&printer).unwrap();
+        for field in [
+            "color_mode",
+            "duplex_mode",
+            "location",
+            "comment",
+            "priority",
+            "port",
+            "jobs_since_reset",
+            "jobs_queued",
+            "driver",
+            "is_published",
+            "server",
+            "queue_stopped",
+            "tags",
+        ] {
+            older.as_object_mut().unwrap().remove(field);
+        }
+        let bytes = rmp_serde::to_vec_named(&older).unwrap();
+
+        assert_eq!(Printer::from_bytes(&bytes).unwrap(), printer);
+    }
+
+    #[test]
+    fn test_alert_message() {
+        use chrono::TimeZone;
+
+        let at = |changes: Vec<PropertyChange>| {
+            let mut set = PrinterChanges::new("HP".to_string());
+            set.timestamp = chrono::Utc.with_ymd_and_hms(2025, 3, 4, 14, 3, 27).unwrap();
+            set.changes = changes;
+            set.alert_message()
+        };
+        let offline = PropertyChange::IsOffline {
+            old: false,
+            new: true,
+        };
+        let jammed = PropertyChange::ErrorState {
+            old: ErrorState::NoError,
+            new: ErrorState::Jammed,
+        };
+        let to_offline = PropertyChange::Status {
+            old: PrinterStatus::Idle,
+            new: PrinterStatus::Offline,
+        };
+        let codes = PropertyChange::PrinterStatusCode {
+            old: Some(3),
+            new: Some(7),
+        };
+
+        assert_eq!(
+            at(vec![
+                to_offline.clone(),
+                jammed.clone(),
+                offline,
+                codes.clone()
+            ]),
+            "Printer 'HP' went OFFLINE and reported Jammed at 14:03 UTC"
+        );
+        assert_eq!(
+            at(vec![
+                PropertyChange::Status {
+                    old: PrinterStatus::StoppedPrinting,
+                    new: PrinterStatus::Printing,
+                },
+                PropertyChange::ErrorState {
+                    old: ErrorState::NoPaper,
+                    new: ErrorState::NoError,
+                },
+            ]),
+            "Printer 'HP' recovered from No Paper and is now Printing at 14:03 UTC"
+        );
+        assert_eq!(
+            at(vec![
+                PropertyChange::IsOffline {
+                    old: true,
+                    new: false,
+                },
+                to_offline,
+            ]),
+            "Printer 'HP' came back ONLINE at 14:03 UTC"
+        );
+        assert_eq!(
+            at(vec![
+                codes,
+                PropertyChange::Location {
+                    old: None,
+                    new: Some("Floor 2".to_string()),
+                },
+                PropertyChange::Priority {
+                    old: Some(1),
+                    new: Some(2),
+                },
+            ]),
+            "Printer 'HP' changed PrinterStatusCode, Location and Priority at 14:03 UTC"
+        );
+        assert_eq!(at(vec![]), "Printer 'HP' reported no changes at 14:03 UTC");
+    }
+
+    #[test]
+    fn test_timestamp_in_time_zone() {
+        use chrono::{FixedOffset, TimeZone, Timelike};
+
+        let mut changes = PrinterChanges::new("HP".to_string());
+        changes.timestamp = chrono::Utc
+            .with_ymd_and_hms(2025, 12, 31, 22, 45, 0)
+            .unwrap();
+
+        let kolkata = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+        let local = changes.timestamp_in(&kolkata);
+        assert_eq!((local.hour(), local.minute()), (4, 15));
+        assert_eq!(local, changes.timestamp);
+        assert_eq!(
+            changes.format_timestamp(&kolkata, "%Y-%m-%d %H:%M %:z"),
+            "2026-01-01 04:15 +05:30"
+        );
+
+        let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+        assert_eq!(
+            changes.format_timestamp(&new_york, "%H:%M %:z"),
+            "17:45 -05:00"
+        );
+        // The stored value is untouched
+        assert_eq!(changes.timestamp.hour(), 22);
+    }
+
+    #[test]
+    fn test_change_rows() {
+        let before = Printer::new(
+            "HP LaserJet".to_string(),
+            PrinterStatus::Idle,
+            ErrorState::NoError,
+            false,
+            false,
+        )
+        .with_location(Some("Floor 2".to_string()));
+        let after = Printer::new(
+            "HP LaserJet".to_string(),
+            PrinterStatus::Printing,
+            ErrorState::NoError,
+            true,
+            false,
+        );
+
+        let changes = before.compare_with(&after);
+        let row = |property: &str, old: &str, new: &str| ChangeRow {
+            property: property.to_string(),
+            old: old.to_string(),
+            new: new.to_string(),
+        };
+        assert_eq!(
+            changes.rows(),
+            vec![
+                row("Status", "Idle", "Printing"),
+                row("IsOffline", "false", "true"),
+                row("Location", "Some(\"Floor 2\")", "None"),
+            ]
+        );
+        for (row, change) in changes.rows().iter().zip(&changes.changes) {
+            assert_eq!(
+                format!("{}: {} → {}", row.property, row.old, row.new),
+                change.description()
+            );
+        }
+    }
+
+    #[test]
+    fn test_changes_to_delta_json() {
+        let mut changes = PrinterChanges::new("Test Printer".to_string());
+        changes.changes = vec![
+            PropertyChange::Status {
+                old: PrinterStatus::Idle,
+                new: PrinterStatus::StoppedPrinting,
+            },
+            PropertyChange::IsOffline {
+                old: false,
+                new: true,
+            },
+            PropertyChange::ErrorState {
+                old: ErrorState::NoError,
+                new: ErrorState::Jammed,
+            },
+            PropertyChange::Location {
+                old: None,
+                new: Some("Floor 2".to_string()),
+            },
+            PropertyChange::PrinterStateCode {
+                old: Some(0),
+                new: Some(8),
+            },
+            PropertyChange::PrinterStateCode {
+                old: Some(8),
+                new: Some(136),
+            },
+        ];
+
+        let delta = changes.to_delta();
+        assert_eq!(delta.len(), 5);
+        assert_eq!(
+            serde_json::to_value(&delta).unwrap(),
+            serde_json::json!({
+                "ErrorState": ["NoError", "Jammed"],
+                "IsOffline": ["false", "true"],
+                "Location": [null, "Floor 2"],
+                "PrinterStateCode": ["0", "136"],
+                "Status": ["Idle", "StoppedPrinting"],
+            })
+        );
+
+        let round_trip: PrinterDelta =
+            serde_json::from_str(&serde_json::to_string(&delta).unwrap()).unwrap();
+        assert_eq!(round_trip, delta);
+        assert!(
+            PrinterChanges::new("Test Printer".to_string())
+                .to_delta()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_merge_collapses_successive_changes() {
+        let mut first = PrinterChanges::new("Test Printer".to_string());
+        first.changes.push(PropertyChange::Status {
+            old: PrinterStatus::Idle,
+            new: PrinterStatus::Printing,
+        });
+        first.changes.push(PropertyChange::IsOffline {
+            old: false,
+            new: true,
+        });
+
+        let mut second = PrinterChanges::new("Test Printer".to_string());
+        second.changes.push(PropertyChange::Status {
+            old: PrinterStatus::Printing,
+            new: PrinterStatus::Other,
+        });
+        second.changes.push(PropertyChange::IsOffline {
+            old: true,
+            new: false,
+        });
+        second.changes.push(PropertyChange::ErrorState {
+            old: ErrorState::NoError,
+            new: ErrorState::Jammed,
+        });
+
+        first.merge(&second);
+
+        assert_eq!(
+            first.changes,
+            vec![
+                PropertyChange::Status {
+                    old: PrinterStatus::Idle,
+                    new: PrinterStatus::Other,
+                },
+                PropertyChange::ErrorState {
+                    old: ErrorState::NoError,
+                    new: ErrorState::Jammed,
+                },
+            ]
+        );
+        assert!(first.timestamp >= second.timestamp);
+    }
 }
@@ -0,0 +1,438 @@
+use crate::{Printer, PrinterChanges, PrinterStatus};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+/// Turns successive printer observations into time-in-state statistics.
+///
+/// Each observation closes the interval since the previous one and attributes it
+/// to the status seen at the start of that interval. Printers flagged offline
+/// count as [`PrinterStatus::Offline`] regardless of their reported status. The
+/// first observation only opens an interval; observations older than the
+/// previous one are ignored.
+///
+/// # Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use printer_event_handler::{ErrorState, Printer, PrinterStatus, StateAccumulator};
+///
+/// let printer = |status| Printer::new("HP".to_string(), status, ErrorState::NoError, false, false);
+/// let start = Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+///
+/// let mut stats = StateAccumulator::new();
+/// stats.observe(&printer(PrinterStatus::Idle), start);
+/// stats.observe(&printer(PrinterStatus::Printing), start + Duration::minutes(45));
+/// stats.observe(&printer(PrinterStatus::Idle), start + Duration::minutes(60));
+///
+/// assert_eq!(stats.duration_in(&PrinterStatus::Printing), Duration::minutes(15));
+/// assert_eq!(stats.percentage_in(&PrinterStatus::Idle), 75.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StateAccumulator {
+    totals: HashMap<PrinterStatus, Duration>,
+    last: Option<(PrinterStatus, DateTime<Utc>)>,
+}
+
+impl StateAccumulator {
+    /// Creates an accumulator with no observations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the printer as observed at the given time
+    pub fn observe(&mut self, printer: &Printer, at: DateTime<Utc>) {
+        let status = if printer.is_offline() {
+            PrinterStatus::Offline
+        } else {
+            printer.status().clone()
+        };
+
+        match self.last.take() {
+            Some((previous, since)) if at < since => {
+                // Out of order; keep the newer observation
+                self.last = Some((previous, since));
+                return;
+            }
+            Some((previous, since)) => {
+                *self.totals.entry(previous).or_insert_with(Duration::zero) += at - since;
+            }
+            None => {}
+        }
+
+        self.last = Some((status, at));
+    }
+
+    /// Returns the total time spent in the given status
+    pub fn duration_in(&self, status: &PrinterStatus) -> Duration {
+        self.totals
+            .get(status)
+            .copied()
+            .unwrap_or_else(Duration::zero)
+    }
+
+    /// Returns the total time covered by the observations
+    pub fn total(&self) -> Duration {
+        self.totals
+            .values()
+            .fold(Duration::zero(), |sum, d| sum + *d)
+    }
+
+    /// Returns the share of the observed time spent in the given status, from 0 to 100
+    pub fn percentage_in(&self, status: &PrinterStatus) -> f64 {
+        let total = self.total().num_milliseconds();
+        if total == 0 {
+            return 0.0;
+        }
+        self.duration_in(status).num_milliseconds() as f64 * 100.0 / total as f64
+    }
+
+    /// Returns the percentage of observed time per status, for every status seen
+    pub fn breakdown(&self) -> HashMap<PrinterStatus, f64> {
+        self.totals
+            .keys()
+            .map(|status| (status.clone(), self.percentage_in(status)))
+            .collect()
+    }
+}
+
+/// Counts how often each property changes over a monitoring session.
+///
+/// Feed it every [`PrinterChanges`] reported by a monitoring loop; frequently
+/// changing properties and printers point at flapping devices. Properties are
+/// identified by [`crate::PropertyChange::property_name`].
+///
+/// # Example
+/// ```
+/// use printer_event_handler::{ChangeStats, ErrorState, Printer, PrinterStatus};
+///
+/// let idle = Printer::new("HP".to_string(), PrinterStatus::Idle, ErrorState::NoError, false, false);
+/// let offline = Printer::new("HP".to_string(), PrinterStatus::Offline, ErrorState::NoError, true, false);
+///
+/// let mut stats = ChangeStats::new();
+/// stats.record(&idle.compare_with(&offline));
+/// stats.record(&offline.compare_with(&idle));
+///
+/// assert_eq!(stats.change_count("IsOffline"), 2);
+/// assert_eq!(stats.printer_change_count("HP"), 4);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChangeStats {
+    by_property: HashMap<&'static str, usize>,
+    by_printer: HashMap<String, usize>,
+}
+
+impl ChangeStats {
+    /// Creates empty statistics
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tallies every property change in the given change set
+    pub fn record(&mut self, changes: &PrinterChanges) {
+        if changes.changes.is_empty() {
+            return;
+        }
+        for change in &changes.changes {
+            *self.by_property.entry(change.property_name()).or_default() += 1;
+        }
+        *self
+            .by_printer
+            .entry(changes.printer_name.clone())
+            .or_default() += changes.changes.len();
+    }
+
+    /// Returns how many times the given property changed, across all printers
+    pub fn change_count(&self, property: &str) -> usize {
+        self.by_property.get(property).copied().unwrap_or(0)
+    }
+
+    /// Returns how many property changes were recorded for the given printer
+    pub fn printer_change_count(&self, printer_name: &str) -> usize {
+        self.by_printer.get(printer_name).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of property changes recorded
+    pub fn total(&self) -> usize {
+        self.by_property.values().sum()
+    }
+
+    /// Returns the property that changed most often, ties going to the name
+    /// that sorts first, or `None` if nothing was recorded
+    pub fn most_volatile_property(&self) -> Option<&'static str> {
+        self.by_property
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(property, _)| *property)
+    }
+
+    /// Returns the printer with the most property changes, ties going to the
+    /// name that sorts first, or `None` if nothing was recorded
+    pub fn most_volatile_printer(&self) -> Option<&str> {
+        self.by_printer
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+/// Tracks how long a printer has been continuously idle.
+///
+/// Fed timed observations like [`StateAccumulator`], it remembers when the
+/// current idle stretch began, so "safe to submit" logic can wait until a printer
+/// has settled instead of racing a job into it mid-transition. A printer counts
+/// as idle when its status is [`PrinterStatus::Idle`], it is online and it has no
+/// active work (see [`Printer::has_active_work`]). Idle time is measured up to
+/// the latest observation; observations older than the latest one are ignored.
+///
+/// # Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use printer_event_handler::{ErrorState, IdleDetector, Printer, PrinterStatus};
+///
+/// let printer = |status| Printer::new("HP".to_string(), status, ErrorState::NoError, false, false);
+/// let start = Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+///
+/// let mut idle = IdleDetector::new();
+/// idle.observe(&printer(PrinterStatus::Printing), start);
+/// idle.observe(&printer(PrinterStatus::Idle), start + Duration::seconds(10));
+/// idle.observe(&printer(PrinterStatus::Idle), start + Duration::seconds(40));
+///
+/// assert_eq!(idle.idle_for(), Some(Duration::seconds(30)));
+/// assert!(idle.is_stably_idle(Duration::seconds(30)));
+/// assert!(!idle.is_stably_idle(Duration::seconds(60)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IdleDetector {
+    /// Start of the current idle stretch, if the printer is idle
+    idle_since: Option<DateTime<Utc>>,
+    /// Time of the latest observation
+    last_seen: Option<DateTime<Utc>>,
+}
+
+impl IdleDetector {
+    /// Creates a detector with no observations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the printer as observed at the given time
+    pub fn observe(&mut self, printer: &Printer, at: DateTime<Utc>) {
+        if self.last_seen.is_some_and(|last| at < last) {
+            // Out of order; keep the newer observation
+            return;
+        }
+        self.last_seen = Some(at);
+
+        let idle = *printer.status() == PrinterStatus::Idle
+            && !printer.is_offline()
+            && !printer.has_active_work();
+        if !idle {
+            self.idle_since = None;
+        } else if self.idle_since.is_none() {
+            self.idle_since = Some(at);
+        }
+    }
+
+    /// Returns how long the printer has been idle as of the latest observation,
+    /// or `None` if it was not idle then
+    pub fn idle_for(&self) -> Option<Duration> {
+        Some(self.last_seen? - self.idle_since?)
+    }
+
+    /// Checks whether the printer has been idle for at least `grace`
+    pub fn is_stably_idle(&self, grace: Duration) -> bool {
+        self.idle_for().is_some_and(|idle| idle >= grace)
+    }
+}
+
+/// How long recent backend queries took, from [`crate::PrinterMonitor::query_timings`].
+///
+/// Covers the printer listings and lookups the monitor sent to WMI or CUPS,
+/// failed ones included. A polling interval shorter than the typical query time
+/// keeps the backend permanently busy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryTimings {
+    /// Number of queries the statistics cover
+    pub samples: usize,
+    /// Fastest query
+    pub min: std::time::Duration,
+    /// Slowest query
+    pub max: std::time::Duration,
+    /// Mean query time
+    pub average: std::time::Duration,
+    /// Most recent query
+    pub last: std::time::Duration,
+}
+
+impl QueryTimings {
+    /// Summarizes query durations, oldest first; `None` when there are none
+    pub(crate) fn from_samples<'a>(
+        samples: impl ExactSizeIterator<Item = &'a std::time::Duration> + Clone,
+    ) -> Option<Self> {
+        let count = samples.len();
+        let last = *samples.clone().last()?;
+        let total: std::time::Duration = samples.clone().sum();
+        Some(Self {
+            samples: count,
+            min: *samples.clone().min()?,
+            max: *samples.max()?,
+            average: total / count as u32,
+            last,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ErrorState, PropertyChange};
+    use chrono::TimeZone;
+
+    fn printer(status: PrinterStatus, is_offline: bool) -> Printer {
+        Printer::new(
+            "Test Printer".to_string(),
+            status,
+            ErrorState::NoError,
+            is_offline,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_time_in_state() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 8, 0, 0).unwrap();
+        let mut stats = StateAccumulator::new();
+
+        // The first observation alone covers no time
+        stats.observe(&printer(PrinterStatus::Idle, false), start);
+        assert_eq!(stats.total(), Duration::zero());
+        assert_eq!(stats.percentage_in(&PrinterStatus::Idle), 0.0);
+
+        stats.observe(
+            &printer(PrinterStatus::Printing, false),
+            start + Duration::minutes(30),
+        );
+        stats.observe(
+            &printer(PrinterStatus::Idle, true),
+            start + Duration::minutes(50),
+        );
+        // Stale observation is ignored
+        stats.observe(
+            &printer(PrinterStatus::Printing, false),
+            start + Duration::minutes(40),
+        );
+        stats.observe(
+            &printer(PrinterStatus::Idle, false),
+            start + Duration::minutes(100),
+        );
+
+        assert_eq!(
+            stats.duration_in(&PrinterStatus::Idle),
+            Duration::minutes(30)
+        );
+        assert_eq!(
+            stats.duration_in(&PrinterStatus::Printing),
+            Duration::minutes(20)
+        );
+        assert_eq!(
+            stats.duration_in(&PrinterStatus::Offline),
+            Duration::minutes(50)
+        );
+        assert_eq!(stats.duration_in(&PrinterStatus::Warmup), Duration::zero());
+        assert_eq!(stats.total(), Duration::minutes(100));
+
+        let breakdown = stats.breakdown();
+        assert_eq!(breakdown.len(), 3);
+        assert_eq!(breakdown[&PrinterStatus::Offline], 50.0);
+        assert_eq!(breakdown[&PrinterStatus::Idle], 30.0);
+        assert_eq!(breakdown[&PrinterStatus::Printing], 20.0);
+    }
+
+    fn changes(name: &str, changes: Vec<PropertyChange>) -> PrinterChanges {
+        let mut set = PrinterChanges::new(name.to_string());
+        set.changes = changes;
+        set
+    }
+
+    fn offline_change() -> PropertyChange {
+        PropertyChange::IsOffline {
+            old: false,
+            new: true,
+        }
+    }
+
+    fn status_change() -> PropertyChange {
+        PropertyChange::Status {
+            old: PrinterStatus::Idle,
+            new: PrinterStatus::Printing,
+        }
+    }
+
+    #[test]
+    fn test_change_stats_tallies() {
+        let mut stats = ChangeStats::new();
+        assert_eq!(stats.most_volatile_property(), None);
+        assert_eq!(stats.most_volatile_printer(), None);
+
+        stats.record(&changes("Lab", vec![status_change(), offline_change()]));
+        stats.record(&changes("Office", vec![offline_change()]));
+        stats.record(&changes("Office", vec![offline_change()]));
+        stats.record(&changes("Office", vec![offline_change()]));
+        stats.record(&changes("Reception", vec![]));
+
+        assert_eq!(stats.change_count("IsOffline"), 4);
+        assert_eq!(stats.change_count("Status"), 1);
+        assert_eq!(stats.change_count("Location"), 0);
+        assert_eq!(stats.total(), 5);
+        assert_eq!(stats.printer_change_count("Office"), 3);
+        assert_eq!(stats.printer_change_count("Lab"), 2);
+        assert_eq!(stats.printer_change_count("Reception"), 0);
+        assert_eq!(stats.most_volatile_property(), Some("IsOffline"));
+        assert_eq!(stats.most_volatile_printer(), Some("Office"));
+
+        // Ties go to the name that sorts first
+        stats.record(&changes("Lab", vec![status_change()]));
+        assert_eq!(stats.most_volatile_printer(), Some("Lab"));
+    }
+
+    #[test]
+    fn test_idle_detector_grace_period() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 8, 0, 0).unwrap();
+        let at = |seconds| start + Duration::seconds(seconds);
+        let grace = Duration::seconds(20);
+        let mut detector = IdleDetector::new();
+        assert_eq!(detector.idle_for(), None);
+
+        detector.observe(&printer(PrinterStatus::Printing, false), at(0));
+        assert_eq!(detector.idle_for(), None);
+
+        // Idle starts, but has not lasted the grace period yet
+        detector.observe(&printer(PrinterStatus::Idle, false), at(5));
+        assert_eq!(detector.idle_for(), Some(Duration::zero()));
+        detector.observe(&printer(PrinterStatus::Idle, false), at(15));
+        assert_eq!(detector.idle_for(), Some(Duration::seconds(10)));
+        assert!(!detector.is_stably_idle(grace));
+
+        // Idle persists past the grace period
+        detector.observe(&printer(PrinterStatus::Idle, false), at(30));
+        assert_eq!(detector.idle_for(), Some(Duration::seconds(25)));
+        assert!(detector.is_stably_idle(grace));
+
+        // A stale observation does not reset the stretch
+        detector.observe(&printer(PrinterStatus::Printing, false), at(10));
+        assert!(detector.is_stably_idle(grace));
+
+        // Any busy, offline or queued observation restarts the wait
+        detector.observe(
+            &printer(PrinterStatus::Idle, false).with_jobs_queued(Some(1)),
+            at(35),
+        );
+        assert_eq!(detector.idle_for(), None);
+        detector.observe(&printer(PrinterStatus::Idle, false), at(40));
+        detector.observe(&printer(PrinterStatus::Idle, true), at(45));
+        assert!(!detector.is_stably_idle(Duration::zero()));
+        detector.observe(&printer(PrinterStatus::Idle, false), at(50));
+        detector.observe(&printer(PrinterStatus::Idle, false), at(65));
+        assert_eq!(detector.idle_for(), Some(Duration::seconds(15)));
+        assert!(!detector.is_stably_idle(grace));
+    }
+}
@@ -0,0 +1,262 @@
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, Instant, sleep, sleep_until};
+
+/// Decides when a monitoring loop queries the backend again
+pub(crate) enum PollTrigger {
+    /// Re-query after a fixed delay
+    Interval(Duration),
+    /// Re-query when CUPS touches its state directories, or after `fallback` at the latest
+    #[cfg(all(target_os = "linux", feature = "inotify"))]
+    CupsEvents {
+        watcher: cups::CupsWatcher,
+        fallback: Duration,
+    },
+}
+
+impl PollTrigger {
+    /// Watches the CUPS state directories, falling back to polling every `fallback`
+    /// when none of them can be watched (e.g. missing permissions).
+    #[cfg(all(target_os = "linux", feature = "inotify"))]
+    pub(crate) fn cups_events(fallback: Duration) -> Self {
+        match cups::CupsWatcher::watch(cups::CUPS_STATE_DIRS) {
+            Some(watcher) => PollTrigger::CupsEvents { watcher, fallback },
+            None => {
+                log::warn!("CUPS state directories are not watchable, falling back to polling");
+                PollTrigger::Interval(fallback)
+            }
+        }
+    }
+
+    /// Returns the longest time between two queries
+    pub(crate) fn interval(&self) -> Duration {
+        match self {
+            PollTrigger::Interval(delay) => *delay,
+            #[cfg(all(target_os = "linux", feature = "inotify"))]
+            PollTrigger::CupsEvents { fallback, .. } => *fallback,
+        }
+    }
+
+    /// Waits until the next query is due; `spread` adjusts each polling delay,
+    /// e.g. to add jitter
+    pub(crate) async fn wait(&mut self, spread: impl Fn(Duration) -> Duration) {
+        match self {
+            PollTrigger::Interval(delay) => sleep(spread(*delay)).await,
+            #[cfg(all(target_os = "linux", feature = "inotify"))]
+            PollTrigger::CupsEvents { watcher, fallback } => {
+                let fallback = *fallback;
+                let stopped = tokio::select! {
+                    changed = watcher.changed() => !changed,
+                    _ = sleep(spread(fallback)) => false,
+                };
+                if stopped {
+                    log::warn!("CUPS watcher stopped, falling back to polling");
+                    *self = PollTrigger::Interval(fallback);
+                }
+            }
+        }
+    }
+}
+
+/// Called by the watchdog with the time since the last completed poll
+pub(crate) type StallHandler = Arc<dyn Fn(Duration) + Send + Sync>;
+
+/// Timer task that reports a monitoring loop whose polls stopped completing.
+///
+/// The loop calls [`Self::poll_completed`] after every backend query. If none
+/// completes within the limit, the stall handler is called once; it is called
+/// again only after polls resumed and stalled anew. The task stops when the
+/// watchdog is dropped.
+pub(crate) struct Watchdog {
+    last_poll: Arc<Mutex<Instant>>,
+    task: JoinHandle<()>,
+}
+
+impl Watchdog {
+    /// Starts watching, counting from now
+    pub(crate) fn start(limit: Duration, on_stall: StallHandler) -> Self {
+        let last_poll = Arc::new(Mutex::new(Instant::now()));
+        let watched = Arc::clone(&last_poll);
+        let task = tokio::spawn(async move {
+            let mut reported = None;
+            loop {
+                let last = *watched.lock().unwrap();
+                let deadline = last + limit;
+                if Instant::now() < deadline {
+                    sleep_until(deadline).await;
+                    continue;
+                }
+                if reported != Some(last) {
+                    reported = Some(last);
+                    on_stall(last.elapsed());
+                }
+                sleep(limit).await;
+            }
+        });
+        Self { last_poll, task }
+    }
+
+    /// Records that a poll finished, successfully or not
+    pub(crate) fn poll_completed(&self) {
+        *self.last_poll.lock().unwrap() = Instant::now();
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "inotify"))]
+pub(crate) mod cups {
+    use log::debug;
+    use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+    use std::path::Path;
+    use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+    /// Directories CUPS rewrites when printer or job state changes
+    pub(crate) const CUPS_STATE_DIRS: &[&str] = &[
+        "/var/spool/cups",
+        "/var/cache/cups",
+        "/etc/cups",
+        "/run/cups",
+    ];
+
+    /// inotify watcher over the CUPS state directories
+    pub(crate) struct CupsWatcher {
+        _watcher: Option<RecommendedWatcher>,
+        events: UnboundedReceiver<notify::Result<Event>>,
+    }
+
+    impl CupsWatcher {
+        /// Starts watching every directory in `dirs` that can be watched.
+        ///
+        /// Returns `None` when none of them could be watched.
+        pub(crate) fn watch(dirs: &[&str]) -> Option<Self> {
+            let (events_tx, events) = unbounded_channel();
+            let mut watcher = notify::recommended_watcher(move |event| {
+                let _ = events_tx.send(event);
+            })
+            .ok()?;
+
+            let watched = dirs
+                .iter()
+                .filter(
+                    |dir| match watcher.watch(Path::new(dir), RecursiveMode::NonRecursive) {
+                        Ok(()) => true,
+                        Err(e) => {
+                            debug!("Cannot watch {}: {}", dir, e);
+                            false
+                        }
+                    },
+                )
+                .count();
+
+            (watched > 0).then_some(Self {
+                _watcher: Some(watcher),
+                events,
+            })
+        }
+
+        /// Waits for a relevant change, coalescing any burst of queued events.
+        ///
+        /// Returns `false` once the watcher has stopped delivering events.
+        pub(crate) async fn changed(&mut self) -> bool {
+            while let Some(event) = self.events.recv().await {
+                if is_relevant(&event) {
+                    while self.events.try_recv().is_ok() {}
+                    return true;
+                }
+            }
+            false
+        }
+
+        #[cfg(test)]
+        pub(crate) fn from_channel(events: UnboundedReceiver<notify::Result<Event>>) -> Self {
+            Self {
+                _watcher: None,
+                events,
+            }
+        }
+    }
+
+    /// Checks whether an event may reflect a printer or job state change.
+    ///
+    /// Reads never change state; watcher errors trigger a re-query to be safe.
+    pub(crate) fn is_relevant(event: &notify::Result<Event>) -> bool {
+        match event {
+            Ok(event) => matches!(
+                event.kind,
+                EventKind::Any | EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ),
+            Err(_) => true,
+        }
+    }
+}
+
+#[cfg(all(test, target_os = "linux", feature = "inotify"))]
+mod tests {
+    use super::cups::{CupsWatcher, is_relevant};
+    use super::*;
+    use notify::Event;
+    use notify::event::{AccessKind, CreateKind, EventKind, ModifyKind};
+    use tokio::sync::mpsc::unbounded_channel;
+
+    fn event(kind: EventKind) -> notify::Result<Event> {
+        Ok(Event::new(kind).add_path("/var/cache/cups/printers.conf".into()))
+    }
+
+    #[test]
+    fn test_event_relevance() {
+        assert!(is_relevant(&event(EventKind::Create(CreateKind::File))));
+        assert!(is_relevant(&event(EventKind::Modify(ModifyKind::Any))));
+        assert!(!is_relevant(&event(EventKind::Access(AccessKind::Read))));
+        assert!(!is_relevant(&event(EventKind::Other)));
+    }
+
+    #[tokio::test]
+    async fn test_simulated_event_triggers_requery() {
+        let (events_tx, events) = unbounded_channel();
+        let mut trigger = PollTrigger::CupsEvents {
+            watcher: CupsWatcher::from_channel(events),
+            fallback: Duration::from_secs(3600),
+        };
+
+        events_tx
+            .send(event(EventKind::Access(AccessKind::Read)))
+            .unwrap();
+        events_tx
+            .send(event(EventKind::Modify(ModifyKind::Any)))
+            .unwrap();
+        events_tx
+            .send(event(EventKind::Modify(ModifyKind::Any)))
+            .unwrap();
+
+        tokio::time::timeout(Duration::from_secs(1), trigger.wait(|delay| delay))
+            .await
+            .expect("relevant event should end the wait");
+
+        // The burst was coalesced into a single wake-up
+        let PollTrigger::CupsEvents { watcher, .. } = &mut trigger else {
+            panic!("trigger should still be event driven");
+        };
+        drop(events_tx);
+        assert!(!watcher.changed().await);
+    }
+
+    #[tokio::test]
+    async fn test_stopped_watcher_falls_back_to_polling() {
+        let (events_tx, events) = unbounded_channel();
+        let mut trigger = PollTrigger::CupsEvents {
+            watcher: CupsWatcher::from_channel(events),
+            fallback: Duration::from_millis(5),
+        };
+        drop(events_tx);
+
+        trigger.wait(|delay| delay).await;
+        assert!(
+            matches!(trigger, PollTrigger::Interval(delay) if delay == Duration::from_millis(5))
+        );
+    }
+}